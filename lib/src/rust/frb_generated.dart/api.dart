@@ -3,8 +3,12 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'events.dart';
 import 'frb_generated.dart';
+import 'i18n.dart';
+import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'wallet.dart';
 
 // These functions are ignored because they are not marked as `pub`: `run_blocking`
 
@@ -13,120 +17,370 @@ Future<void> setNodeUrl({required String url}) =>
 
 Future<String> getNodeUrl() => RustLib.instance.api.crateApiGetNodeUrl();
 
+/// Sprache fuer Fehler- und Statusmeldungen der Bridge; die App ruft das beim Start und bei
+/// jedem Wechsel im `LocaleStore` auf.
+Future<void> setLocale({required BridgeLocale locale}) =>
+    RustLib.instance.api.crateApiSetLocale(locale: locale);
+
+Future<BridgeLocale> getLocale() => RustLib.instance.api.crateApiGetLocale();
+
 Future<BigInt> getNodeTip() => RustLib.instance.api.crateApiGetNodeTip();
 
-Future<void> walletInitOrOpen(
-        {required String dataDir, required String passphrase}) =>
-    RustLib.instance.api
-        .crateApiWalletInitOrOpen(dataDir: dataDir, passphrase: passphrase);
+Future<BigInt> walletInitOrOpen(
+        {required String dataDir,
+        required String passphrase,
+        WalletChainType? chainType}) =>
+    RustLib.instance.api.crateApiWalletInitOrOpen(
+        dataDir: dataDir, passphrase: passphrase, chainType: chainType);
 
-Future<String> walletCreate(
+Future<CreatedWallet> walletCreate(
         {required String dataDir,
         required String passphrase,
-        required BigInt mnemonicLength}) =>
+        required BigInt mnemonicLength,
+        required WalletChainType chainType}) =>
     RustLib.instance.api.crateApiWalletCreate(
         dataDir: dataDir,
         passphrase: passphrase,
-        mnemonicLength: mnemonicLength);
+        mnemonicLength: mnemonicLength,
+        chainType: chainType);
 
 Future<String> walletSeedPhrase(
         {required String dataDir, required String passphrase}) =>
     RustLib.instance.api
         .crateApiWalletSeedPhrase(dataDir: dataDir, passphrase: passphrase);
 
-Future<void> walletRestoreFromSeed(
+Future<RestoreResultDto> walletRestoreFromSeed(
         {required String dataDir,
         required String passphrase,
-        required String phrase}) =>
+        required String phrase,
+        required WalletChainType chainType,
+        BigInt? cancelToken}) =>
     RustLib.instance.api.crateApiWalletRestoreFromSeed(
-        dataDir: dataDir, passphrase: passphrase, phrase: phrase);
+        dataDir: dataDir,
+        passphrase: passphrase,
+        phrase: phrase,
+        chainType: chainType,
+        cancelToken: cancelToken);
 
-Future<String> walletGetAddress() =>
-    RustLib.instance.api.crateApiWalletGetAddress();
+/// Neues Token fuer `wallet_scan`, `wallet_resume_scan` oder `wallet_restore_from_seed`.
+Future<BigInt> createCancelToken() =>
+    RustLib.instance.api.crateApiCreateCancelToken();
 
-Future<void> walletSync() => RustLib.instance.api.crateApiWalletSync();
+Future<bool> cancelOperation({required BigInt token}) =>
+    RustLib.instance.api.crateApiCancelOperation(token: token);
 
-Future<BigInt> walletGetBalance() =>
-    RustLib.instance.api.crateApiWalletGetBalance();
+Future<WalletCloseReportDto> walletClose({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletClose(handle: handle);
 
-Future<String> walletSendSlatepack(
-        {required String to, required BigInt amountNano}) =>
+Future<void> walletSetAutoLock(
+        {required BigInt handle, required BigInt timeoutSecs}) =>
     RustLib.instance.api
-        .crateApiWalletSendSlatepack(to: to, amountNano: amountNano);
+        .crateApiWalletSetAutoLock(handle: handle, timeoutSecs: timeoutSecs);
 
-Future<String> walletIssueInvoice({required BigInt amountNano}) =>
-    RustLib.instance.api.crateApiWalletIssueInvoice(amountNano: amountNano);
+/// Standard-TTL in Bloecken fuer neue Sends und Rechnungen; 0 schaltet ihn ab. Bleibt gespeichert.
+/// Abgelaufene Txs storniert der Updater (`wallet_start_updater`) beim Refresh.
+Future<void> walletSetDefaultTtl(
+        {required BigInt handle, required BigInt ttlBlocks}) =>
+    RustLib.instance.api
+        .crateApiWalletSetDefaultTtl(handle: handle, ttlBlocks: ttlBlocks);
 
-Future<String> walletReceiveSlatepack({required String message}) =>
-    RustLib.instance.api.crateApiWalletReceiveSlatepack(message: message);
+Stream<WalletEventDto> walletEvents() =>
+    RustLib.instance.api.crateApiWalletEvents();
 
-Future<String> walletProcessInvoice({required String message}) =>
-    RustLib.instance.api.crateApiWalletProcessInvoice(message: message);
+Future<void> walletChangePassword(
+        {required BigInt handle,
+        required String oldPassphrase,
+        required String newPassphrase}) =>
+    RustLib.instance.api.crateApiWalletChangePassword(
+        handle: handle,
+        oldPassphrase: oldPassphrase,
+        newPassphrase: newPassphrase);
 
-Future<String> walletInspectSlatepack({required String message}) =>
-    RustLib.instance.api.crateApiWalletInspectSlatepack(message: message);
+Future<String> walletGetAddress({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletGetAddress(handle: handle);
 
-Future<String> walletFinalizeSlatepack(
-        {required String message, required bool postTx, required bool fluff}) =>
-    RustLib.instance.api.crateApiWalletFinalizeSlatepack(
-        message: message, postTx: postTx, fluff: fluff);
+Future<void> walletSync({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletSync(handle: handle);
+
+Future<UpdaterStatusDto> walletStartUpdater(
+        {required BigInt handle, required BigInt intervalSecs}) =>
+    RustLib.instance.api
+        .crateApiWalletStartUpdater(handle: handle, intervalSecs: intervalSecs);
+
+Future<UpdaterStatusDto> walletStopUpdater({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletStopUpdater(handle: handle);
+
+Future<UpdaterStatusDto> walletUpdaterStatus({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletUpdaterStatus(handle: handle);
 
-Future<String> walletInfo() => RustLib.instance.api.crateApiWalletInfo();
+Future<BigInt> walletGetBalance({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletGetBalance(handle: handle);
 
-Future<String> walletListTransactions({required bool refreshFromNode}) =>
+Future<String> walletSendSlatepack(
+        {required BigInt handle,
+        required String to,
+        required BigInt amountNano,
+        required SendOptions options,
+        SlatepackFileTarget? outputFile}) =>
+    RustLib.instance.api.crateApiWalletSendSlatepack(
+        handle: handle,
+        to: to,
+        amountNano: amountNano,
+        options: options,
+        outputFile: outputFile);
+
+Future<DirectSendResultDto> walletSendViaTor(
+        {required BigInt handle,
+        required String address,
+        required BigInt amountNano,
+        required SendOptions options}) =>
+    RustLib.instance.api.crateApiWalletSendViaTor(
+        handle: handle,
+        address: address,
+        amountNano: amountNano,
+        options: options);
+
+Future<PayoutReportDto> walletSendBatch(
+        {required BigInt handle,
+        required List<PayoutItem> items,
+        required bool viaTor,
+        required SendOptions options}) =>
+    RustLib.instance.api.crateApiWalletSendBatch(
+        handle: handle, items: items, viaTor: viaTor, options: options);
+
+Future<DirectSendResultDto> walletSendViaHttp(
+        {required BigInt handle,
+        required String url,
+        required BigInt amountNano,
+        required SendOptions options}) =>
+    RustLib.instance.api.crateApiWalletSendViaHttp(
+        handle: handle, url: url, amountNano: amountNano, options: options);
+
+Future<SendEstimateDto> walletEstimateSend(
+        {required BigInt handle,
+        required BigInt amountNano,
+        required SendOptions options}) =>
+    RustLib.instance.api.crateApiWalletEstimateSend(
+        handle: handle, amountNano: amountNano, options: options);
+
+Future<String> walletIssueInvoice(
+        {required BigInt handle, required BigInt amountNano}) =>
+    RustLib.instance.api
+        .crateApiWalletIssueInvoice(handle: handle, amountNano: amountNano);
+
+Future<String> walletReceiveSlatepack(
+        {required BigInt handle,
+        required String message,
+        SlatepackFileTarget? outputFile}) =>
+    RustLib.instance.api.crateApiWalletReceiveSlatepack(
+        handle: handle, message: message, outputFile: outputFile);
+
+Future<String> walletProcessInvoice(
+        {required BigInt handle,
+        required String message,
+        required SendOptions options,
+        SlatepackFileTarget? outputFile}) =>
+    RustLib.instance.api.crateApiWalletProcessInvoice(
+        handle: handle,
+        message: message,
+        options: options,
+        outputFile: outputFile);
+
+/// Zerlegt ein Slatepack in Frames fuer einen animierten QR-Code.
+Future<List<String>> qrEncodeSlatepack(
+        {required String message, int? maxCharsPerFrame}) =>
+    RustLib.instance.api.crateApiQrEncodeSlatepack(
+        message: message, maxCharsPerFrame: maxCharsPerFrame);
+
+Future<BigInt> qrCreateScanSession() =>
+    RustLib.instance.api.crateApiQrCreateScanSession();
+
+Future<QrScanProgressDto> qrAddFrame(
+        {required BigInt session, required String frame}) =>
+    RustLib.instance.api.crateApiQrAddFrame(session: session, frame: frame);
+
+Future<void> qrReleaseScanSession({required BigInt session}) =>
+    RustLib.instance.api.crateApiQrReleaseScanSession(session: session);
+
+/// Schreibt ein ausgehendes Slatepack (Send, Antwort, Rechnung, finalisiert) in eine Datei.
+/// Ohne `overwrite` schlaegt das Schreiben fehl, wenn die Datei schon existiert.
+Future<SlatepackFileDto> writeSlatepackFile(
+        {required String message,
+        required String path,
+        required bool binary,
+        required bool overwrite}) =>
+    RustLib.instance.api.crateApiWriteSlatepackFile(
+        message: message, path: path, binary: binary, overwrite: overwrite);
+
+/// Liest eine armored oder binaere Slatepack-Datei; das Ergebnis geht an die normalen
+/// Slatepack-Funktionen.
+Future<String> readSlatepackFile({required String path}) =>
+    RustLib.instance.api.crateApiReadSlatepackFile(path: path);
+
+Future<SlateInspectionDto> walletInspectSlatepack(
+        {required BigInt handle, required String message}) =>
     RustLib.instance.api
-        .crateApiWalletListTransactions(refreshFromNode: refreshFromNode);
+        .crateApiWalletInspectSlatepack(handle: handle, message: message);
 
-Future<String> walletListOutputs(
-        {required bool includeSpent, required bool refreshFromNode}) =>
+Future<String> walletFinalizeSlatepack(
+        {required BigInt handle,
+        required String message,
+        required bool postTx,
+        required bool fluff,
+        SlatepackFileTarget? outputFile}) =>
+    RustLib.instance.api.crateApiWalletFinalizeSlatepack(
+        handle: handle,
+        message: message,
+        postTx: postTx,
+        fluff: fluff,
+        outputFile: outputFile);
+
+Future<TxMetadataDto?> walletTxMetadata(
+        {required BigInt handle,
+        required String parentKeyId,
+        required int txId}) =>
+    RustLib.instance.api.crateApiWalletTxMetadata(
+        handle: handle, parentKeyId: parentKeyId, txId: txId);
+
+Future<TxMetadataDto?> walletSetTxMetadata(
+        {required BigInt handle,
+        required String parentKeyId,
+        required int txId,
+        required TxMetadataDto metadata}) =>
+    RustLib.instance.api.crateApiWalletSetTxMetadata(
+        handle: handle,
+        parentKeyId: parentKeyId,
+        txId: txId,
+        metadata: metadata);
+
+Future<void> walletSetRequireReview(
+        {required BigInt handle, required bool required}) =>
+    RustLib.instance.api
+        .crateApiWalletSetRequireReview(handle: handle, required: required);
+
+Future<SlateReviewDto> walletReviewSlatepack(
+        {required BigInt handle,
+        required String message,
+        required SlateExpectation expectation,
+        required SendOptions options}) =>
+    RustLib.instance.api.crateApiWalletReviewSlatepack(
+        handle: handle,
+        message: message,
+        expectation: expectation,
+        options: options);
+
+Future<String> walletApproveSlatepack(
+        {required BigInt handle,
+        required String slateId,
+        required SendOptions options}) =>
+    RustLib.instance.api.crateApiWalletApproveSlatepack(
+        handle: handle, slateId: slateId, options: options);
+
+Future<bool> walletRejectSlatepack(
+        {required BigInt handle, required String slateId}) =>
+    RustLib.instance.api
+        .crateApiWalletRejectSlatepack(handle: handle, slateId: slateId);
+
+/// Ein Einstieg fuer jedes eingefuegte Slatepack; leitet je nach Slate-Status weiter.
+Future<ProcessedSlatepackDto> walletProcessSlatepack(
+        {required BigInt handle,
+        required String message,
+        required ProcessSlatepackOptions options}) =>
+    RustLib.instance.api.crateApiWalletProcessSlatepack(
+        handle: handle, message: message, options: options);
+
+Future<WalletInfoDto> walletInfo({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletInfo(handle: handle);
+
+Future<List<TransactionDto>> walletListTransactions(
+        {required BigInt handle, required bool refreshFromNode}) =>
+    RustLib.instance.api.crateApiWalletListTransactions(
+        handle: handle, refreshFromNode: refreshFromNode);
+
+Future<List<OutputDto>> walletListOutputs(
+        {required BigInt handle,
+        required bool includeSpent,
+        required bool refreshFromNode}) =>
     RustLib.instance.api.crateApiWalletListOutputs(
-        includeSpent: includeSpent, refreshFromNode: refreshFromNode);
+        handle: handle,
+        includeSpent: includeSpent,
+        refreshFromNode: refreshFromNode);
 
-Future<void> walletCancelTx({required int txId}) =>
-    RustLib.instance.api.crateApiWalletCancelTx(txId: txId);
+Future<void> walletCancelTx({required BigInt handle, required int txId}) =>
+    RustLib.instance.api.crateApiWalletCancelTx(handle: handle, txId: txId);
 
-Future<void> walletRepostTx({required int txId, required bool fluff}) =>
-    RustLib.instance.api.crateApiWalletRepostTx(txId: txId, fluff: fluff);
+Future<void> walletRepostTx(
+        {required BigInt handle, required int txId, required bool fluff}) =>
+    RustLib.instance.api
+        .crateApiWalletRepostTx(handle: handle, txId: txId, fluff: fluff);
 
-Future<String> walletScan(
-        {required bool deleteUnconfirmed,
+Future<ScanResultDto> walletScan(
+        {required BigInt handle,
+        required bool deleteUnconfirmed,
         BigInt? startHeight,
-        BigInt? backwardsFromTip}) =>
+        BigInt? backwardsFromTip,
+        BigInt? cancelToken}) =>
     RustLib.instance.api.crateApiWalletScan(
+        handle: handle,
         deleteUnconfirmed: deleteUnconfirmed,
         startHeight: startHeight,
-        backwardsFromTip: backwardsFromTip);
+        backwardsFromTip: backwardsFromTip,
+        cancelToken: cancelToken);
+
+Future<ScanResultDto> walletResumeScan(
+        {required BigInt handle, BigInt? cancelToken}) =>
+    RustLib.instance.api
+        .crateApiWalletResumeScan(handle: handle, cancelToken: cancelToken);
+
+Future<BigInt?> walletScanResumeHeight({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletScanResumeHeight(handle: handle);
 
-Future<String> walletListAccounts() =>
-    RustLib.instance.api.crateApiWalletListAccounts();
+Future<List<AccountDto>> walletListAccounts({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletListAccounts(handle: handle);
+
+Future<AccountDto> walletCreateAccount(
+        {required BigInt handle, required String label}) =>
+    RustLib.instance.api
+        .crateApiWalletCreateAccount(handle: handle, label: label);
 
-Future<String> walletCreateAccount({required String label}) =>
-    RustLib.instance.api.crateApiWalletCreateAccount(label: label);
+Future<AccountDto> walletSetActiveAccount(
+        {required BigInt handle, required String label}) =>
+    RustLib.instance.api
+        .crateApiWalletSetActiveAccount(handle: handle, label: label);
 
-Future<String> walletSetActiveAccount({required String label}) =>
-    RustLib.instance.api.crateApiWalletSetActiveAccount(label: label);
+Future<String> walletActiveAccount({required BigInt handle}) =>
+    RustLib.instance.api.crateApiWalletActiveAccount(handle: handle);
 
-Future<String> walletActiveAccount() =>
-    RustLib.instance.api.crateApiWalletActiveAccount();
+Future<PaymentProofDto> walletPaymentProof(
+        {required BigInt handle, required int txId}) =>
+    RustLib.instance.api.crateApiWalletPaymentProof(handle: handle, txId: txId);
 
-Future<String> walletPaymentProof({required int txId}) =>
-    RustLib.instance.api.crateApiWalletPaymentProof(txId: txId);
+Future<String> walletTransactionSlatepack(
+        {required BigInt handle, required int txId}) =>
+    RustLib.instance.api
+        .crateApiWalletTransactionSlatepack(handle: handle, txId: txId);
 
-Future<String> walletTransactionSlatepack({required int txId}) =>
-    RustLib.instance.api.crateApiWalletTransactionSlatepack(txId: txId);
+Future<PaymentProofVerificationDto> walletVerifyPaymentProof(
+        {required BigInt handle, required String payload}) =>
+    RustLib.instance.api
+        .crateApiWalletVerifyPaymentProof(handle: handle, payload: payload);
 
-Future<String> walletVerifyPaymentProof({required String payload}) =>
-    RustLib.instance.api.crateApiWalletVerifyPaymentProof(payload: payload);
+Future<TorStatusDto> torStatus({required BigInt handle}) =>
+    RustLib.instance.api.crateApiTorStatus(handle: handle);
 
-Future<String> torStatus() => RustLib.instance.api.crateApiTorStatus();
+Future<TorStatusDto> torStart(
+        {required BigInt handle, required String listenAddr}) =>
+    RustLib.instance.api
+        .crateApiTorStart(handle: handle, listenAddr: listenAddr);
 
-Future<String> torStart({required String listenAddr}) =>
-    RustLib.instance.api.crateApiTorStart(listenAddr: listenAddr);
+Future<void> torStop({required BigInt handle}) =>
+    RustLib.instance.api.crateApiTorStop(handle: handle);
 
-Future<void> torStop() => RustLib.instance.api.crateApiTorStop();
+Future<String> foreignListenerAddr({required BigInt handle}) =>
+    RustLib.instance.api.crateApiForeignListenerAddr(handle: handle);
 
-Future<String> ownerListenerStatus() =>
-    RustLib.instance.api.crateApiOwnerListenerStatus();
+Future<OwnerListenerStatusDto> ownerListenerStatus({required BigInt handle}) =>
+    RustLib.instance.api.crateApiOwnerListenerStatus(handle: handle);
 
-Future<String> ownerListenerStart() =>
-    RustLib.instance.api.crateApiOwnerListenerStart();
+Future<OwnerListenerStatusDto> ownerListenerStart({required BigInt handle}) =>
+    RustLib.instance.api.crateApiOwnerListenerStart(handle: handle);
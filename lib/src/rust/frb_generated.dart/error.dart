@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+part 'error.freezed.dart';
+
+@freezed
+sealed class BridgeError with _$BridgeError implements FrbException {
+  const BridgeError._();
+
+  /// Das Wallet-Verzeichnis enthaelt noch keinen Seed.
+  const factory BridgeError.notInitialized({
+    required String detail,
+  }) = BridgeError_NotInitialized;
+
+  /// Zum Handle gibt es keine geoeffnete Wallet (geschlossen oder automatisch gesperrt).
+  const factory BridgeError.walletNotOpen({
+    required String detail,
+  }) = BridgeError_WalletNotOpen;
+
+  const factory BridgeError.walletAlreadyOpen({
+    required String detail,
+  }) = BridgeError_WalletAlreadyOpen;
+
+  const factory BridgeError.wrongPassword({
+    required String detail,
+  }) = BridgeError_WrongPassword;
+
+  const factory BridgeError.insufficientFunds({
+    required String detail,
+  }) = BridgeError_InsufficientFunds;
+
+  const factory BridgeError.nodeUnreachable({
+    required String detail,
+  }) = BridgeError_NodeUnreachable;
+
+  const factory BridgeError.invalidSlatepack({
+    required String detail,
+  }) = BridgeError_InvalidSlatepack;
+
+  const factory BridgeError.invalidAddress({
+    required String detail,
+  }) = BridgeError_InvalidAddress;
+
+  /// Ungueltiger Parameter (leerer Name, Betrag 0, falscher Chain-Typ, ...).
+  const factory BridgeError.invalidInput({
+    required String detail,
+  }) = BridgeError_InvalidInput;
+
+  const factory BridgeError.accountError({
+    required String detail,
+  }) = BridgeError_AccountError;
+
+  const factory BridgeError.transactionError({
+    required String detail,
+  }) = BridgeError_TransactionError;
+
+  const factory BridgeError.paymentProofError({
+    required String detail,
+  }) = BridgeError_PaymentProofError;
+
+  const factory BridgeError.torFailed({
+    required String detail,
+  }) = BridgeError_TorFailed;
+
+  const factory BridgeError.listenerFailed({
+    required String detail,
+  }) = BridgeError_ListenerFailed;
+
+  /// Ueber ein Abbruch-Token beendet; laesst sich in der Regel fortsetzen.
+  const factory BridgeError.cancelled({
+    required String detail,
+  }) = BridgeError_Cancelled;
+
+  const factory BridgeError.internal({
+    required String detail,
+  }) = BridgeError_Internal;
+}
@@ -0,0 +1,4001 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$BridgeError {
+  String get detail => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $BridgeErrorCopyWith<BridgeError> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BridgeErrorCopyWith<$Res> {
+  factory $BridgeErrorCopyWith(
+          BridgeError value, $Res Function(BridgeError) then) =
+      _$BridgeErrorCopyWithImpl<$Res, BridgeError>;
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class _$BridgeErrorCopyWithImpl<$Res, $Val extends BridgeError>
+    implements $BridgeErrorCopyWith<$Res> {
+  _$BridgeErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_value.copyWith(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$BridgeError_NotInitializedImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_NotInitializedImplCopyWith(
+          _$BridgeError_NotInitializedImpl value,
+          $Res Function(_$BridgeError_NotInitializedImpl) then) =
+      __$$BridgeError_NotInitializedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_NotInitializedImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_NotInitializedImpl>
+    implements _$$BridgeError_NotInitializedImplCopyWith<$Res> {
+  __$$BridgeError_NotInitializedImplCopyWithImpl(
+      _$BridgeError_NotInitializedImpl _value,
+      $Res Function(_$BridgeError_NotInitializedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_NotInitializedImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_NotInitializedImpl extends BridgeError_NotInitialized {
+  const _$BridgeError_NotInitializedImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.notInitialized(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_NotInitializedImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_NotInitializedImplCopyWith<_$BridgeError_NotInitializedImpl>
+      get copyWith => __$$BridgeError_NotInitializedImplCopyWithImpl<
+          _$BridgeError_NotInitializedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return notInitialized(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return notInitialized?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (notInitialized != null) {
+      return notInitialized(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return notInitialized(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return notInitialized?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (notInitialized != null) {
+      return notInitialized(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_NotInitialized extends BridgeError {
+  const factory BridgeError_NotInitialized(
+      {required final String detail}) = _$BridgeError_NotInitializedImpl;
+  const BridgeError_NotInitialized._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_NotInitializedImplCopyWith<_$BridgeError_NotInitializedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_WalletNotOpenImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_WalletNotOpenImplCopyWith(
+          _$BridgeError_WalletNotOpenImpl value,
+          $Res Function(_$BridgeError_WalletNotOpenImpl) then) =
+      __$$BridgeError_WalletNotOpenImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_WalletNotOpenImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_WalletNotOpenImpl>
+    implements _$$BridgeError_WalletNotOpenImplCopyWith<$Res> {
+  __$$BridgeError_WalletNotOpenImplCopyWithImpl(
+      _$BridgeError_WalletNotOpenImpl _value,
+      $Res Function(_$BridgeError_WalletNotOpenImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_WalletNotOpenImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_WalletNotOpenImpl extends BridgeError_WalletNotOpen {
+  const _$BridgeError_WalletNotOpenImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.walletNotOpen(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_WalletNotOpenImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_WalletNotOpenImplCopyWith<_$BridgeError_WalletNotOpenImpl>
+      get copyWith => __$$BridgeError_WalletNotOpenImplCopyWithImpl<
+          _$BridgeError_WalletNotOpenImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return walletNotOpen(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return walletNotOpen?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (walletNotOpen != null) {
+      return walletNotOpen(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return walletNotOpen(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return walletNotOpen?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (walletNotOpen != null) {
+      return walletNotOpen(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_WalletNotOpen extends BridgeError {
+  const factory BridgeError_WalletNotOpen(
+      {required final String detail}) = _$BridgeError_WalletNotOpenImpl;
+  const BridgeError_WalletNotOpen._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_WalletNotOpenImplCopyWith<_$BridgeError_WalletNotOpenImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_WalletAlreadyOpenImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_WalletAlreadyOpenImplCopyWith(
+          _$BridgeError_WalletAlreadyOpenImpl value,
+          $Res Function(_$BridgeError_WalletAlreadyOpenImpl) then) =
+      __$$BridgeError_WalletAlreadyOpenImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_WalletAlreadyOpenImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_WalletAlreadyOpenImpl>
+    implements _$$BridgeError_WalletAlreadyOpenImplCopyWith<$Res> {
+  __$$BridgeError_WalletAlreadyOpenImplCopyWithImpl(
+      _$BridgeError_WalletAlreadyOpenImpl _value,
+      $Res Function(_$BridgeError_WalletAlreadyOpenImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_WalletAlreadyOpenImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_WalletAlreadyOpenImpl
+    extends BridgeError_WalletAlreadyOpen {
+  const _$BridgeError_WalletAlreadyOpenImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.walletAlreadyOpen(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_WalletAlreadyOpenImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_WalletAlreadyOpenImplCopyWith<_$BridgeError_WalletAlreadyOpenImpl>
+      get copyWith => __$$BridgeError_WalletAlreadyOpenImplCopyWithImpl<
+          _$BridgeError_WalletAlreadyOpenImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return walletAlreadyOpen(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return walletAlreadyOpen?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (walletAlreadyOpen != null) {
+      return walletAlreadyOpen(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return walletAlreadyOpen(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return walletAlreadyOpen?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (walletAlreadyOpen != null) {
+      return walletAlreadyOpen(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_WalletAlreadyOpen extends BridgeError {
+  const factory BridgeError_WalletAlreadyOpen(
+      {required final String detail}) = _$BridgeError_WalletAlreadyOpenImpl;
+  const BridgeError_WalletAlreadyOpen._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_WalletAlreadyOpenImplCopyWith<_$BridgeError_WalletAlreadyOpenImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_WrongPasswordImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_WrongPasswordImplCopyWith(
+          _$BridgeError_WrongPasswordImpl value,
+          $Res Function(_$BridgeError_WrongPasswordImpl) then) =
+      __$$BridgeError_WrongPasswordImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_WrongPasswordImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_WrongPasswordImpl>
+    implements _$$BridgeError_WrongPasswordImplCopyWith<$Res> {
+  __$$BridgeError_WrongPasswordImplCopyWithImpl(
+      _$BridgeError_WrongPasswordImpl _value,
+      $Res Function(_$BridgeError_WrongPasswordImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_WrongPasswordImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_WrongPasswordImpl extends BridgeError_WrongPassword {
+  const _$BridgeError_WrongPasswordImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.wrongPassword(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_WrongPasswordImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_WrongPasswordImplCopyWith<_$BridgeError_WrongPasswordImpl>
+      get copyWith => __$$BridgeError_WrongPasswordImplCopyWithImpl<
+          _$BridgeError_WrongPasswordImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return wrongPassword(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return wrongPassword?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (wrongPassword != null) {
+      return wrongPassword(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return wrongPassword(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return wrongPassword?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (wrongPassword != null) {
+      return wrongPassword(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_WrongPassword extends BridgeError {
+  const factory BridgeError_WrongPassword(
+      {required final String detail}) = _$BridgeError_WrongPasswordImpl;
+  const BridgeError_WrongPassword._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_WrongPasswordImplCopyWith<_$BridgeError_WrongPasswordImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_InsufficientFundsImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_InsufficientFundsImplCopyWith(
+          _$BridgeError_InsufficientFundsImpl value,
+          $Res Function(_$BridgeError_InsufficientFundsImpl) then) =
+      __$$BridgeError_InsufficientFundsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_InsufficientFundsImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_InsufficientFundsImpl>
+    implements _$$BridgeError_InsufficientFundsImplCopyWith<$Res> {
+  __$$BridgeError_InsufficientFundsImplCopyWithImpl(
+      _$BridgeError_InsufficientFundsImpl _value,
+      $Res Function(_$BridgeError_InsufficientFundsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_InsufficientFundsImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_InsufficientFundsImpl
+    extends BridgeError_InsufficientFunds {
+  const _$BridgeError_InsufficientFundsImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.insufficientFunds(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_InsufficientFundsImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_InsufficientFundsImplCopyWith<_$BridgeError_InsufficientFundsImpl>
+      get copyWith => __$$BridgeError_InsufficientFundsImplCopyWithImpl<
+          _$BridgeError_InsufficientFundsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return insufficientFunds(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return insufficientFunds?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (insufficientFunds != null) {
+      return insufficientFunds(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return insufficientFunds(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return insufficientFunds?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (insufficientFunds != null) {
+      return insufficientFunds(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_InsufficientFunds extends BridgeError {
+  const factory BridgeError_InsufficientFunds(
+      {required final String detail}) = _$BridgeError_InsufficientFundsImpl;
+  const BridgeError_InsufficientFunds._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_InsufficientFundsImplCopyWith<_$BridgeError_InsufficientFundsImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_NodeUnreachableImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_NodeUnreachableImplCopyWith(
+          _$BridgeError_NodeUnreachableImpl value,
+          $Res Function(_$BridgeError_NodeUnreachableImpl) then) =
+      __$$BridgeError_NodeUnreachableImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_NodeUnreachableImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_NodeUnreachableImpl>
+    implements _$$BridgeError_NodeUnreachableImplCopyWith<$Res> {
+  __$$BridgeError_NodeUnreachableImplCopyWithImpl(
+      _$BridgeError_NodeUnreachableImpl _value,
+      $Res Function(_$BridgeError_NodeUnreachableImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_NodeUnreachableImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_NodeUnreachableImpl extends BridgeError_NodeUnreachable {
+  const _$BridgeError_NodeUnreachableImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.nodeUnreachable(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_NodeUnreachableImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_NodeUnreachableImplCopyWith<_$BridgeError_NodeUnreachableImpl>
+      get copyWith => __$$BridgeError_NodeUnreachableImplCopyWithImpl<
+          _$BridgeError_NodeUnreachableImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return nodeUnreachable(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return nodeUnreachable?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (nodeUnreachable != null) {
+      return nodeUnreachable(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return nodeUnreachable(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return nodeUnreachable?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (nodeUnreachable != null) {
+      return nodeUnreachable(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_NodeUnreachable extends BridgeError {
+  const factory BridgeError_NodeUnreachable(
+      {required final String detail}) = _$BridgeError_NodeUnreachableImpl;
+  const BridgeError_NodeUnreachable._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_NodeUnreachableImplCopyWith<_$BridgeError_NodeUnreachableImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_InvalidSlatepackImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_InvalidSlatepackImplCopyWith(
+          _$BridgeError_InvalidSlatepackImpl value,
+          $Res Function(_$BridgeError_InvalidSlatepackImpl) then) =
+      __$$BridgeError_InvalidSlatepackImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_InvalidSlatepackImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_InvalidSlatepackImpl>
+    implements _$$BridgeError_InvalidSlatepackImplCopyWith<$Res> {
+  __$$BridgeError_InvalidSlatepackImplCopyWithImpl(
+      _$BridgeError_InvalidSlatepackImpl _value,
+      $Res Function(_$BridgeError_InvalidSlatepackImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_InvalidSlatepackImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_InvalidSlatepackImpl extends BridgeError_InvalidSlatepack {
+  const _$BridgeError_InvalidSlatepackImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.invalidSlatepack(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_InvalidSlatepackImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_InvalidSlatepackImplCopyWith<_$BridgeError_InvalidSlatepackImpl>
+      get copyWith => __$$BridgeError_InvalidSlatepackImplCopyWithImpl<
+          _$BridgeError_InvalidSlatepackImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return invalidSlatepack(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return invalidSlatepack?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidSlatepack != null) {
+      return invalidSlatepack(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return invalidSlatepack(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return invalidSlatepack?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidSlatepack != null) {
+      return invalidSlatepack(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_InvalidSlatepack extends BridgeError {
+  const factory BridgeError_InvalidSlatepack(
+      {required final String detail}) = _$BridgeError_InvalidSlatepackImpl;
+  const BridgeError_InvalidSlatepack._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_InvalidSlatepackImplCopyWith<_$BridgeError_InvalidSlatepackImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_InvalidAddressImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_InvalidAddressImplCopyWith(
+          _$BridgeError_InvalidAddressImpl value,
+          $Res Function(_$BridgeError_InvalidAddressImpl) then) =
+      __$$BridgeError_InvalidAddressImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_InvalidAddressImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_InvalidAddressImpl>
+    implements _$$BridgeError_InvalidAddressImplCopyWith<$Res> {
+  __$$BridgeError_InvalidAddressImplCopyWithImpl(
+      _$BridgeError_InvalidAddressImpl _value,
+      $Res Function(_$BridgeError_InvalidAddressImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_InvalidAddressImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_InvalidAddressImpl extends BridgeError_InvalidAddress {
+  const _$BridgeError_InvalidAddressImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.invalidAddress(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_InvalidAddressImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_InvalidAddressImplCopyWith<_$BridgeError_InvalidAddressImpl>
+      get copyWith => __$$BridgeError_InvalidAddressImplCopyWithImpl<
+          _$BridgeError_InvalidAddressImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return invalidAddress(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return invalidAddress?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidAddress != null) {
+      return invalidAddress(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return invalidAddress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return invalidAddress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidAddress != null) {
+      return invalidAddress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_InvalidAddress extends BridgeError {
+  const factory BridgeError_InvalidAddress(
+      {required final String detail}) = _$BridgeError_InvalidAddressImpl;
+  const BridgeError_InvalidAddress._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_InvalidAddressImplCopyWith<_$BridgeError_InvalidAddressImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_InvalidInputImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_InvalidInputImplCopyWith(
+          _$BridgeError_InvalidInputImpl value,
+          $Res Function(_$BridgeError_InvalidInputImpl) then) =
+      __$$BridgeError_InvalidInputImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_InvalidInputImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_InvalidInputImpl>
+    implements _$$BridgeError_InvalidInputImplCopyWith<$Res> {
+  __$$BridgeError_InvalidInputImplCopyWithImpl(
+      _$BridgeError_InvalidInputImpl _value,
+      $Res Function(_$BridgeError_InvalidInputImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_InvalidInputImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_InvalidInputImpl extends BridgeError_InvalidInput {
+  const _$BridgeError_InvalidInputImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.invalidInput(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_InvalidInputImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_InvalidInputImplCopyWith<_$BridgeError_InvalidInputImpl>
+      get copyWith => __$$BridgeError_InvalidInputImplCopyWithImpl<
+          _$BridgeError_InvalidInputImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return invalidInput(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return invalidInput?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidInput != null) {
+      return invalidInput(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return invalidInput(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return invalidInput?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (invalidInput != null) {
+      return invalidInput(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_InvalidInput extends BridgeError {
+  const factory BridgeError_InvalidInput(
+      {required final String detail}) = _$BridgeError_InvalidInputImpl;
+  const BridgeError_InvalidInput._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_InvalidInputImplCopyWith<_$BridgeError_InvalidInputImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_AccountErrorImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_AccountErrorImplCopyWith(
+          _$BridgeError_AccountErrorImpl value,
+          $Res Function(_$BridgeError_AccountErrorImpl) then) =
+      __$$BridgeError_AccountErrorImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_AccountErrorImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_AccountErrorImpl>
+    implements _$$BridgeError_AccountErrorImplCopyWith<$Res> {
+  __$$BridgeError_AccountErrorImplCopyWithImpl(
+      _$BridgeError_AccountErrorImpl _value,
+      $Res Function(_$BridgeError_AccountErrorImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_AccountErrorImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_AccountErrorImpl extends BridgeError_AccountError {
+  const _$BridgeError_AccountErrorImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.accountError(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_AccountErrorImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_AccountErrorImplCopyWith<_$BridgeError_AccountErrorImpl>
+      get copyWith => __$$BridgeError_AccountErrorImplCopyWithImpl<
+          _$BridgeError_AccountErrorImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return accountError(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return accountError?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (accountError != null) {
+      return accountError(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return accountError(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return accountError?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (accountError != null) {
+      return accountError(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_AccountError extends BridgeError {
+  const factory BridgeError_AccountError(
+      {required final String detail}) = _$BridgeError_AccountErrorImpl;
+  const BridgeError_AccountError._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_AccountErrorImplCopyWith<_$BridgeError_AccountErrorImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_TransactionErrorImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_TransactionErrorImplCopyWith(
+          _$BridgeError_TransactionErrorImpl value,
+          $Res Function(_$BridgeError_TransactionErrorImpl) then) =
+      __$$BridgeError_TransactionErrorImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_TransactionErrorImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_TransactionErrorImpl>
+    implements _$$BridgeError_TransactionErrorImplCopyWith<$Res> {
+  __$$BridgeError_TransactionErrorImplCopyWithImpl(
+      _$BridgeError_TransactionErrorImpl _value,
+      $Res Function(_$BridgeError_TransactionErrorImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_TransactionErrorImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_TransactionErrorImpl extends BridgeError_TransactionError {
+  const _$BridgeError_TransactionErrorImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.transactionError(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_TransactionErrorImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_TransactionErrorImplCopyWith<_$BridgeError_TransactionErrorImpl>
+      get copyWith => __$$BridgeError_TransactionErrorImplCopyWithImpl<
+          _$BridgeError_TransactionErrorImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return transactionError(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return transactionError?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (transactionError != null) {
+      return transactionError(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return transactionError(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return transactionError?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (transactionError != null) {
+      return transactionError(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_TransactionError extends BridgeError {
+  const factory BridgeError_TransactionError(
+      {required final String detail}) = _$BridgeError_TransactionErrorImpl;
+  const BridgeError_TransactionError._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_TransactionErrorImplCopyWith<_$BridgeError_TransactionErrorImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_PaymentProofErrorImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_PaymentProofErrorImplCopyWith(
+          _$BridgeError_PaymentProofErrorImpl value,
+          $Res Function(_$BridgeError_PaymentProofErrorImpl) then) =
+      __$$BridgeError_PaymentProofErrorImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_PaymentProofErrorImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_PaymentProofErrorImpl>
+    implements _$$BridgeError_PaymentProofErrorImplCopyWith<$Res> {
+  __$$BridgeError_PaymentProofErrorImplCopyWithImpl(
+      _$BridgeError_PaymentProofErrorImpl _value,
+      $Res Function(_$BridgeError_PaymentProofErrorImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_PaymentProofErrorImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_PaymentProofErrorImpl
+    extends BridgeError_PaymentProofError {
+  const _$BridgeError_PaymentProofErrorImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.paymentProofError(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_PaymentProofErrorImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_PaymentProofErrorImplCopyWith<_$BridgeError_PaymentProofErrorImpl>
+      get copyWith => __$$BridgeError_PaymentProofErrorImplCopyWithImpl<
+          _$BridgeError_PaymentProofErrorImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return paymentProofError(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return paymentProofError?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (paymentProofError != null) {
+      return paymentProofError(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return paymentProofError(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return paymentProofError?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (paymentProofError != null) {
+      return paymentProofError(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_PaymentProofError extends BridgeError {
+  const factory BridgeError_PaymentProofError(
+      {required final String detail}) = _$BridgeError_PaymentProofErrorImpl;
+  const BridgeError_PaymentProofError._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_PaymentProofErrorImplCopyWith<_$BridgeError_PaymentProofErrorImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_TorFailedImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_TorFailedImplCopyWith(
+          _$BridgeError_TorFailedImpl value,
+          $Res Function(_$BridgeError_TorFailedImpl) then) =
+      __$$BridgeError_TorFailedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_TorFailedImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_TorFailedImpl>
+    implements _$$BridgeError_TorFailedImplCopyWith<$Res> {
+  __$$BridgeError_TorFailedImplCopyWithImpl(
+      _$BridgeError_TorFailedImpl _value,
+      $Res Function(_$BridgeError_TorFailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_TorFailedImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_TorFailedImpl extends BridgeError_TorFailed {
+  const _$BridgeError_TorFailedImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.torFailed(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_TorFailedImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_TorFailedImplCopyWith<_$BridgeError_TorFailedImpl>
+      get copyWith => __$$BridgeError_TorFailedImplCopyWithImpl<
+          _$BridgeError_TorFailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return torFailed(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return torFailed?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (torFailed != null) {
+      return torFailed(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return torFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return torFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (torFailed != null) {
+      return torFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_TorFailed extends BridgeError {
+  const factory BridgeError_TorFailed(
+      {required final String detail}) = _$BridgeError_TorFailedImpl;
+  const BridgeError_TorFailed._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_TorFailedImplCopyWith<_$BridgeError_TorFailedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_ListenerFailedImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_ListenerFailedImplCopyWith(
+          _$BridgeError_ListenerFailedImpl value,
+          $Res Function(_$BridgeError_ListenerFailedImpl) then) =
+      __$$BridgeError_ListenerFailedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_ListenerFailedImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_ListenerFailedImpl>
+    implements _$$BridgeError_ListenerFailedImplCopyWith<$Res> {
+  __$$BridgeError_ListenerFailedImplCopyWithImpl(
+      _$BridgeError_ListenerFailedImpl _value,
+      $Res Function(_$BridgeError_ListenerFailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_ListenerFailedImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_ListenerFailedImpl extends BridgeError_ListenerFailed {
+  const _$BridgeError_ListenerFailedImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.listenerFailed(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_ListenerFailedImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_ListenerFailedImplCopyWith<_$BridgeError_ListenerFailedImpl>
+      get copyWith => __$$BridgeError_ListenerFailedImplCopyWithImpl<
+          _$BridgeError_ListenerFailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return listenerFailed(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return listenerFailed?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (listenerFailed != null) {
+      return listenerFailed(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return listenerFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return listenerFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (listenerFailed != null) {
+      return listenerFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_ListenerFailed extends BridgeError {
+  const factory BridgeError_ListenerFailed(
+      {required final String detail}) = _$BridgeError_ListenerFailedImpl;
+  const BridgeError_ListenerFailed._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_ListenerFailedImplCopyWith<_$BridgeError_ListenerFailedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_CancelledImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_CancelledImplCopyWith(
+          _$BridgeError_CancelledImpl value,
+          $Res Function(_$BridgeError_CancelledImpl) then) =
+      __$$BridgeError_CancelledImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_CancelledImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_CancelledImpl>
+    implements _$$BridgeError_CancelledImplCopyWith<$Res> {
+  __$$BridgeError_CancelledImplCopyWithImpl(
+      _$BridgeError_CancelledImpl _value,
+      $Res Function(_$BridgeError_CancelledImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_CancelledImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_CancelledImpl extends BridgeError_Cancelled {
+  const _$BridgeError_CancelledImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.cancelled(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_CancelledImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_CancelledImplCopyWith<_$BridgeError_CancelledImpl>
+      get copyWith => __$$BridgeError_CancelledImplCopyWithImpl<
+          _$BridgeError_CancelledImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return cancelled(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return cancelled?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return cancelled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return cancelled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_Cancelled extends BridgeError {
+  const factory BridgeError_Cancelled(
+      {required final String detail}) = _$BridgeError_CancelledImpl;
+  const BridgeError_Cancelled._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_CancelledImplCopyWith<_$BridgeError_CancelledImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$BridgeError_InternalImplCopyWith<$Res>
+    implements $BridgeErrorCopyWith<$Res> {
+  factory _$$BridgeError_InternalImplCopyWith(
+          _$BridgeError_InternalImpl value,
+          $Res Function(_$BridgeError_InternalImpl) then) =
+      __$$BridgeError_InternalImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String detail});
+}
+
+/// @nodoc
+class __$$BridgeError_InternalImplCopyWithImpl<$Res>
+    extends _$BridgeErrorCopyWithImpl<$Res, _$BridgeError_InternalImpl>
+    implements _$$BridgeError_InternalImplCopyWith<$Res> {
+  __$$BridgeError_InternalImplCopyWithImpl(
+      _$BridgeError_InternalImpl _value,
+      $Res Function(_$BridgeError_InternalImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? detail = null,
+  }) {
+    return _then(_$BridgeError_InternalImpl(
+      detail: null == detail
+          ? _value.detail
+          : detail // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BridgeError_InternalImpl extends BridgeError_Internal {
+  const _$BridgeError_InternalImpl({required this.detail}) : super._();
+
+  @override
+  final String detail;
+
+  @override
+  String toString() {
+    return 'BridgeError.internal(detail: $detail)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BridgeError_InternalImpl &&
+            (identical(other.detail, detail) || other.detail == detail));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, detail);
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BridgeError_InternalImplCopyWith<_$BridgeError_InternalImpl>
+      get copyWith => __$$BridgeError_InternalImplCopyWithImpl<
+          _$BridgeError_InternalImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String detail) notInitialized,
+    required TResult Function(String detail) walletNotOpen,
+    required TResult Function(String detail) walletAlreadyOpen,
+    required TResult Function(String detail) wrongPassword,
+    required TResult Function(String detail) insufficientFunds,
+    required TResult Function(String detail) nodeUnreachable,
+    required TResult Function(String detail) invalidSlatepack,
+    required TResult Function(String detail) invalidAddress,
+    required TResult Function(String detail) invalidInput,
+    required TResult Function(String detail) accountError,
+    required TResult Function(String detail) transactionError,
+    required TResult Function(String detail) paymentProofError,
+    required TResult Function(String detail) torFailed,
+    required TResult Function(String detail) listenerFailed,
+    required TResult Function(String detail) cancelled,
+    required TResult Function(String detail) internal,
+  }) {
+    return internal(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String detail)? notInitialized,
+    TResult? Function(String detail)? walletNotOpen,
+    TResult? Function(String detail)? walletAlreadyOpen,
+    TResult? Function(String detail)? wrongPassword,
+    TResult? Function(String detail)? insufficientFunds,
+    TResult? Function(String detail)? nodeUnreachable,
+    TResult? Function(String detail)? invalidSlatepack,
+    TResult? Function(String detail)? invalidAddress,
+    TResult? Function(String detail)? invalidInput,
+    TResult? Function(String detail)? accountError,
+    TResult? Function(String detail)? transactionError,
+    TResult? Function(String detail)? paymentProofError,
+    TResult? Function(String detail)? torFailed,
+    TResult? Function(String detail)? listenerFailed,
+    TResult? Function(String detail)? cancelled,
+    TResult? Function(String detail)? internal,
+  }) {
+    return internal?.call(detail);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String detail)? notInitialized,
+    TResult Function(String detail)? walletNotOpen,
+    TResult Function(String detail)? walletAlreadyOpen,
+    TResult Function(String detail)? wrongPassword,
+    TResult Function(String detail)? insufficientFunds,
+    TResult Function(String detail)? nodeUnreachable,
+    TResult Function(String detail)? invalidSlatepack,
+    TResult Function(String detail)? invalidAddress,
+    TResult Function(String detail)? invalidInput,
+    TResult Function(String detail)? accountError,
+    TResult Function(String detail)? transactionError,
+    TResult Function(String detail)? paymentProofError,
+    TResult Function(String detail)? torFailed,
+    TResult Function(String detail)? listenerFailed,
+    TResult Function(String detail)? cancelled,
+    TResult Function(String detail)? internal,
+    required TResult orElse(),
+  }) {
+    if (internal != null) {
+      return internal(detail);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BridgeError_NotInitialized value) notInitialized,
+    required TResult Function(BridgeError_WalletNotOpen value) walletNotOpen,
+    required TResult Function(BridgeError_WalletAlreadyOpen value)
+        walletAlreadyOpen,
+    required TResult Function(BridgeError_WrongPassword value) wrongPassword,
+    required TResult Function(BridgeError_InsufficientFunds value)
+        insufficientFunds,
+    required TResult Function(BridgeError_NodeUnreachable value)
+        nodeUnreachable,
+    required TResult Function(BridgeError_InvalidSlatepack value)
+        invalidSlatepack,
+    required TResult Function(BridgeError_InvalidAddress value) invalidAddress,
+    required TResult Function(BridgeError_InvalidInput value) invalidInput,
+    required TResult Function(BridgeError_AccountError value) accountError,
+    required TResult Function(BridgeError_TransactionError value)
+        transactionError,
+    required TResult Function(BridgeError_PaymentProofError value)
+        paymentProofError,
+    required TResult Function(BridgeError_TorFailed value) torFailed,
+    required TResult Function(BridgeError_ListenerFailed value) listenerFailed,
+    required TResult Function(BridgeError_Cancelled value) cancelled,
+    required TResult Function(BridgeError_Internal value) internal,
+  }) {
+    return internal(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult? Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult? Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult? Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult? Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult? Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult? Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult? Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult? Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult? Function(BridgeError_AccountError value)? accountError,
+    TResult? Function(BridgeError_TransactionError value)? transactionError,
+    TResult? Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult? Function(BridgeError_TorFailed value)? torFailed,
+    TResult? Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult? Function(BridgeError_Cancelled value)? cancelled,
+    TResult? Function(BridgeError_Internal value)? internal,
+  }) {
+    return internal?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BridgeError_NotInitialized value)? notInitialized,
+    TResult Function(BridgeError_WalletNotOpen value)? walletNotOpen,
+    TResult Function(BridgeError_WalletAlreadyOpen value)? walletAlreadyOpen,
+    TResult Function(BridgeError_WrongPassword value)? wrongPassword,
+    TResult Function(BridgeError_InsufficientFunds value)? insufficientFunds,
+    TResult Function(BridgeError_NodeUnreachable value)? nodeUnreachable,
+    TResult Function(BridgeError_InvalidSlatepack value)? invalidSlatepack,
+    TResult Function(BridgeError_InvalidAddress value)? invalidAddress,
+    TResult Function(BridgeError_InvalidInput value)? invalidInput,
+    TResult Function(BridgeError_AccountError value)? accountError,
+    TResult Function(BridgeError_TransactionError value)? transactionError,
+    TResult Function(BridgeError_PaymentProofError value)? paymentProofError,
+    TResult Function(BridgeError_TorFailed value)? torFailed,
+    TResult Function(BridgeError_ListenerFailed value)? listenerFailed,
+    TResult Function(BridgeError_Cancelled value)? cancelled,
+    TResult Function(BridgeError_Internal value)? internal,
+    required TResult orElse(),
+  }) {
+    if (internal != null) {
+      return internal(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BridgeError_Internal extends BridgeError {
+  const factory BridgeError_Internal(
+      {required final String detail}) = _$BridgeError_InternalImpl;
+  const BridgeError_Internal._() : super._();
+
+  @override
+  String get detail;
+
+  /// Create a copy of BridgeError
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BridgeError_InternalImplCopyWith<_$BridgeError_InternalImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
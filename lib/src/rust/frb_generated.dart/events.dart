@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'models.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+part 'events.freezed.dart';
+
+@freezed
+sealed class WalletEventDto with _$WalletEventDto {
+  const WalletEventDto._();
+
+  const factory WalletEventDto.autoLocked({
+    required BigInt handle,
+    required BigInt idleSecs,
+  }) = WalletEventDto_AutoLocked;
+
+  /// Neue, noch unbestaetigte Eingangs-Tx (typischerweise ueber den Foreign Listener).
+  const factory WalletEventDto.incomingSlate({
+    required BigInt handle,
+    required TransactionDto tx,
+  }) = WalletEventDto_IncomingSlate;
+
+  const factory WalletEventDto.confirmationsChanged({
+    required BigInt handle,
+    required int txId,
+    required BigInt confirmations,
+  }) = WalletEventDto_ConfirmationsChanged;
+
+  const factory WalletEventDto.balanceChanged({
+    required BigInt handle,
+    required WalletInfoDto balance,
+  }) = WalletEventDto_BalanceChanged;
+
+  const factory WalletEventDto.txReverted({
+    required BigInt handle,
+    required int txId,
+  }) = WalletEventDto_TxReverted;
+
+  /// Tx wurde nach Ablauf ihres TTL storniert; bei Sends sind die Inputs wieder frei.
+  const factory WalletEventDto.txExpired({
+    required BigInt handle,
+    required int txId,
+    String? txSlateId,
+    required BigInt unlockedAmount,
+    required BigInt unlockedInputs,
+  }) = WalletEventDto_TxExpired;
+
+  const factory WalletEventDto.scanProgress({
+    required BigInt handle,
+    required ScanProgressDto progress,
+  }) = WalletEventDto_ScanProgress;
+
+  const factory WalletEventDto.scanCompleted({
+    required BigInt handle,
+    required ScanResultDto result,
+  }) = WalletEventDto_ScanCompleted;
+
+  /// Fortschritt des Owner-Updaters oder eines Scans (Prozent, aktueller Schritt).
+  const factory WalletEventDto.updaterProgress({
+    required BigInt handle,
+    required UpdaterMessageDto message,
+  }) = WalletEventDto_UpdaterProgress;
+
+  /// Gilt fuer alle Wallets, da die Node-URL global ist.
+  const factory WalletEventDto.nodeConnectivityChanged({
+    required bool reachable,
+    BigInt? tipHeight,
+  }) = WalletEventDto_NodeConnectivityChanged;
+}
@@ -0,0 +1,2769 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'events.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$WalletEventDto {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $WalletEventDtoCopyWith<$Res> {
+  factory $WalletEventDtoCopyWith(
+          WalletEventDto value, $Res Function(WalletEventDto) then) =
+      _$WalletEventDtoCopyWithImpl<$Res, WalletEventDto>;
+}
+
+/// @nodoc
+class _$WalletEventDtoCopyWithImpl<$Res, $Val extends WalletEventDto>
+    implements $WalletEventDtoCopyWith<$Res> {
+  _$WalletEventDtoCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$WalletEventDto_AutoLockedImplCopyWith<$Res> {
+  factory _$$WalletEventDto_AutoLockedImplCopyWith(
+          _$WalletEventDto_AutoLockedImpl value,
+          $Res Function(_$WalletEventDto_AutoLockedImpl) then) =
+      __$$WalletEventDto_AutoLockedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, BigInt idleSecs});
+}
+
+/// @nodoc
+class __$$WalletEventDto_AutoLockedImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_AutoLockedImpl>
+    implements _$$WalletEventDto_AutoLockedImplCopyWith<$Res> {
+  __$$WalletEventDto_AutoLockedImplCopyWithImpl(
+      _$WalletEventDto_AutoLockedImpl _value,
+      $Res Function(_$WalletEventDto_AutoLockedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? idleSecs = null,
+  }) {
+    return _then(_$WalletEventDto_AutoLockedImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      idleSecs: null == idleSecs
+          ? _value.idleSecs
+          : idleSecs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_AutoLockedImpl extends WalletEventDto_AutoLocked {
+  const _$WalletEventDto_AutoLockedImpl(
+      {required this.handle, required this.idleSecs})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final BigInt idleSecs;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.autoLocked(handle: $handle, idleSecs: $idleSecs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_AutoLockedImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.idleSecs, idleSecs) ||
+                other.idleSecs == idleSecs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, idleSecs);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_AutoLockedImplCopyWith<_$WalletEventDto_AutoLockedImpl>
+      get copyWith => __$$WalletEventDto_AutoLockedImplCopyWithImpl<
+          _$WalletEventDto_AutoLockedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return autoLocked(handle, idleSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return autoLocked?.call(handle, idleSecs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (autoLocked != null) {
+      return autoLocked(handle, idleSecs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return autoLocked(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return autoLocked?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (autoLocked != null) {
+      return autoLocked(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_AutoLocked extends WalletEventDto {
+  const factory WalletEventDto_AutoLocked(
+      {required final BigInt handle,
+      required final BigInt idleSecs}) = _$WalletEventDto_AutoLockedImpl;
+  const WalletEventDto_AutoLocked._() : super._();
+
+  BigInt get handle;
+  BigInt get idleSecs;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_AutoLockedImplCopyWith<_$WalletEventDto_AutoLockedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_IncomingSlateImplCopyWith<$Res> {
+  factory _$$WalletEventDto_IncomingSlateImplCopyWith(
+          _$WalletEventDto_IncomingSlateImpl value,
+          $Res Function(_$WalletEventDto_IncomingSlateImpl) then) =
+      __$$WalletEventDto_IncomingSlateImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, TransactionDto tx});
+}
+
+/// @nodoc
+class __$$WalletEventDto_IncomingSlateImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_IncomingSlateImpl>
+    implements _$$WalletEventDto_IncomingSlateImplCopyWith<$Res> {
+  __$$WalletEventDto_IncomingSlateImplCopyWithImpl(
+      _$WalletEventDto_IncomingSlateImpl _value,
+      $Res Function(_$WalletEventDto_IncomingSlateImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? tx = null,
+  }) {
+    return _then(_$WalletEventDto_IncomingSlateImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      tx: null == tx
+          ? _value.tx
+          : tx // ignore: cast_nullable_to_non_nullable
+              as TransactionDto,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_IncomingSlateImpl extends WalletEventDto_IncomingSlate {
+  const _$WalletEventDto_IncomingSlateImpl(
+      {required this.handle, required this.tx})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final TransactionDto tx;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.incomingSlate(handle: $handle, tx: $tx)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_IncomingSlateImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.tx, tx) || other.tx == tx));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, tx);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_IncomingSlateImplCopyWith<_$WalletEventDto_IncomingSlateImpl>
+      get copyWith => __$$WalletEventDto_IncomingSlateImplCopyWithImpl<
+          _$WalletEventDto_IncomingSlateImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return incomingSlate(handle, tx);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return incomingSlate?.call(handle, tx);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (incomingSlate != null) {
+      return incomingSlate(handle, tx);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return incomingSlate(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return incomingSlate?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (incomingSlate != null) {
+      return incomingSlate(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_IncomingSlate extends WalletEventDto {
+  const factory WalletEventDto_IncomingSlate(
+      {required final BigInt handle,
+      required final TransactionDto tx}) = _$WalletEventDto_IncomingSlateImpl;
+  const WalletEventDto_IncomingSlate._() : super._();
+
+  BigInt get handle;
+  TransactionDto get tx;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_IncomingSlateImplCopyWith<_$WalletEventDto_IncomingSlateImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_ConfirmationsChangedImplCopyWith<$Res> {
+  factory _$$WalletEventDto_ConfirmationsChangedImplCopyWith(
+          _$WalletEventDto_ConfirmationsChangedImpl value,
+          $Res Function(_$WalletEventDto_ConfirmationsChangedImpl) then) =
+      __$$WalletEventDto_ConfirmationsChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, int txId, BigInt confirmations});
+}
+
+/// @nodoc
+class __$$WalletEventDto_ConfirmationsChangedImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_ConfirmationsChangedImpl>
+    implements _$$WalletEventDto_ConfirmationsChangedImplCopyWith<$Res> {
+  __$$WalletEventDto_ConfirmationsChangedImplCopyWithImpl(
+      _$WalletEventDto_ConfirmationsChangedImpl _value,
+      $Res Function(_$WalletEventDto_ConfirmationsChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? txId = null,
+    Object? confirmations = null,
+  }) {
+    return _then(_$WalletEventDto_ConfirmationsChangedImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      txId: null == txId
+          ? _value.txId
+          : txId // ignore: cast_nullable_to_non_nullable
+              as int,
+      confirmations: null == confirmations
+          ? _value.confirmations
+          : confirmations // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_ConfirmationsChangedImpl
+    extends WalletEventDto_ConfirmationsChanged {
+  const _$WalletEventDto_ConfirmationsChangedImpl(
+      {required this.handle, required this.txId, required this.confirmations})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final int txId;
+  @override
+  final BigInt confirmations;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.confirmationsChanged(handle: $handle, txId: $txId, confirmations: $confirmations)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_ConfirmationsChangedImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.txId, txId) || other.txId == txId) &&
+            (identical(other.confirmations, confirmations) ||
+                other.confirmations == confirmations));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, txId, confirmations);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_ConfirmationsChangedImplCopyWith<_$WalletEventDto_ConfirmationsChangedImpl>
+      get copyWith => __$$WalletEventDto_ConfirmationsChangedImplCopyWithImpl<
+          _$WalletEventDto_ConfirmationsChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return confirmationsChanged(handle, txId, confirmations);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return confirmationsChanged?.call(handle, txId, confirmations);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (confirmationsChanged != null) {
+      return confirmationsChanged(handle, txId, confirmations);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return confirmationsChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return confirmationsChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (confirmationsChanged != null) {
+      return confirmationsChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_ConfirmationsChanged extends WalletEventDto {
+  const factory WalletEventDto_ConfirmationsChanged(
+      {required final BigInt handle,
+      required final int txId,
+      required final BigInt confirmations}) = _$WalletEventDto_ConfirmationsChangedImpl;
+  const WalletEventDto_ConfirmationsChanged._() : super._();
+
+  BigInt get handle;
+  int get txId;
+  BigInt get confirmations;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_ConfirmationsChangedImplCopyWith<_$WalletEventDto_ConfirmationsChangedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_BalanceChangedImplCopyWith<$Res> {
+  factory _$$WalletEventDto_BalanceChangedImplCopyWith(
+          _$WalletEventDto_BalanceChangedImpl value,
+          $Res Function(_$WalletEventDto_BalanceChangedImpl) then) =
+      __$$WalletEventDto_BalanceChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, WalletInfoDto balance});
+}
+
+/// @nodoc
+class __$$WalletEventDto_BalanceChangedImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_BalanceChangedImpl>
+    implements _$$WalletEventDto_BalanceChangedImplCopyWith<$Res> {
+  __$$WalletEventDto_BalanceChangedImplCopyWithImpl(
+      _$WalletEventDto_BalanceChangedImpl _value,
+      $Res Function(_$WalletEventDto_BalanceChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? balance = null,
+  }) {
+    return _then(_$WalletEventDto_BalanceChangedImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      balance: null == balance
+          ? _value.balance
+          : balance // ignore: cast_nullable_to_non_nullable
+              as WalletInfoDto,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_BalanceChangedImpl
+    extends WalletEventDto_BalanceChanged {
+  const _$WalletEventDto_BalanceChangedImpl(
+      {required this.handle, required this.balance})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final WalletInfoDto balance;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.balanceChanged(handle: $handle, balance: $balance)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_BalanceChangedImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.balance, balance) || other.balance == balance));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, balance);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_BalanceChangedImplCopyWith<_$WalletEventDto_BalanceChangedImpl>
+      get copyWith => __$$WalletEventDto_BalanceChangedImplCopyWithImpl<
+          _$WalletEventDto_BalanceChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return balanceChanged(handle, balance);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return balanceChanged?.call(handle, balance);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (balanceChanged != null) {
+      return balanceChanged(handle, balance);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return balanceChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return balanceChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (balanceChanged != null) {
+      return balanceChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_BalanceChanged extends WalletEventDto {
+  const factory WalletEventDto_BalanceChanged(
+      {required final BigInt handle,
+      required final WalletInfoDto balance}) = _$WalletEventDto_BalanceChangedImpl;
+  const WalletEventDto_BalanceChanged._() : super._();
+
+  BigInt get handle;
+  WalletInfoDto get balance;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_BalanceChangedImplCopyWith<_$WalletEventDto_BalanceChangedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_TxRevertedImplCopyWith<$Res> {
+  factory _$$WalletEventDto_TxRevertedImplCopyWith(
+          _$WalletEventDto_TxRevertedImpl value,
+          $Res Function(_$WalletEventDto_TxRevertedImpl) then) =
+      __$$WalletEventDto_TxRevertedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, int txId});
+}
+
+/// @nodoc
+class __$$WalletEventDto_TxRevertedImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_TxRevertedImpl>
+    implements _$$WalletEventDto_TxRevertedImplCopyWith<$Res> {
+  __$$WalletEventDto_TxRevertedImplCopyWithImpl(
+      _$WalletEventDto_TxRevertedImpl _value,
+      $Res Function(_$WalletEventDto_TxRevertedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? txId = null,
+  }) {
+    return _then(_$WalletEventDto_TxRevertedImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      txId: null == txId
+          ? _value.txId
+          : txId // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_TxRevertedImpl extends WalletEventDto_TxReverted {
+  const _$WalletEventDto_TxRevertedImpl(
+      {required this.handle, required this.txId})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final int txId;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.txReverted(handle: $handle, txId: $txId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_TxRevertedImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.txId, txId) || other.txId == txId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, txId);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_TxRevertedImplCopyWith<_$WalletEventDto_TxRevertedImpl>
+      get copyWith => __$$WalletEventDto_TxRevertedImplCopyWithImpl<
+          _$WalletEventDto_TxRevertedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return txReverted(handle, txId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return txReverted?.call(handle, txId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (txReverted != null) {
+      return txReverted(handle, txId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return txReverted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return txReverted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (txReverted != null) {
+      return txReverted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_TxReverted extends WalletEventDto {
+  const factory WalletEventDto_TxReverted(
+      {required final BigInt handle,
+      required final int txId}) = _$WalletEventDto_TxRevertedImpl;
+  const WalletEventDto_TxReverted._() : super._();
+
+  BigInt get handle;
+  int get txId;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_TxRevertedImplCopyWith<_$WalletEventDto_TxRevertedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_TxExpiredImplCopyWith<$Res> {
+  factory _$$WalletEventDto_TxExpiredImplCopyWith(
+          _$WalletEventDto_TxExpiredImpl value,
+          $Res Function(_$WalletEventDto_TxExpiredImpl) then) =
+      __$$WalletEventDto_TxExpiredImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call(
+      {BigInt handle,
+      int txId,
+      String? txSlateId,
+      BigInt unlockedAmount,
+      BigInt unlockedInputs});
+}
+
+/// @nodoc
+class __$$WalletEventDto_TxExpiredImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_TxExpiredImpl>
+    implements _$$WalletEventDto_TxExpiredImplCopyWith<$Res> {
+  __$$WalletEventDto_TxExpiredImplCopyWithImpl(
+      _$WalletEventDto_TxExpiredImpl _value,
+      $Res Function(_$WalletEventDto_TxExpiredImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? txId = null,
+    Object? txSlateId = freezed,
+    Object? unlockedAmount = null,
+    Object? unlockedInputs = null,
+  }) {
+    return _then(_$WalletEventDto_TxExpiredImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      txId: null == txId
+          ? _value.txId
+          : txId // ignore: cast_nullable_to_non_nullable
+              as int,
+      txSlateId: freezed == txSlateId
+          ? _value.txSlateId
+          : txSlateId // ignore: cast_nullable_to_non_nullable
+              as String?,
+      unlockedAmount: null == unlockedAmount
+          ? _value.unlockedAmount
+          : unlockedAmount // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      unlockedInputs: null == unlockedInputs
+          ? _value.unlockedInputs
+          : unlockedInputs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_TxExpiredImpl extends WalletEventDto_TxExpired {
+  const _$WalletEventDto_TxExpiredImpl(
+      {required this.handle,
+      required this.txId,
+      this.txSlateId,
+      required this.unlockedAmount,
+      required this.unlockedInputs})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final int txId;
+  @override
+  final String? txSlateId;
+  @override
+  final BigInt unlockedAmount;
+  @override
+  final BigInt unlockedInputs;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.txExpired(handle: $handle, txId: $txId, txSlateId: $txSlateId, unlockedAmount: $unlockedAmount, unlockedInputs: $unlockedInputs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_TxExpiredImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.txId, txId) || other.txId == txId) &&
+            (identical(other.txSlateId, txSlateId) ||
+                other.txSlateId == txSlateId) &&
+            (identical(other.unlockedAmount, unlockedAmount) ||
+                other.unlockedAmount == unlockedAmount) &&
+            (identical(other.unlockedInputs, unlockedInputs) ||
+                other.unlockedInputs == unlockedInputs));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, handle, txId, txSlateId, unlockedAmount, unlockedInputs);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_TxExpiredImplCopyWith<_$WalletEventDto_TxExpiredImpl>
+      get copyWith => __$$WalletEventDto_TxExpiredImplCopyWithImpl<
+          _$WalletEventDto_TxExpiredImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return txExpired(handle, txId, txSlateId, unlockedAmount, unlockedInputs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return txExpired?.call(handle, txId, txSlateId, unlockedAmount, unlockedInputs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (txExpired != null) {
+      return txExpired(handle, txId, txSlateId, unlockedAmount, unlockedInputs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return txExpired(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return txExpired?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (txExpired != null) {
+      return txExpired(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_TxExpired extends WalletEventDto {
+  const factory WalletEventDto_TxExpired(
+      {required final BigInt handle,
+      required final int txId,
+      final String? txSlateId,
+      required final BigInt unlockedAmount,
+      required final BigInt unlockedInputs}) = _$WalletEventDto_TxExpiredImpl;
+  const WalletEventDto_TxExpired._() : super._();
+
+  BigInt get handle;
+  int get txId;
+  String? get txSlateId;
+  BigInt get unlockedAmount;
+  BigInt get unlockedInputs;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_TxExpiredImplCopyWith<_$WalletEventDto_TxExpiredImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_ScanProgressImplCopyWith<$Res> {
+  factory _$$WalletEventDto_ScanProgressImplCopyWith(
+          _$WalletEventDto_ScanProgressImpl value,
+          $Res Function(_$WalletEventDto_ScanProgressImpl) then) =
+      __$$WalletEventDto_ScanProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, ScanProgressDto progress});
+}
+
+/// @nodoc
+class __$$WalletEventDto_ScanProgressImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_ScanProgressImpl>
+    implements _$$WalletEventDto_ScanProgressImplCopyWith<$Res> {
+  __$$WalletEventDto_ScanProgressImplCopyWithImpl(
+      _$WalletEventDto_ScanProgressImpl _value,
+      $Res Function(_$WalletEventDto_ScanProgressImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? progress = null,
+  }) {
+    return _then(_$WalletEventDto_ScanProgressImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      progress: null == progress
+          ? _value.progress
+          : progress // ignore: cast_nullable_to_non_nullable
+              as ScanProgressDto,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_ScanProgressImpl extends WalletEventDto_ScanProgress {
+  const _$WalletEventDto_ScanProgressImpl(
+      {required this.handle, required this.progress})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final ScanProgressDto progress;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.scanProgress(handle: $handle, progress: $progress)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_ScanProgressImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.progress, progress) ||
+                other.progress == progress));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, progress);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_ScanProgressImplCopyWith<_$WalletEventDto_ScanProgressImpl>
+      get copyWith => __$$WalletEventDto_ScanProgressImplCopyWithImpl<
+          _$WalletEventDto_ScanProgressImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return scanProgress(handle, progress);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return scanProgress?.call(handle, progress);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (scanProgress != null) {
+      return scanProgress(handle, progress);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return scanProgress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return scanProgress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (scanProgress != null) {
+      return scanProgress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_ScanProgress extends WalletEventDto {
+  const factory WalletEventDto_ScanProgress(
+      {required final BigInt handle,
+      required final ScanProgressDto progress}) = _$WalletEventDto_ScanProgressImpl;
+  const WalletEventDto_ScanProgress._() : super._();
+
+  BigInt get handle;
+  ScanProgressDto get progress;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_ScanProgressImplCopyWith<_$WalletEventDto_ScanProgressImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_ScanCompletedImplCopyWith<$Res> {
+  factory _$$WalletEventDto_ScanCompletedImplCopyWith(
+          _$WalletEventDto_ScanCompletedImpl value,
+          $Res Function(_$WalletEventDto_ScanCompletedImpl) then) =
+      __$$WalletEventDto_ScanCompletedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, ScanResultDto result});
+}
+
+/// @nodoc
+class __$$WalletEventDto_ScanCompletedImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_ScanCompletedImpl>
+    implements _$$WalletEventDto_ScanCompletedImplCopyWith<$Res> {
+  __$$WalletEventDto_ScanCompletedImplCopyWithImpl(
+      _$WalletEventDto_ScanCompletedImpl _value,
+      $Res Function(_$WalletEventDto_ScanCompletedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? result = null,
+  }) {
+    return _then(_$WalletEventDto_ScanCompletedImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      result: null == result
+          ? _value.result
+          : result // ignore: cast_nullable_to_non_nullable
+              as ScanResultDto,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_ScanCompletedImpl extends WalletEventDto_ScanCompleted {
+  const _$WalletEventDto_ScanCompletedImpl(
+      {required this.handle, required this.result})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final ScanResultDto result;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.scanCompleted(handle: $handle, result: $result)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_ScanCompletedImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.result, result) || other.result == result));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, result);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_ScanCompletedImplCopyWith<_$WalletEventDto_ScanCompletedImpl>
+      get copyWith => __$$WalletEventDto_ScanCompletedImplCopyWithImpl<
+          _$WalletEventDto_ScanCompletedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return scanCompleted(handle, result);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return scanCompleted?.call(handle, result);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (scanCompleted != null) {
+      return scanCompleted(handle, result);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return scanCompleted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return scanCompleted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (scanCompleted != null) {
+      return scanCompleted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_ScanCompleted extends WalletEventDto {
+  const factory WalletEventDto_ScanCompleted(
+      {required final BigInt handle,
+      required final ScanResultDto result}) = _$WalletEventDto_ScanCompletedImpl;
+  const WalletEventDto_ScanCompleted._() : super._();
+
+  BigInt get handle;
+  ScanResultDto get result;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_ScanCompletedImplCopyWith<_$WalletEventDto_ScanCompletedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_UpdaterProgressImplCopyWith<$Res> {
+  factory _$$WalletEventDto_UpdaterProgressImplCopyWith(
+          _$WalletEventDto_UpdaterProgressImpl value,
+          $Res Function(_$WalletEventDto_UpdaterProgressImpl) then) =
+      __$$WalletEventDto_UpdaterProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt handle, UpdaterMessageDto message});
+
+  $UpdaterMessageDtoCopyWith<$Res> get message;
+}
+
+/// @nodoc
+class __$$WalletEventDto_UpdaterProgressImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_UpdaterProgressImpl>
+    implements _$$WalletEventDto_UpdaterProgressImplCopyWith<$Res> {
+  __$$WalletEventDto_UpdaterProgressImplCopyWithImpl(
+      _$WalletEventDto_UpdaterProgressImpl _value,
+      $Res Function(_$WalletEventDto_UpdaterProgressImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? handle = null,
+    Object? message = null,
+  }) {
+    return _then(_$WalletEventDto_UpdaterProgressImpl(
+      handle: null == handle
+          ? _value.handle
+          : handle // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as UpdaterMessageDto,
+    ));
+  }
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $UpdaterMessageDtoCopyWith<$Res> get message {
+    return $UpdaterMessageDtoCopyWith<$Res>(_value.message, (value) {
+      return _then(_value.copyWith(message: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_UpdaterProgressImpl
+    extends WalletEventDto_UpdaterProgress {
+  const _$WalletEventDto_UpdaterProgressImpl(
+      {required this.handle, required this.message})
+      : super._();
+
+  @override
+  final BigInt handle;
+  @override
+  final UpdaterMessageDto message;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.updaterProgress(handle: $handle, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_UpdaterProgressImpl &&
+            (identical(other.handle, handle) || other.handle == handle) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, handle, message);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_UpdaterProgressImplCopyWith<_$WalletEventDto_UpdaterProgressImpl>
+      get copyWith => __$$WalletEventDto_UpdaterProgressImplCopyWithImpl<
+          _$WalletEventDto_UpdaterProgressImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return updaterProgress(handle, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return updaterProgress?.call(handle, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (updaterProgress != null) {
+      return updaterProgress(handle, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return updaterProgress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return updaterProgress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (updaterProgress != null) {
+      return updaterProgress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_UpdaterProgress extends WalletEventDto {
+  const factory WalletEventDto_UpdaterProgress(
+      {required final BigInt handle,
+      required final UpdaterMessageDto message}) = _$WalletEventDto_UpdaterProgressImpl;
+  const WalletEventDto_UpdaterProgress._() : super._();
+
+  BigInt get handle;
+  UpdaterMessageDto get message;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_UpdaterProgressImplCopyWith<_$WalletEventDto_UpdaterProgressImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$WalletEventDto_NodeConnectivityChangedImplCopyWith<$Res> {
+  factory _$$WalletEventDto_NodeConnectivityChangedImplCopyWith(
+          _$WalletEventDto_NodeConnectivityChangedImpl value,
+          $Res Function(_$WalletEventDto_NodeConnectivityChangedImpl) then) =
+      __$$WalletEventDto_NodeConnectivityChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool reachable, BigInt? tipHeight});
+}
+
+/// @nodoc
+class __$$WalletEventDto_NodeConnectivityChangedImplCopyWithImpl<$Res>
+    extends _$WalletEventDtoCopyWithImpl<$Res, _$WalletEventDto_NodeConnectivityChangedImpl>
+    implements _$$WalletEventDto_NodeConnectivityChangedImplCopyWith<$Res> {
+  __$$WalletEventDto_NodeConnectivityChangedImplCopyWithImpl(
+      _$WalletEventDto_NodeConnectivityChangedImpl _value,
+      $Res Function(_$WalletEventDto_NodeConnectivityChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? reachable = null,
+    Object? tipHeight = freezed,
+  }) {
+    return _then(_$WalletEventDto_NodeConnectivityChangedImpl(
+      reachable: null == reachable
+          ? _value.reachable
+          : reachable // ignore: cast_nullable_to_non_nullable
+              as bool,
+      tipHeight: freezed == tipHeight
+          ? _value.tipHeight
+          : tipHeight // ignore: cast_nullable_to_non_nullable
+              as BigInt?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$WalletEventDto_NodeConnectivityChangedImpl
+    extends WalletEventDto_NodeConnectivityChanged {
+  const _$WalletEventDto_NodeConnectivityChangedImpl(
+      {required this.reachable, this.tipHeight})
+      : super._();
+
+  @override
+  final bool reachable;
+  @override
+  final BigInt? tipHeight;
+
+  @override
+  String toString() {
+    return 'WalletEventDto.nodeConnectivityChanged(reachable: $reachable, tipHeight: $tipHeight)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$WalletEventDto_NodeConnectivityChangedImpl &&
+            (identical(other.reachable, reachable) ||
+                other.reachable == reachable) &&
+            (identical(other.tipHeight, tipHeight) ||
+                other.tipHeight == tipHeight));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, reachable, tipHeight);
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$WalletEventDto_NodeConnectivityChangedImplCopyWith<_$WalletEventDto_NodeConnectivityChangedImpl>
+      get copyWith => __$$WalletEventDto_NodeConnectivityChangedImplCopyWithImpl<
+          _$WalletEventDto_NodeConnectivityChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt handle, BigInt idleSecs) autoLocked,
+    required TResult Function(BigInt handle, TransactionDto tx) incomingSlate,
+    required TResult Function(
+            BigInt handle, int txId, BigInt confirmations)
+        confirmationsChanged,
+    required TResult Function(
+            BigInt handle, WalletInfoDto balance)
+        balanceChanged,
+    required TResult Function(BigInt handle, int txId) txReverted,
+    required TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)
+        txExpired,
+    required TResult Function(
+            BigInt handle, ScanProgressDto progress)
+        scanProgress,
+    required TResult Function(
+            BigInt handle, ScanResultDto result)
+        scanCompleted,
+    required TResult Function(
+            BigInt handle, UpdaterMessageDto message)
+        updaterProgress,
+    required TResult Function(
+            bool reachable, BigInt? tipHeight)
+        nodeConnectivityChanged,
+  }) {
+    return nodeConnectivityChanged(reachable, tipHeight);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult? Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult? Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult? Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult? Function(BigInt handle, int txId)? txReverted,
+    TResult? Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult? Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult? Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult? Function(
+            BigInt handle, UpdaterMessageDto message)?
+        updaterProgress,
+    TResult? Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+  }) {
+    return nodeConnectivityChanged?.call(reachable, tipHeight);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt handle, BigInt idleSecs)? autoLocked,
+    TResult Function(BigInt handle, TransactionDto tx)? incomingSlate,
+    TResult Function(
+            BigInt handle, int txId, BigInt confirmations)?
+        confirmationsChanged,
+    TResult Function(BigInt handle, WalletInfoDto balance)? balanceChanged,
+    TResult Function(BigInt handle, int txId)? txReverted,
+    TResult Function(
+            BigInt handle,
+            int txId,
+            String? txSlateId,
+            BigInt unlockedAmount,
+            BigInt unlockedInputs)?
+        txExpired,
+    TResult Function(BigInt handle, ScanProgressDto progress)? scanProgress,
+    TResult Function(BigInt handle, ScanResultDto result)? scanCompleted,
+    TResult Function(BigInt handle, UpdaterMessageDto message)? updaterProgress,
+    TResult Function(
+            bool reachable, BigInt? tipHeight)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (nodeConnectivityChanged != null) {
+      return nodeConnectivityChanged(reachable, tipHeight);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(WalletEventDto_AutoLocked value) autoLocked,
+    required TResult Function(WalletEventDto_IncomingSlate value) incomingSlate,
+    required TResult Function(WalletEventDto_ConfirmationsChanged value)
+        confirmationsChanged,
+    required TResult Function(WalletEventDto_BalanceChanged value)
+        balanceChanged,
+    required TResult Function(WalletEventDto_TxReverted value) txReverted,
+    required TResult Function(WalletEventDto_TxExpired value) txExpired,
+    required TResult Function(WalletEventDto_ScanProgress value) scanProgress,
+    required TResult Function(WalletEventDto_ScanCompleted value) scanCompleted,
+    required TResult Function(WalletEventDto_UpdaterProgress value)
+        updaterProgress,
+    required TResult Function(WalletEventDto_NodeConnectivityChanged value)
+        nodeConnectivityChanged,
+  }) {
+    return nodeConnectivityChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult? Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult? Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult? Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult? Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult? Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult? Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult? Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult? Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult? Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+  }) {
+    return nodeConnectivityChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(WalletEventDto_AutoLocked value)? autoLocked,
+    TResult Function(WalletEventDto_IncomingSlate value)? incomingSlate,
+    TResult Function(WalletEventDto_ConfirmationsChanged value)?
+        confirmationsChanged,
+    TResult Function(WalletEventDto_BalanceChanged value)? balanceChanged,
+    TResult Function(WalletEventDto_TxReverted value)? txReverted,
+    TResult Function(WalletEventDto_TxExpired value)? txExpired,
+    TResult Function(WalletEventDto_ScanProgress value)? scanProgress,
+    TResult Function(WalletEventDto_ScanCompleted value)? scanCompleted,
+    TResult Function(WalletEventDto_UpdaterProgress value)? updaterProgress,
+    TResult Function(WalletEventDto_NodeConnectivityChanged value)?
+        nodeConnectivityChanged,
+    required TResult orElse(),
+  }) {
+    if (nodeConnectivityChanged != null) {
+      return nodeConnectivityChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class WalletEventDto_NodeConnectivityChanged extends WalletEventDto {
+  const factory WalletEventDto_NodeConnectivityChanged(
+      {required final bool reachable,
+      final BigInt? tipHeight}) = _$WalletEventDto_NodeConnectivityChangedImpl;
+  const WalletEventDto_NodeConnectivityChanged._() : super._();
+
+  bool get reachable;
+  BigInt? get tipHeight;
+
+  /// Create a copy of WalletEventDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$WalletEventDto_NodeConnectivityChangedImplCopyWith<_$WalletEventDto_NodeConnectivityChangedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
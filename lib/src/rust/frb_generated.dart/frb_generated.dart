@@ -6,10 +6,15 @@
 import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'error.dart';
+import 'events.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'i18n.dart';
+import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'wallet.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -68,7 +73,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 96907516;
+  int get rustContentHash => 1317904890;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,88 +84,234 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<bool> crateApiCancelOperation({required BigInt token});
+
+  Future<BigInt> crateApiCreateCancelToken();
+
+  Future<String> crateApiForeignListenerAddr({required BigInt handle});
+
+  Future<BridgeLocale> crateApiGetLocale();
+
   Future<BigInt> crateApiGetNodeTip();
 
   Future<String> crateApiGetNodeUrl();
 
-  Future<String> crateApiOwnerListenerStart();
+  Future<OwnerListenerStatusDto> crateApiOwnerListenerStart(
+      {required BigInt handle});
+
+  Future<OwnerListenerStatusDto> crateApiOwnerListenerStatus(
+      {required BigInt handle});
+
+  Future<QrScanProgressDto> crateApiQrAddFrame(
+      {required BigInt session, required String frame});
+
+  Future<BigInt> crateApiQrCreateScanSession();
+
+  Future<List<String>> crateApiQrEncodeSlatepack(
+      {required String message, int? maxCharsPerFrame});
 
-  Future<String> crateApiOwnerListenerStatus();
+  Future<void> crateApiQrReleaseScanSession({required BigInt session});
+
+  Future<String> crateApiReadSlatepackFile({required String path});
+
+  Future<void> crateApiSetLocale({required BridgeLocale locale});
 
   Future<void> crateApiSetNodeUrl({required String url});
 
-  Future<String> crateApiTorStart({required String listenAddr});
+  Future<TorStatusDto> crateApiTorStart(
+      {required BigInt handle, required String listenAddr});
+
+  Future<TorStatusDto> crateApiTorStatus({required BigInt handle});
+
+  Future<void> crateApiTorStop({required BigInt handle});
 
-  Future<String> crateApiTorStatus();
+  Future<String> crateApiWalletActiveAccount({required BigInt handle});
 
-  Future<void> crateApiTorStop();
+  Future<String> crateApiWalletApproveSlatepack(
+      {required BigInt handle,
+      required String slateId,
+      required SendOptions options});
 
-  Future<String> crateApiWalletActiveAccount();
+  Future<void> crateApiWalletCancelTx(
+      {required BigInt handle, required int txId});
 
-  Future<void> crateApiWalletCancelTx({required int txId});
+  Future<void> crateApiWalletChangePassword(
+      {required BigInt handle,
+      required String oldPassphrase,
+      required String newPassphrase});
 
-  Future<String> crateApiWalletCreate(
+  Future<WalletCloseReportDto> crateApiWalletClose({required BigInt handle});
+
+  Future<CreatedWallet> crateApiWalletCreate(
       {required String dataDir,
       required String passphrase,
-      required BigInt mnemonicLength});
+      required BigInt mnemonicLength,
+      required WalletChainType chainType});
+
+  Future<AccountDto> crateApiWalletCreateAccount(
+      {required BigInt handle, required String label});
 
-  Future<String> crateApiWalletCreateAccount({required String label});
+  Future<SendEstimateDto> crateApiWalletEstimateSend(
+      {required BigInt handle,
+      required BigInt amountNano,
+      required SendOptions options});
+
+  Stream<WalletEventDto> crateApiWalletEvents();
 
   Future<String> crateApiWalletFinalizeSlatepack(
-      {required String message, required bool postTx, required bool fluff});
+      {required BigInt handle,
+      required String message,
+      required bool postTx,
+      required bool fluff,
+      SlatepackFileTarget? outputFile});
 
-  Future<String> crateApiWalletGetAddress();
+  Future<String> crateApiWalletGetAddress({required BigInt handle});
 
-  Future<BigInt> crateApiWalletGetBalance();
+  Future<BigInt> crateApiWalletGetBalance({required BigInt handle});
 
-  Future<String> crateApiWalletInfo();
+  Future<WalletInfoDto> crateApiWalletInfo({required BigInt handle});
 
-  Future<void> crateApiWalletInitOrOpen(
-      {required String dataDir, required String passphrase});
+  Future<BigInt> crateApiWalletInitOrOpen(
+      {required String dataDir,
+      required String passphrase,
+      WalletChainType? chainType});
+
+  Future<SlateInspectionDto> crateApiWalletInspectSlatepack(
+      {required BigInt handle, required String message});
+
+  Future<String> crateApiWalletIssueInvoice(
+      {required BigInt handle, required BigInt amountNano});
 
-  Future<String> crateApiWalletInspectSlatepack({required String message});
+  Future<List<AccountDto>> crateApiWalletListAccounts({required BigInt handle});
 
-  Future<String> crateApiWalletIssueInvoice({required BigInt amountNano});
+  Future<List<OutputDto>> crateApiWalletListOutputs(
+      {required BigInt handle,
+      required bool includeSpent,
+      required bool refreshFromNode});
 
-  Future<String> crateApiWalletListAccounts();
+  Future<List<TransactionDto>> crateApiWalletListTransactions(
+      {required BigInt handle, required bool refreshFromNode});
 
-  Future<String> crateApiWalletListOutputs(
-      {required bool includeSpent, required bool refreshFromNode});
+  Future<PaymentProofDto> crateApiWalletPaymentProof(
+      {required BigInt handle, required int txId});
 
-  Future<String> crateApiWalletListTransactions(
-      {required bool refreshFromNode});
+  Future<String> crateApiWalletProcessInvoice(
+      {required BigInt handle,
+      required String message,
+      required SendOptions options,
+      SlatepackFileTarget? outputFile});
 
-  Future<String> crateApiWalletPaymentProof({required int txId});
+  Future<ProcessedSlatepackDto> crateApiWalletProcessSlatepack(
+      {required BigInt handle,
+      required String message,
+      required ProcessSlatepackOptions options});
 
-  Future<String> crateApiWalletProcessInvoice({required String message});
+  Future<String> crateApiWalletReceiveSlatepack(
+      {required BigInt handle,
+      required String message,
+      SlatepackFileTarget? outputFile});
 
-  Future<String> crateApiWalletReceiveSlatepack({required String message});
+  Future<bool> crateApiWalletRejectSlatepack(
+      {required BigInt handle, required String slateId});
 
-  Future<void> crateApiWalletRepostTx({required int txId, required bool fluff});
+  Future<void> crateApiWalletRepostTx(
+      {required BigInt handle, required int txId, required bool fluff});
 
-  Future<void> crateApiWalletRestoreFromSeed(
+  Future<RestoreResultDto> crateApiWalletRestoreFromSeed(
       {required String dataDir,
       required String passphrase,
-      required String phrase});
-
-  Future<String> crateApiWalletScan(
-      {required bool deleteUnconfirmed,
+      required String phrase,
+      required WalletChainType chainType,
+      BigInt? cancelToken});
+
+  Future<ScanResultDto> crateApiWalletResumeScan(
+      {required BigInt handle, BigInt? cancelToken});
+
+  Future<SlateReviewDto> crateApiWalletReviewSlatepack(
+      {required BigInt handle,
+      required String message,
+      required SlateExpectation expectation,
+      required SendOptions options});
+
+  Future<ScanResultDto> crateApiWalletScan(
+      {required BigInt handle,
+      required bool deleteUnconfirmed,
       BigInt? startHeight,
-      BigInt? backwardsFromTip});
+      BigInt? backwardsFromTip,
+      BigInt? cancelToken});
+
+  Future<BigInt?> crateApiWalletScanResumeHeight({required BigInt handle});
 
   Future<String> crateApiWalletSeedPhrase(
       {required String dataDir, required String passphrase});
 
+  Future<PayoutReportDto> crateApiWalletSendBatch(
+      {required BigInt handle,
+      required List<PayoutItem> items,
+      required bool viaTor,
+      required SendOptions options});
+
   Future<String> crateApiWalletSendSlatepack(
-      {required String to, required BigInt amountNano});
+      {required BigInt handle,
+      required String to,
+      required BigInt amountNano,
+      required SendOptions options,
+      SlatepackFileTarget? outputFile});
+
+  Future<DirectSendResultDto> crateApiWalletSendViaHttp(
+      {required BigInt handle,
+      required String url,
+      required BigInt amountNano,
+      required SendOptions options});
+
+  Future<DirectSendResultDto> crateApiWalletSendViaTor(
+      {required BigInt handle,
+      required String address,
+      required BigInt amountNano,
+      required SendOptions options});
+
+  Future<AccountDto> crateApiWalletSetActiveAccount(
+      {required BigInt handle, required String label});
+
+  Future<void> crateApiWalletSetAutoLock(
+      {required BigInt handle, required BigInt timeoutSecs});
+
+  Future<void> crateApiWalletSetDefaultTtl(
+      {required BigInt handle, required BigInt ttlBlocks});
+
+  Future<void> crateApiWalletSetRequireReview(
+      {required BigInt handle, required bool required});
+
+  Future<TxMetadataDto?> crateApiWalletSetTxMetadata(
+      {required BigInt handle,
+      required String parentKeyId,
+      required int txId,
+      required TxMetadataDto metadata});
+
+  Future<UpdaterStatusDto> crateApiWalletStartUpdater(
+      {required BigInt handle, required BigInt intervalSecs});
 
-  Future<String> crateApiWalletSetActiveAccount({required String label});
+  Future<UpdaterStatusDto> crateApiWalletStopUpdater({required BigInt handle});
 
-  Future<void> crateApiWalletSync();
+  Future<void> crateApiWalletSync({required BigInt handle});
 
-  Future<String> crateApiWalletTransactionSlatepack({required int txId});
+  Future<String> crateApiWalletTransactionSlatepack(
+      {required BigInt handle, required int txId});
 
-  Future<String> crateApiWalletVerifyPaymentProof({required String payload});
+  Future<TxMetadataDto?> crateApiWalletTxMetadata(
+      {required BigInt handle, required String parentKeyId, required int txId});
+
+  Future<UpdaterStatusDto> crateApiWalletUpdaterStatus(
+      {required BigInt handle});
+
+  Future<PaymentProofVerificationDto> crateApiWalletVerifyPaymentProof(
+      {required BigInt handle, required String payload});
+
+  Future<SlatepackFileDto> crateApiWriteSlatepackFile(
+      {required String message,
+      required String path,
+      required bool binary,
+      required bool overwrite});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -172,30 +323,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<BigInt> crateApiGetNodeTip() {
+  Future<bool> crateApiCancelOperation({required BigInt token}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(token, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 1, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiGetNodeTipConstMeta,
-      argValues: [],
+      constMeta: kCrateApiCancelOperationConstMeta,
+      argValues: [token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiGetNodeTipConstMeta => const TaskConstMeta(
-        debugName: "get_node_tip",
-        argNames: [],
+  TaskConstMeta get kCrateApiCancelOperationConstMeta => const TaskConstMeta(
+        debugName: "cancel_operation",
+        argNames: ["token"],
       );
 
   @override
-  Future<String> crateApiGetNodeUrl() {
+  Future<BigInt> crateApiCreateCancelToken() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -203,45 +355,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiGetNodeUrlConstMeta,
+      constMeta: kCrateApiCreateCancelTokenConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiGetNodeUrlConstMeta => const TaskConstMeta(
-        debugName: "get_node_url",
+  TaskConstMeta get kCrateApiCreateCancelTokenConstMeta => const TaskConstMeta(
+        debugName: "create_cancel_token",
         argNames: [],
       );
 
   @override
-  Future<String> crateApiOwnerListenerStart() {
+  Future<String> crateApiForeignListenerAddr({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiOwnerListenerStartConstMeta,
-      argValues: [],
+      constMeta: kCrateApiForeignListenerAddrConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiOwnerListenerStartConstMeta => const TaskConstMeta(
-        debugName: "owner_listener_start",
-        argNames: [],
+  TaskConstMeta get kCrateApiForeignListenerAddrConstMeta =>
+      const TaskConstMeta(
+        debugName: "foreign_listener_addr",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiOwnerListenerStatus() {
+  Future<BridgeLocale> crateApiGetLocale() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -249,942 +403,3901 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 4, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_bridge_locale,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiOwnerListenerStatusConstMeta,
+      constMeta: kCrateApiGetLocaleConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiOwnerListenerStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "owner_listener_status",
+  TaskConstMeta get kCrateApiGetLocaleConstMeta => const TaskConstMeta(
+        debugName: "get_locale",
         argNames: [],
       );
 
   @override
-  Future<void> crateApiSetNodeUrl({required String url}) {
+  Future<BigInt> crateApiGetNodeTip() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(url, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 5, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiSetNodeUrlConstMeta,
-      argValues: [url],
+      constMeta: kCrateApiGetNodeTipConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSetNodeUrlConstMeta => const TaskConstMeta(
-        debugName: "set_node_url",
-        argNames: ["url"],
+  TaskConstMeta get kCrateApiGetNodeTipConstMeta => const TaskConstMeta(
+        debugName: "get_node_tip",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiTorStart({required String listenAddr}) {
+  Future<String> crateApiGetNodeUrl() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(listenAddr, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 6, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiTorStartConstMeta,
-      argValues: [listenAddr],
+      constMeta: kCrateApiGetNodeUrlConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTorStartConstMeta => const TaskConstMeta(
-        debugName: "tor_start",
-        argNames: ["listenAddr"],
+  TaskConstMeta get kCrateApiGetNodeUrlConstMeta => const TaskConstMeta(
+        debugName: "get_node_url",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiTorStatus() {
+  Future<OwnerListenerStatusDto> crateApiOwnerListenerStart(
+      {required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 7, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_owner_listener_status_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiTorStatusConstMeta,
-      argValues: [],
+      constMeta: kCrateApiOwnerListenerStartConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTorStatusConstMeta => const TaskConstMeta(
-        debugName: "tor_status",
-        argNames: [],
+  TaskConstMeta get kCrateApiOwnerListenerStartConstMeta => const TaskConstMeta(
+        debugName: "owner_listener_start",
+        argNames: ["handle"],
       );
 
   @override
-  Future<void> crateApiTorStop() {
+  Future<OwnerListenerStatusDto> crateApiOwnerListenerStatus(
+      {required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 8, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_owner_listener_status_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiTorStopConstMeta,
-      argValues: [],
+      constMeta: kCrateApiOwnerListenerStatusConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiTorStopConstMeta => const TaskConstMeta(
-        debugName: "tor_stop",
-        argNames: [],
+  TaskConstMeta get kCrateApiOwnerListenerStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "owner_listener_status",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiWalletActiveAccount() {
+  Future<QrScanProgressDto> crateApiQrAddFrame(
+      {required BigInt session, required String frame}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(session, serializer);
+        sse_encode_String(frame, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_qr_scan_progress_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletActiveAccountConstMeta,
-      argValues: [],
+      constMeta: kCrateApiQrAddFrameConstMeta,
+      argValues: [session, frame],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletActiveAccountConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_active_account",
-        argNames: [],
+  TaskConstMeta get kCrateApiQrAddFrameConstMeta => const TaskConstMeta(
+        debugName: "qr_add_frame",
+        argNames: ["session", "frame"],
       );
 
   @override
-  Future<void> crateApiWalletCancelTx({required int txId}) {
+  Future<BigInt> crateApiQrCreateScanSession() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_32(txId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 10, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiWalletCancelTxConstMeta,
-      argValues: [txId],
+      constMeta: kCrateApiQrCreateScanSessionConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletCancelTxConstMeta => const TaskConstMeta(
-        debugName: "wallet_cancel_tx",
-        argNames: ["txId"],
+  TaskConstMeta get kCrateApiQrCreateScanSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "qr_create_scan_session",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiWalletCreate(
-      {required String dataDir,
-      required String passphrase,
-      required BigInt mnemonicLength}) {
+  Future<List<String>> crateApiQrEncodeSlatepack(
+      {required String message, int? maxCharsPerFrame}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dataDir, serializer);
-        sse_encode_String(passphrase, serializer);
-        sse_encode_usize(mnemonicLength, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_opt_box_autoadd_u_32(maxCharsPerFrame, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 11, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletCreateConstMeta,
-      argValues: [dataDir, passphrase, mnemonicLength],
+      constMeta: kCrateApiQrEncodeSlatepackConstMeta,
+      argValues: [message, maxCharsPerFrame],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletCreateConstMeta => const TaskConstMeta(
-        debugName: "wallet_create",
-        argNames: ["dataDir", "passphrase", "mnemonicLength"],
+  TaskConstMeta get kCrateApiQrEncodeSlatepackConstMeta => const TaskConstMeta(
+        debugName: "qr_encode_slatepack",
+        argNames: ["message", "maxCharsPerFrame"],
       );
 
   @override
-  Future<String> crateApiWalletCreateAccount({required String label}) {
+  Future<void> crateApiQrReleaseScanSession({required BigInt session}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(label, serializer);
+        sse_encode_u_64(session, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiWalletCreateAccountConstMeta,
-      argValues: [label],
+      constMeta: kCrateApiQrReleaseScanSessionConstMeta,
+      argValues: [session],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletCreateAccountConstMeta =>
+  TaskConstMeta get kCrateApiQrReleaseScanSessionConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_create_account",
-        argNames: ["label"],
+        debugName: "qr_release_scan_session",
+        argNames: ["session"],
       );
 
   @override
-  Future<String> crateApiWalletFinalizeSlatepack(
-      {required String message, required bool postTx, required bool fluff}) {
+  Future<String> crateApiReadSlatepackFile({required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(message, serializer);
-        sse_encode_bool(postTx, serializer);
-        sse_encode_bool(fluff, serializer);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 13, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletFinalizeSlatepackConstMeta,
-      argValues: [message, postTx, fluff],
+      constMeta: kCrateApiReadSlatepackFileConstMeta,
+      argValues: [path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletFinalizeSlatepackConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_finalize_slatepack",
-        argNames: ["message", "postTx", "fluff"],
+  TaskConstMeta get kCrateApiReadSlatepackFileConstMeta => const TaskConstMeta(
+        debugName: "read_slatepack_file",
+        argNames: ["path"],
       );
 
   @override
-  Future<String> crateApiWalletGetAddress() {
+  Future<void> crateApiSetLocale({required BridgeLocale locale}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bridge_locale(locale, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 14, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiWalletGetAddressConstMeta,
-      argValues: [],
+      constMeta: kCrateApiSetLocaleConstMeta,
+      argValues: [locale],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletGetAddressConstMeta => const TaskConstMeta(
-        debugName: "wallet_get_address",
-        argNames: [],
+  TaskConstMeta get kCrateApiSetLocaleConstMeta => const TaskConstMeta(
+        debugName: "set_locale",
+        argNames: ["locale"],
       );
 
   @override
-  Future<BigInt> crateApiWalletGetBalance() {
+  Future<void> crateApiSetNodeUrl({required String url}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(url, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 15, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_u_64,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletGetBalanceConstMeta,
-      argValues: [],
+      constMeta: kCrateApiSetNodeUrlConstMeta,
+      argValues: [url],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletGetBalanceConstMeta => const TaskConstMeta(
-        debugName: "wallet_get_balance",
-        argNames: [],
+  TaskConstMeta get kCrateApiSetNodeUrlConstMeta => const TaskConstMeta(
+        debugName: "set_node_url",
+        argNames: ["url"],
       );
 
   @override
-  Future<String> crateApiWalletInfo() {
+  Future<TorStatusDto> crateApiTorStart(
+      {required BigInt handle, required String listenAddr}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(listenAddr, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 16, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_tor_status_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletInfoConstMeta,
-      argValues: [],
+      constMeta: kCrateApiTorStartConstMeta,
+      argValues: [handle, listenAddr],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletInfoConstMeta => const TaskConstMeta(
-        debugName: "wallet_info",
-        argNames: [],
+  TaskConstMeta get kCrateApiTorStartConstMeta => const TaskConstMeta(
+        debugName: "tor_start",
+        argNames: ["handle", "listenAddr"],
       );
 
   @override
-  Future<void> crateApiWalletInitOrOpen(
-      {required String dataDir, required String passphrase}) {
+  Future<TorStatusDto> crateApiTorStatus({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dataDir, serializer);
-        sse_encode_String(passphrase, serializer);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 17, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_tor_status_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletInitOrOpenConstMeta,
-      argValues: [dataDir, passphrase],
+      constMeta: kCrateApiTorStatusConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletInitOrOpenConstMeta => const TaskConstMeta(
-        debugName: "wallet_init_or_open",
-        argNames: ["dataDir", "passphrase"],
+  TaskConstMeta get kCrateApiTorStatusConstMeta => const TaskConstMeta(
+        debugName: "tor_status",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiWalletInspectSlatepack({required String message}) {
+  Future<void> crateApiTorStop({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(message, serializer);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 18, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletInspectSlatepackConstMeta,
-      argValues: [message],
+      constMeta: kCrateApiTorStopConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletInspectSlatepackConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_inspect_slatepack",
-        argNames: ["message"],
+  TaskConstMeta get kCrateApiTorStopConstMeta => const TaskConstMeta(
+        debugName: "tor_stop",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiWalletIssueInvoice({required BigInt amountNano}) {
+  Future<String> crateApiWalletActiveAccount({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_64(amountNano, serializer);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 19, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletIssueInvoiceConstMeta,
-      argValues: [amountNano],
+      constMeta: kCrateApiWalletActiveAccountConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletIssueInvoiceConstMeta => const TaskConstMeta(
-        debugName: "wallet_issue_invoice",
-        argNames: ["amountNano"],
+  TaskConstMeta get kCrateApiWalletActiveAccountConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_active_account",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiWalletListAccounts() {
+  Future<String> crateApiWalletApproveSlatepack(
+      {required BigInt handle,
+      required String slateId,
+      required SendOptions options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(slateId, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 20, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletListAccountsConstMeta,
-      argValues: [],
+      constMeta: kCrateApiWalletApproveSlatepackConstMeta,
+      argValues: [handle, slateId, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletListAccountsConstMeta => const TaskConstMeta(
-        debugName: "wallet_list_accounts",
-        argNames: [],
+  TaskConstMeta get kCrateApiWalletApproveSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_approve_slatepack",
+        argNames: ["handle", "slateId", "options"],
       );
 
   @override
-  Future<String> crateApiWalletListOutputs(
-      {required bool includeSpent, required bool refreshFromNode}) {
+  Future<void> crateApiWalletCancelTx(
+      {required BigInt handle, required int txId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_bool(includeSpent, serializer);
-        sse_encode_bool(refreshFromNode, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_32(txId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 21, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletListOutputsConstMeta,
-      argValues: [includeSpent, refreshFromNode],
+      constMeta: kCrateApiWalletCancelTxConstMeta,
+      argValues: [handle, txId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletListOutputsConstMeta => const TaskConstMeta(
-        debugName: "wallet_list_outputs",
-        argNames: ["includeSpent", "refreshFromNode"],
+  TaskConstMeta get kCrateApiWalletCancelTxConstMeta => const TaskConstMeta(
+        debugName: "wallet_cancel_tx",
+        argNames: ["handle", "txId"],
       );
 
   @override
-  Future<String> crateApiWalletListTransactions(
-      {required bool refreshFromNode}) {
+  Future<void> crateApiWalletChangePassword(
+      {required BigInt handle,
+      required String oldPassphrase,
+      required String newPassphrase}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_bool(refreshFromNode, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(oldPassphrase, serializer);
+        sse_encode_String(newPassphrase, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 22, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletListTransactionsConstMeta,
-      argValues: [refreshFromNode],
+      constMeta: kCrateApiWalletChangePasswordConstMeta,
+      argValues: [handle, oldPassphrase, newPassphrase],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletListTransactionsConstMeta =>
+  TaskConstMeta get kCrateApiWalletChangePasswordConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_list_transactions",
-        argNames: ["refreshFromNode"],
+        debugName: "wallet_change_password",
+        argNames: ["handle", "oldPassphrase", "newPassphrase"],
       );
 
   @override
-  Future<String> crateApiWalletPaymentProof({required int txId}) {
+  Future<WalletCloseReportDto> crateApiWalletClose({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_32(txId, serializer);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 23, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_wallet_close_report_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletPaymentProofConstMeta,
-      argValues: [txId],
+      constMeta: kCrateApiWalletCloseConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletPaymentProofConstMeta => const TaskConstMeta(
-        debugName: "wallet_payment_proof",
-        argNames: ["txId"],
+  TaskConstMeta get kCrateApiWalletCloseConstMeta => const TaskConstMeta(
+        debugName: "wallet_close",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiWalletProcessInvoice({required String message}) {
+  Future<CreatedWallet> crateApiWalletCreate(
+      {required String dataDir,
+      required String passphrase,
+      required BigInt mnemonicLength,
+      required WalletChainType chainType}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(message, serializer);
+        sse_encode_String(dataDir, serializer);
+        sse_encode_String(passphrase, serializer);
+        sse_encode_usize(mnemonicLength, serializer);
+        sse_encode_wallet_chain_type(chainType, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 24, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_created_wallet,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletProcessInvoiceConstMeta,
-      argValues: [message],
+      constMeta: kCrateApiWalletCreateConstMeta,
+      argValues: [dataDir, passphrase, mnemonicLength, chainType],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletProcessInvoiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_process_invoice",
-        argNames: ["message"],
+  TaskConstMeta get kCrateApiWalletCreateConstMeta => const TaskConstMeta(
+        debugName: "wallet_create",
+        argNames: ["dataDir", "passphrase", "mnemonicLength", "chainType"],
       );
 
   @override
-  Future<String> crateApiWalletReceiveSlatepack({required String message}) {
+  Future<AccountDto> crateApiWalletCreateAccount(
+      {required BigInt handle, required String label}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(message, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(label, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 25, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_account_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletReceiveSlatepackConstMeta,
-      argValues: [message],
+      constMeta: kCrateApiWalletCreateAccountConstMeta,
+      argValues: [handle, label],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletReceiveSlatepackConstMeta =>
+  TaskConstMeta get kCrateApiWalletCreateAccountConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_receive_slatepack",
-        argNames: ["message"],
+        debugName: "wallet_create_account",
+        argNames: ["handle", "label"],
       );
 
   @override
-  Future<void> crateApiWalletRepostTx(
-      {required int txId, required bool fluff}) {
+  Future<SendEstimateDto> crateApiWalletEstimateSend(
+      {required BigInt handle,
+      required BigInt amountNano,
+      required SendOptions options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_32(txId, serializer);
-        sse_encode_bool(fluff, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_64(amountNano, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 26, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_send_estimate_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletRepostTxConstMeta,
-      argValues: [txId, fluff],
+      constMeta: kCrateApiWalletEstimateSendConstMeta,
+      argValues: [handle, amountNano, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletRepostTxConstMeta => const TaskConstMeta(
-        debugName: "wallet_repost_tx",
-        argNames: ["txId", "fluff"],
+  TaskConstMeta get kCrateApiWalletEstimateSendConstMeta => const TaskConstMeta(
+        debugName: "wallet_estimate_send",
+        argNames: ["handle", "amountNano", "options"],
       );
 
   @override
-  Future<void> crateApiWalletRestoreFromSeed(
-      {required String dataDir,
-      required String passphrase,
-      required String phrase}) {
-    return handler.executeNormal(NormalTask(
+  Stream<WalletEventDto> crateApiWalletEvents() {
+    final sink = RustStreamSink<WalletEventDto>();
+    unawaited(handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dataDir, serializer);
-        sse_encode_String(passphrase, serializer);
-        sse_encode_String(phrase, serializer);
+        sse_encode_StreamSink_wallet_event_dto_Sse(sink, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 27, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletRestoreFromSeedConstMeta,
-      argValues: [dataDir, passphrase, phrase],
+      constMeta: kCrateApiWalletEventsConstMeta,
+      argValues: [sink],
       apiImpl: this,
-    ));
+    )));
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWalletRestoreFromSeedConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_restore_from_seed",
-        argNames: ["dataDir", "passphrase", "phrase"],
+  TaskConstMeta get kCrateApiWalletEventsConstMeta => const TaskConstMeta(
+        debugName: "wallet_events",
+        argNames: ["sink"],
       );
 
   @override
-  Future<String> crateApiWalletScan(
-      {required bool deleteUnconfirmed,
-      BigInt? startHeight,
-      BigInt? backwardsFromTip}) {
+  Future<String> crateApiWalletFinalizeSlatepack(
+      {required BigInt handle,
+      required String message,
+      required bool postTx,
+      required bool fluff,
+      SlatepackFileTarget? outputFile}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_bool(deleteUnconfirmed, serializer);
-        sse_encode_opt_box_autoadd_u_64(startHeight, serializer);
-        sse_encode_opt_box_autoadd_u_64(backwardsFromTip, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_bool(postTx, serializer);
+        sse_encode_bool(fluff, serializer);
+        sse_encode_opt_box_autoadd_slatepack_file_target(
+            outputFile, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 28, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletScanConstMeta,
-      argValues: [deleteUnconfirmed, startHeight, backwardsFromTip],
+      constMeta: kCrateApiWalletFinalizeSlatepackConstMeta,
+      argValues: [handle, message, postTx, fluff, outputFile],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletScanConstMeta => const TaskConstMeta(
-        debugName: "wallet_scan",
-        argNames: ["deleteUnconfirmed", "startHeight", "backwardsFromTip"],
+  TaskConstMeta get kCrateApiWalletFinalizeSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_finalize_slatepack",
+        argNames: ["handle", "message", "postTx", "fluff", "outputFile"],
       );
 
   @override
-  Future<String> crateApiWalletSeedPhrase(
-      {required String dataDir, required String passphrase}) {
+  Future<String> crateApiWalletGetAddress({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dataDir, serializer);
-        sse_encode_String(passphrase, serializer);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 29, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletSeedPhraseConstMeta,
-      argValues: [dataDir, passphrase],
+      constMeta: kCrateApiWalletGetAddressConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletSeedPhraseConstMeta => const TaskConstMeta(
-        debugName: "wallet_seed_phrase",
-        argNames: ["dataDir", "passphrase"],
+  TaskConstMeta get kCrateApiWalletGetAddressConstMeta => const TaskConstMeta(
+        debugName: "wallet_get_address",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiWalletSendSlatepack(
-      {required String to, required BigInt amountNano}) {
+  Future<BigInt> crateApiWalletGetBalance({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(to, serializer);
-        sse_encode_u_64(amountNano, serializer);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 30, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletSendSlatepackConstMeta,
-      argValues: [to, amountNano],
+      constMeta: kCrateApiWalletGetBalanceConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletSendSlatepackConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_send_slatepack",
-        argNames: ["to", "amountNano"],
+  TaskConstMeta get kCrateApiWalletGetBalanceConstMeta => const TaskConstMeta(
+        debugName: "wallet_get_balance",
+        argNames: ["handle"],
       );
 
   @override
-  Future<String> crateApiWalletSetActiveAccount({required String label}) {
+  Future<WalletInfoDto> crateApiWalletInfo({required BigInt handle}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(label, serializer);
+        sse_encode_u_64(handle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 31, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_wallet_info_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletSetActiveAccountConstMeta,
-      argValues: [label],
+      constMeta: kCrateApiWalletInfoConstMeta,
+      argValues: [handle],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletSetActiveAccountConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_set_active_account",
-        argNames: ["label"],
+  TaskConstMeta get kCrateApiWalletInfoConstMeta => const TaskConstMeta(
+        debugName: "wallet_info",
+        argNames: ["handle"],
       );
 
   @override
-  Future<void> crateApiWalletSync() {
+  Future<BigInt> crateApiWalletInitOrOpen(
+      {required String dataDir,
+      required String passphrase,
+      WalletChainType? chainType}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dataDir, serializer);
+        sse_encode_String(passphrase, serializer);
+        sse_encode_opt_box_autoadd_wallet_chain_type(chainType, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 32, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletSyncConstMeta,
-      argValues: [],
+      constMeta: kCrateApiWalletInitOrOpenConstMeta,
+      argValues: [dataDir, passphrase, chainType],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletSyncConstMeta => const TaskConstMeta(
-        debugName: "wallet_sync",
-        argNames: [],
+  TaskConstMeta get kCrateApiWalletInitOrOpenConstMeta => const TaskConstMeta(
+        debugName: "wallet_init_or_open",
+        argNames: ["dataDir", "passphrase", "chainType"],
       );
 
   @override
-  Future<String> crateApiWalletTransactionSlatepack({required int txId}) {
+  Future<SlateInspectionDto> crateApiWalletInspectSlatepack(
+      {required BigInt handle, required String message}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_32(txId, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(message, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 33, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_slate_inspection_dto,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletTransactionSlatepackConstMeta,
-      argValues: [txId],
+      constMeta: kCrateApiWalletInspectSlatepackConstMeta,
+      argValues: [handle, message],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletTransactionSlatepackConstMeta =>
+  TaskConstMeta get kCrateApiWalletInspectSlatepackConstMeta =>
       const TaskConstMeta(
-        debugName: "wallet_transaction_slatepack",
-        argNames: ["txId"],
+        debugName: "wallet_inspect_slatepack",
+        argNames: ["handle", "message"],
       );
 
   @override
-  Future<String> crateApiWalletVerifyPaymentProof({required String payload}) {
+  Future<String> crateApiWalletIssueInvoice(
+      {required BigInt handle, required BigInt amountNano}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(payload, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_64(amountNano, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 34, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateApiWalletVerifyPaymentProofConstMeta,
-      argValues: [payload],
+      constMeta: kCrateApiWalletIssueInvoiceConstMeta,
+      argValues: [handle, amountNano],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiWalletVerifyPaymentProofConstMeta =>
-      const TaskConstMeta(
-        debugName: "wallet_verify_payment_proof",
-        argNames: ["payload"],
+  TaskConstMeta get kCrateApiWalletIssueInvoiceConstMeta => const TaskConstMeta(
+        debugName: "wallet_issue_invoice",
+        argNames: ["handle", "amountNano"],
       );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  @override
+  Future<List<AccountDto>> crateApiWalletListAccounts(
+      {required BigInt handle}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_account_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletListAccountsConstMeta,
+      argValues: [handle],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiWalletListAccountsConstMeta => const TaskConstMeta(
+        debugName: "wallet_list_accounts",
+        argNames: ["handle"],
+      );
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  @override
+  Future<List<OutputDto>> crateApiWalletListOutputs(
+      {required BigInt handle,
+      required bool includeSpent,
+      required bool refreshFromNode}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_bool(includeSpent, serializer);
+        sse_encode_bool(refreshFromNode, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_output_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletListOutputsConstMeta,
+      argValues: [handle, includeSpent, refreshFromNode],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
+  TaskConstMeta get kCrateApiWalletListOutputsConstMeta => const TaskConstMeta(
+        debugName: "wallet_list_outputs",
+        argNames: ["handle", "includeSpent", "refreshFromNode"],
+      );
 
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  @override
+  Future<List<TransactionDto>> crateApiWalletListTransactions(
+      {required BigInt handle, required bool refreshFromNode}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_bool(refreshFromNode, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_transaction_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletListTransactionsConstMeta,
+      argValues: [handle, refreshFromNode],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
+  TaskConstMeta get kCrateApiWalletListTransactionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_list_transactions",
+        argNames: ["handle", "refreshFromNode"],
+      );
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  @override
+  Future<PaymentProofDto> crateApiWalletPaymentProof(
+      {required BigInt handle, required int txId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_32(txId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_payment_proof_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletPaymentProofConstMeta,
+      argValues: [handle, txId],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
+  TaskConstMeta get kCrateApiWalletPaymentProofConstMeta => const TaskConstMeta(
+        debugName: "wallet_payment_proof",
+        argNames: ["handle", "txId"],
+      );
 
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  @override
+  Future<String> crateApiWalletProcessInvoice(
+      {required BigInt handle,
+      required String message,
+      required SendOptions options,
+      SlatepackFileTarget? outputFile}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
+        sse_encode_opt_box_autoadd_slatepack_file_target(
+            outputFile, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletProcessInvoiceConstMeta,
+      argValues: [handle, message, options, outputFile],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
+  TaskConstMeta get kCrateApiWalletProcessInvoiceConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_process_invoice",
+        argNames: ["handle", "message", "options", "outputFile"],
+      );
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+  @override
+  Future<ProcessedSlatepackDto> crateApiWalletProcessSlatepack(
+      {required BigInt handle,
+      required String message,
+      required ProcessSlatepackOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_box_autoadd_process_slatepack_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_processed_slatepack_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletProcessSlatepackConstMeta,
+      argValues: [handle, message, options],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
+  TaskConstMeta get kCrateApiWalletProcessSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_process_slatepack",
+        argNames: ["handle", "message", "options"],
+      );
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
+  @override
+  Future<String> crateApiWalletReceiveSlatepack(
+      {required BigInt handle,
+      required String message,
+      SlatepackFileTarget? outputFile}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_opt_box_autoadd_slatepack_file_target(
+            outputFile, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletReceiveSlatepackConstMeta,
+      argValues: [handle, message, outputFile],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletReceiveSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_receive_slatepack",
+        argNames: ["handle", "message", "outputFile"],
+      );
+
+  @override
+  Future<bool> crateApiWalletRejectSlatepack(
+      {required BigInt handle, required String slateId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(slateId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletRejectSlatepackConstMeta,
+      argValues: [handle, slateId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletRejectSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_reject_slatepack",
+        argNames: ["handle", "slateId"],
+      );
+
+  @override
+  Future<void> crateApiWalletRepostTx(
+      {required BigInt handle, required int txId, required bool fluff}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_32(txId, serializer);
+        sse_encode_bool(fluff, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletRepostTxConstMeta,
+      argValues: [handle, txId, fluff],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletRepostTxConstMeta => const TaskConstMeta(
+        debugName: "wallet_repost_tx",
+        argNames: ["handle", "txId", "fluff"],
+      );
+
+  @override
+  Future<RestoreResultDto> crateApiWalletRestoreFromSeed(
+      {required String dataDir,
+      required String passphrase,
+      required String phrase,
+      required WalletChainType chainType,
+      BigInt? cancelToken}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dataDir, serializer);
+        sse_encode_String(passphrase, serializer);
+        sse_encode_String(phrase, serializer);
+        sse_encode_wallet_chain_type(chainType, serializer);
+        sse_encode_opt_box_autoadd_u_64(cancelToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_restore_result_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletRestoreFromSeedConstMeta,
+      argValues: [dataDir, passphrase, phrase, chainType, cancelToken],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletRestoreFromSeedConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_restore_from_seed",
+        argNames: [
+          "dataDir",
+          "passphrase",
+          "phrase",
+          "chainType",
+          "cancelToken"
+        ],
+      );
+
+  @override
+  Future<ScanResultDto> crateApiWalletResumeScan(
+      {required BigInt handle, BigInt? cancelToken}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_opt_box_autoadd_u_64(cancelToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_result_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletResumeScanConstMeta,
+      argValues: [handle, cancelToken],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletResumeScanConstMeta => const TaskConstMeta(
+        debugName: "wallet_resume_scan",
+        argNames: ["handle", "cancelToken"],
+      );
+
+  @override
+  Future<SlateReviewDto> crateApiWalletReviewSlatepack(
+      {required BigInt handle,
+      required String message,
+      required SlateExpectation expectation,
+      required SendOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_box_autoadd_slate_expectation(expectation, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_slate_review_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletReviewSlatepackConstMeta,
+      argValues: [handle, message, expectation, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletReviewSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_review_slatepack",
+        argNames: ["handle", "message", "expectation", "options"],
+      );
+
+  @override
+  Future<ScanResultDto> crateApiWalletScan(
+      {required BigInt handle,
+      required bool deleteUnconfirmed,
+      BigInt? startHeight,
+      BigInt? backwardsFromTip,
+      BigInt? cancelToken}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_bool(deleteUnconfirmed, serializer);
+        sse_encode_opt_box_autoadd_u_64(startHeight, serializer);
+        sse_encode_opt_box_autoadd_u_64(backwardsFromTip, serializer);
+        sse_encode_opt_box_autoadd_u_64(cancelToken, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_scan_result_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletScanConstMeta,
+      argValues: [
+        handle,
+        deleteUnconfirmed,
+        startHeight,
+        backwardsFromTip,
+        cancelToken
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletScanConstMeta => const TaskConstMeta(
+        debugName: "wallet_scan",
+        argNames: [
+          "handle",
+          "deleteUnconfirmed",
+          "startHeight",
+          "backwardsFromTip",
+          "cancelToken"
+        ],
+      );
+
+  @override
+  Future<BigInt?> crateApiWalletScanResumeHeight({required BigInt handle}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletScanResumeHeightConstMeta,
+      argValues: [handle],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletScanResumeHeightConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_scan_resume_height",
+        argNames: ["handle"],
+      );
+
+  @override
+  Future<String> crateApiWalletSeedPhrase(
+      {required String dataDir, required String passphrase}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dataDir, serializer);
+        sse_encode_String(passphrase, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSeedPhraseConstMeta,
+      argValues: [dataDir, passphrase],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSeedPhraseConstMeta => const TaskConstMeta(
+        debugName: "wallet_seed_phrase",
+        argNames: ["dataDir", "passphrase"],
+      );
+
+  @override
+  Future<PayoutReportDto> crateApiWalletSendBatch(
+      {required BigInt handle,
+      required List<PayoutItem> items,
+      required bool viaTor,
+      required SendOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_list_payout_item(items, serializer);
+        sse_encode_bool(viaTor, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_payout_report_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSendBatchConstMeta,
+      argValues: [handle, items, viaTor, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSendBatchConstMeta => const TaskConstMeta(
+        debugName: "wallet_send_batch",
+        argNames: ["handle", "items", "viaTor", "options"],
+      );
+
+  @override
+  Future<String> crateApiWalletSendSlatepack(
+      {required BigInt handle,
+      required String to,
+      required BigInt amountNano,
+      required SendOptions options,
+      SlatepackFileTarget? outputFile}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(to, serializer);
+        sse_encode_u_64(amountNano, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
+        sse_encode_opt_box_autoadd_slatepack_file_target(
+            outputFile, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSendSlatepackConstMeta,
+      argValues: [handle, to, amountNano, options, outputFile],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSendSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_send_slatepack",
+        argNames: ["handle", "to", "amountNano", "options", "outputFile"],
+      );
+
+  @override
+  Future<DirectSendResultDto> crateApiWalletSendViaHttp(
+      {required BigInt handle,
+      required String url,
+      required BigInt amountNano,
+      required SendOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(url, serializer);
+        sse_encode_u_64(amountNano, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_direct_send_result_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSendViaHttpConstMeta,
+      argValues: [handle, url, amountNano, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSendViaHttpConstMeta => const TaskConstMeta(
+        debugName: "wallet_send_via_http",
+        argNames: ["handle", "url", "amountNano", "options"],
+      );
+
+  @override
+  Future<DirectSendResultDto> crateApiWalletSendViaTor(
+      {required BigInt handle,
+      required String address,
+      required BigInt amountNano,
+      required SendOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(address, serializer);
+        sse_encode_u_64(amountNano, serializer);
+        sse_encode_box_autoadd_send_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_direct_send_result_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSendViaTorConstMeta,
+      argValues: [handle, address, amountNano, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSendViaTorConstMeta => const TaskConstMeta(
+        debugName: "wallet_send_via_tor",
+        argNames: ["handle", "address", "amountNano", "options"],
+      );
+
+  @override
+  Future<AccountDto> crateApiWalletSetActiveAccount(
+      {required BigInt handle, required String label}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(label, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_account_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSetActiveAccountConstMeta,
+      argValues: [handle, label],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSetActiveAccountConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_set_active_account",
+        argNames: ["handle", "label"],
+      );
+
+  @override
+  Future<void> crateApiWalletSetAutoLock(
+      {required BigInt handle, required BigInt timeoutSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_64(timeoutSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSetAutoLockConstMeta,
+      argValues: [handle, timeoutSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSetAutoLockConstMeta => const TaskConstMeta(
+        debugName: "wallet_set_auto_lock",
+        argNames: ["handle", "timeoutSecs"],
+      );
+
+  @override
+  Future<void> crateApiWalletSetDefaultTtl(
+      {required BigInt handle, required BigInt ttlBlocks}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_64(ttlBlocks, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSetDefaultTtlConstMeta,
+      argValues: [handle, ttlBlocks],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSetDefaultTtlConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_set_default_ttl",
+        argNames: ["handle", "ttlBlocks"],
+      );
+
+  @override
+  Future<void> crateApiWalletSetRequireReview(
+      {required BigInt handle, required bool required}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_bool(required, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 57, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSetRequireReviewConstMeta,
+      argValues: [handle, required],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSetRequireReviewConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_set_require_review",
+        argNames: ["handle", "required"],
+      );
+
+  @override
+  Future<TxMetadataDto?> crateApiWalletSetTxMetadata(
+      {required BigInt handle,
+      required String parentKeyId,
+      required int txId,
+      required TxMetadataDto metadata}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(parentKeyId, serializer);
+        sse_encode_u_32(txId, serializer);
+        sse_encode_box_autoadd_tx_metadata_dto(metadata, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 58, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_tx_metadata_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSetTxMetadataConstMeta,
+      argValues: [handle, parentKeyId, txId, metadata],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSetTxMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_set_tx_metadata",
+        argNames: ["handle", "parentKeyId", "txId", "metadata"],
+      );
+
+  @override
+  Future<UpdaterStatusDto> crateApiWalletStartUpdater(
+      {required BigInt handle, required BigInt intervalSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_64(intervalSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 59, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_updater_status_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletStartUpdaterConstMeta,
+      argValues: [handle, intervalSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletStartUpdaterConstMeta => const TaskConstMeta(
+        debugName: "wallet_start_updater",
+        argNames: ["handle", "intervalSecs"],
+      );
+
+  @override
+  Future<UpdaterStatusDto> crateApiWalletStopUpdater({required BigInt handle}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 60, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_updater_status_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletStopUpdaterConstMeta,
+      argValues: [handle],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletStopUpdaterConstMeta => const TaskConstMeta(
+        debugName: "wallet_stop_updater",
+        argNames: ["handle"],
+      );
+
+  @override
+  Future<void> crateApiWalletSync({required BigInt handle}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 61, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletSyncConstMeta,
+      argValues: [handle],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletSyncConstMeta => const TaskConstMeta(
+        debugName: "wallet_sync",
+        argNames: ["handle"],
+      );
+
+  @override
+  Future<String> crateApiWalletTransactionSlatepack(
+      {required BigInt handle, required int txId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_32(txId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 62, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletTransactionSlatepackConstMeta,
+      argValues: [handle, txId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletTransactionSlatepackConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_transaction_slatepack",
+        argNames: ["handle", "txId"],
+      );
+
+  @override
+  Future<TxMetadataDto?> crateApiWalletTxMetadata(
+      {required BigInt handle,
+      required String parentKeyId,
+      required int txId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(parentKeyId, serializer);
+        sse_encode_u_32(txId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_tx_metadata_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletTxMetadataConstMeta,
+      argValues: [handle, parentKeyId, txId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletTxMetadataConstMeta => const TaskConstMeta(
+        debugName: "wallet_tx_metadata",
+        argNames: ["handle", "parentKeyId", "txId"],
+      );
+
+  @override
+  Future<UpdaterStatusDto> crateApiWalletUpdaterStatus(
+      {required BigInt handle}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_updater_status_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletUpdaterStatusConstMeta,
+      argValues: [handle],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletUpdaterStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_updater_status",
+        argNames: ["handle"],
+      );
+
+  @override
+  Future<PaymentProofVerificationDto> crateApiWalletVerifyPaymentProof(
+      {required BigInt handle, required String payload}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_String(payload, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 65, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_payment_proof_verification_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWalletVerifyPaymentProofConstMeta,
+      argValues: [handle, payload],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWalletVerifyPaymentProofConstMeta =>
+      const TaskConstMeta(
+        debugName: "wallet_verify_payment_proof",
+        argNames: ["handle", "payload"],
+      );
+
+  @override
+  Future<SlatepackFileDto> crateApiWriteSlatepackFile(
+      {required String message,
+      required String path,
+      required bool binary,
+      required bool overwrite}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(message, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_bool(binary, serializer);
+        sse_encode_bool(overwrite, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 66, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_slatepack_file_dto,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateApiWriteSlatepackFileConstMeta,
+      argValues: [message, path, binary, overwrite],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiWriteSlatepackFileConstMeta => const TaskConstMeta(
+        debugName: "write_slatepack_file",
+        argNames: ["message", "path", "binary", "overwrite"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<WalletEventDto> dco_decode_StreamSink_wallet_event_dto_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AccountDto dco_decode_account_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AccountDto(
+      label: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      isActive: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ProcessSlatepackOptions dco_decode_box_autoadd_process_slatepack_options(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_process_slatepack_options(raw);
+  }
+
+  @protected
+  ScanProgressDto dco_decode_box_autoadd_scan_progress_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_progress_dto(raw);
+  }
+
+  @protected
+  ScanResultDto dco_decode_box_autoadd_scan_result_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_scan_result_dto(raw);
+  }
+
+  @protected
+  SendOptions dco_decode_box_autoadd_send_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_send_options(raw);
+  }
+
+  @protected
+  SlateExpectation dco_decode_box_autoadd_slate_expectation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_slate_expectation(raw);
+  }
+
+  @protected
+  SlatepackFileTarget dco_decode_box_autoadd_slatepack_file_target(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_slatepack_file_target(raw);
+  }
+
+  @protected
+  TransactionDto dco_decode_box_autoadd_transaction_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_transaction_dto(raw);
+  }
+
+  @protected
+  TxMetadataDto dco_decode_box_autoadd_tx_metadata_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_tx_metadata_dto(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  UpdaterMessageDto dco_decode_box_autoadd_updater_message_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_updater_message_dto(raw);
+  }
+
+  @protected
+  WalletChainType dco_decode_box_autoadd_wallet_chain_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_chain_type(raw);
+  }
+
+  @protected
+  WalletInfoDto dco_decode_box_autoadd_wallet_info_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wallet_info_dto(raw);
+  }
+
+  @protected
+  BridgeError dco_decode_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return BridgeError_NotInitialized(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return BridgeError_WalletNotOpen(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return BridgeError_WalletAlreadyOpen(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 3:
+        return BridgeError_WrongPassword(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 4:
+        return BridgeError_InsufficientFunds(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 5:
+        return BridgeError_NodeUnreachable(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 6:
+        return BridgeError_InvalidSlatepack(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 7:
+        return BridgeError_InvalidAddress(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 8:
+        return BridgeError_InvalidInput(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 9:
+        return BridgeError_AccountError(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 10:
+        return BridgeError_TransactionError(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 11:
+        return BridgeError_PaymentProofError(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 12:
+        return BridgeError_TorFailed(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 13:
+        return BridgeError_ListenerFailed(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 14:
+        return BridgeError_Cancelled(
+          detail: dco_decode_String(raw[1]),
+        );
+      case 15:
+        return BridgeError_Internal(
+          detail: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  BridgeLocale dco_decode_bridge_locale(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BridgeLocale.values[raw as int];
+  }
+
+  @protected
+  CreatedWallet dco_decode_created_wallet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CreatedWallet(
+      handle: dco_decode_u_64(arr[0]),
+      mnemonic: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  DirectSendResultDto dco_decode_direct_send_result_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DirectSendResultDto(
+      txSlateId: dco_decode_String(arr[0]),
+      delivered: dco_decode_bool(arr[1]),
+      slatepack: dco_decode_opt_String(arr[2]),
+      fallbackReason: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AccountDto> dco_decode_list_account_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_account_dto).toList();
+  }
+
+  @protected
+  List<OutputDto> dco_decode_list_output_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_output_dto).toList();
+  }
+
+  @protected
+  List<PayoutItem> dco_decode_list_payout_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payout_item).toList();
+  }
+
+  @protected
+  List<PayoutResultDto> dco_decode_list_payout_result_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payout_result_dto).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<TransactionDto> dco_decode_list_transaction_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transaction_dto).toList();
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  SlatepackFileTarget? dco_decode_opt_box_autoadd_slatepack_file_target(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_slatepack_file_target(raw);
+  }
+
+  @protected
+  TxMetadataDto? dco_decode_opt_box_autoadd_tx_metadata_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_tx_metadata_dto(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  UpdaterMessageDto? dco_decode_opt_box_autoadd_updater_message_dto(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_updater_message_dto(raw);
+  }
+
+  @protected
+  WalletChainType? dco_decode_opt_box_autoadd_wallet_chain_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_wallet_chain_type(raw);
+  }
+
+  @protected
+  OutputDto dco_decode_output_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return OutputDto(
+      commitment: dco_decode_String(arr[0]),
+      value: dco_decode_u_64(arr[1]),
+      status: dco_decode_String(arr[2]),
+      height: dco_decode_u_64(arr[3]),
+      lockHeight: dco_decode_u_64(arr[4]),
+      isCoinbase: dco_decode_bool(arr[5]),
+      mmrIndex: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      txLogId: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      confirmations: dco_decode_u_64(arr[8]),
+      spendable: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  OwnerListenerStatusDto dco_decode_owner_listener_status_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return OwnerListenerStatusDto(
+      running: dco_decode_bool(arr[0]),
+      listenAddr: dco_decode_String(arr[1]),
+      message: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  PaymentProofDto dco_decode_payment_proof_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PaymentProofDto(
+      txId: dco_decode_u_32(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      excess: dco_decode_String(arr[2]),
+      recipientAddress: dco_decode_String(arr[3]),
+      recipientSig: dco_decode_String(arr[4]),
+      senderAddress: dco_decode_String(arr[5]),
+      senderSig: dco_decode_String(arr[6]),
+      proofJson: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  PaymentProofState dco_decode_payment_proof_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaymentProofState.values[raw as int];
+  }
+
+  @protected
+  PaymentProofVerificationDto dco_decode_payment_proof_verification_dto(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaymentProofVerificationDto(
+      isSender: dco_decode_bool(arr[0]),
+      isRecipient: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  PayoutItem dco_decode_payout_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PayoutItem(
+      address: dco_decode_String(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      memo: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  PayoutReportDto dco_decode_payout_report_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PayoutReportDto(
+      items: dco_decode_list_payout_result_dto(arr[0]),
+      delivered: dco_decode_u_32(arr[1]),
+      manual: dco_decode_u_32(arr[2]),
+      skipped: dco_decode_u_32(arr[3]),
+      failed: dco_decode_u_32(arr[4]),
+      totalCommitted: dco_decode_u_64(arr[5]),
+    );
+  }
+
+  @protected
+  PayoutResultDto dco_decode_payout_result_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PayoutResultDto(
+      address: dco_decode_String(arr[0]),
+      amount: dco_decode_u_64(arr[1]),
+      memo: dco_decode_opt_String(arr[2]),
+      status: dco_decode_payout_status(arr[3]),
+      txSlateId: dco_decode_opt_String(arr[4]),
+      fee: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      slatepack: dco_decode_opt_String(arr[6]),
+      error: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  PayoutStatus dco_decode_payout_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PayoutStatus.values[raw as int];
+  }
+
+  @protected
+  ProcessSlatepackOptions dco_decode_process_slatepack_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProcessSlatepackOptions(
+      send: dco_decode_send_options(arr[0]),
+      postTx: dco_decode_opt_box_autoadd_bool(arr[1]),
+      fluff: dco_decode_opt_box_autoadd_bool(arr[2]),
+    );
+  }
+
+  @protected
+  ProcessedSlatepackDto dco_decode_processed_slatepack_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProcessedSlatepackDto(
+      action: dco_decode_slatepack_action(arr[0]),
+      slateId: dco_decode_String(arr[1]),
+      state: dco_decode_slate_code(arr[2]),
+      posted: dco_decode_bool(arr[3]),
+      response: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  QrScanProgressDto dco_decode_qr_scan_progress_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QrScanProgressDto(
+      received: dco_decode_u_32(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+      message: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  RestoreResultDto dco_decode_restore_result_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RestoreResultDto(
+      handle: dco_decode_u_64(arr[0]),
+      completed: dco_decode_bool(arr[1]),
+      resumeHeight: dco_decode_opt_box_autoadd_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  ScanProgressDto dco_decode_scan_progress_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScanProgressDto(
+      startHeight: dco_decode_u_64(arr[0]),
+      currentHeight: dco_decode_u_64(arr[1]),
+      tipHeight: dco_decode_u_64(arr[2]),
+      percent: dco_decode_u_8(arr[3]),
+    );
+  }
+
+  @protected
+  ScanResultDto dco_decode_scan_result_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScanResultDto(
+      deleteUnconfirmed: dco_decode_bool(arr[0]),
+      startHeight: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      backwardsFromTip: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      performedAtEpochSecs: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  SendEstimateDto dco_decode_send_estimate_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SendEstimateDto(
+      amount: dco_decode_u_64(arr[0]),
+      fee: dco_decode_u_64(arr[1]),
+      numInputs: dco_decode_usize(arr[2]),
+      totalInputs: dco_decode_u_64(arr[3]),
+      change: dco_decode_u_64(arr[4]),
+      sufficient: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  SendOptions dco_decode_send_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return SendOptions(
+      minimumConfirmations: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      maxOutputs: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      numChangeOutputs: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      selectionStrategyIsUseAll: dco_decode_opt_box_autoadd_bool(arr[3]),
+      amountIncludesFee: dco_decode_opt_box_autoadd_bool(arr[4]),
+      sendAll: dco_decode_opt_box_autoadd_bool(arr[5]),
+      srcAcctName: dco_decode_opt_String(arr[6]),
+      requestPaymentProof: dco_decode_opt_box_autoadd_bool(arr[7]),
+      ttlBlocks: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      lateLock: dco_decode_opt_box_autoadd_bool(arr[9]),
+      fluff: dco_decode_opt_box_autoadd_bool(arr[10]),
+    );
+  }
+
+  @protected
+  SlateCode dco_decode_slate_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SlateCode.values[raw as int];
+  }
+
+  @protected
+  SlateExpectation dco_decode_slate_expectation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SlateExpectation(
+      amount: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      maxFee: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  SlateInspectionDto dco_decode_slate_inspection_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SlateInspectionDto(
+      code: dco_decode_slate_code(arr[0]),
+      slateId: dco_decode_String(arr[1]),
+      state: dco_decode_String(arr[2]),
+      amount: dco_decode_u_64(arr[3]),
+      fee: dco_decode_u_64(arr[4]),
+      numParticipants: dco_decode_u_16(arr[5]),
+      kernelExcess: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  SlateReviewDto dco_decode_slate_review_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return SlateReviewDto(
+      slateId: dco_decode_String(arr[0]),
+      state: dco_decode_slate_code(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+      fee: dco_decode_u_64(arr[3]),
+      senderAddress: dco_decode_opt_String(arr[4]),
+      encryptedToUs: dco_decode_bool(arr[5]),
+      ttlCutoffHeight: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      paymentProofRequested: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  SlatepackAction dco_decode_slatepack_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SlatepackAction.values[raw as int];
+  }
+
+  @protected
+  SlatepackFileDto dco_decode_slatepack_file_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SlatepackFileDto(
+      path: dco_decode_String(arr[0]),
+      binary: dco_decode_bool(arr[1]),
+      sizeBytes: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  SlatepackFileTarget dco_decode_slatepack_file_target(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SlatepackFileTarget(
+      path: dco_decode_String(arr[0]),
+      binary: dco_decode_bool(arr[1]),
+      overwrite: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  TorStatusDto dco_decode_tor_status_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TorStatusDto(
+      running: dco_decode_bool(arr[0]),
+      onionAddress: dco_decode_opt_String(arr[1]),
+      slatepackAddress: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  TransactionDto dco_decode_transaction_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 20)
+      throw Exception('unexpected arr length: expect 20 but see ${arr.length}');
+    return TransactionDto(
+      id: dco_decode_u_32(arr[0]),
+      parentKeyId: dco_decode_String(arr[1]),
+      txSlateId: dco_decode_opt_String(arr[2]),
+      txType: dco_decode_String(arr[3]),
+      status: dco_decode_String(arr[4]),
+      direction: dco_decode_String(arr[5]),
+      creationTs: dco_decode_String(arr[6]),
+      confirmationTs: dco_decode_opt_String(arr[7]),
+      confirmed: dco_decode_bool(arr[8]),
+      amount: dco_decode_u_64(arr[9]),
+      fee: dco_decode_opt_box_autoadd_u_64(arr[10]),
+      numInputs: dco_decode_usize(arr[11]),
+      numOutputs: dco_decode_usize(arr[12]),
+      hasProof: dco_decode_bool(arr[13]),
+      paymentProofState: dco_decode_payment_proof_state(arr[14]),
+      kernelExcess: dco_decode_opt_String(arr[15]),
+      ttlCutoffHeight: dco_decode_opt_box_autoadd_u_64(arr[16]),
+      revertedAfterSecs: dco_decode_opt_box_autoadd_u_64(arr[17]),
+      confirmations: dco_decode_u_64(arr[18]),
+      metadata: dco_decode_opt_box_autoadd_tx_metadata_dto(arr[19]),
+    );
+  }
+
+  @protected
+  TxMetadataDto dco_decode_tx_metadata_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TxMetadataDto(
+      note: dco_decode_opt_String(arr[0]),
+      category: dco_decode_opt_String(arr[1]),
+      counterparty: dco_decode_opt_String(arr[2]),
+      updatedAt: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UpdaterMessageDto dco_decode_updater_message_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return UpdaterMessageDto_UpdatingOutputs(
+          message: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return UpdaterMessageDto_UpdatingTransactions(
+          message: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return UpdaterMessageDto_FullScanWarn(
+          message: dco_decode_String(raw[1]),
+        );
+      case 3:
+        return UpdaterMessageDto_Scanning(
+          message: dco_decode_String(raw[1]),
+          percent: dco_decode_u_8(raw[2]),
+        );
+      case 4:
+        return UpdaterMessageDto_ScanningComplete(
+          message: dco_decode_String(raw[1]),
+        );
+      case 5:
+        return UpdaterMessageDto_UpdateWarning(
+          message: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  UpdaterStatusDto dco_decode_updater_status_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return UpdaterStatusDto(
+      running: dco_decode_bool(arr[0]),
+      intervalSecs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      lastConfirmedHeight: dco_decode_u_64(arr[2]),
+      lastMessage: dco_decode_opt_box_autoadd_updater_message_dto(arr[3]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WalletChainType dco_decode_wallet_chain_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WalletChainType.values[raw as int];
+  }
+
+  @protected
+  WalletCloseReportDto dco_decode_wallet_close_report_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WalletCloseReportDto(
+      handle: dco_decode_u_64(arr[0]),
+      foreignListenerStopped: dco_decode_bool(arr[1]),
+      ownerListenerStopped: dco_decode_bool(arr[2]),
+      torStopped: dco_decode_bool(arr[3]),
+      updaterStopped: dco_decode_bool(arr[4]),
+      keychainCleared: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  WalletEventDto dco_decode_wallet_event_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return WalletEventDto_AutoLocked(
+          handle: dco_decode_u_64(raw[1]),
+          idleSecs: dco_decode_u_64(raw[2]),
+        );
+      case 1:
+        return WalletEventDto_IncomingSlate(
+          handle: dco_decode_u_64(raw[1]),
+          tx: dco_decode_box_autoadd_transaction_dto(raw[2]),
+        );
+      case 2:
+        return WalletEventDto_ConfirmationsChanged(
+          handle: dco_decode_u_64(raw[1]),
+          txId: dco_decode_u_32(raw[2]),
+          confirmations: dco_decode_u_64(raw[3]),
+        );
+      case 3:
+        return WalletEventDto_BalanceChanged(
+          handle: dco_decode_u_64(raw[1]),
+          balance: dco_decode_box_autoadd_wallet_info_dto(raw[2]),
+        );
+      case 4:
+        return WalletEventDto_TxReverted(
+          handle: dco_decode_u_64(raw[1]),
+          txId: dco_decode_u_32(raw[2]),
+        );
+      case 5:
+        return WalletEventDto_TxExpired(
+          handle: dco_decode_u_64(raw[1]),
+          txId: dco_decode_u_32(raw[2]),
+          txSlateId: dco_decode_opt_String(raw[3]),
+          unlockedAmount: dco_decode_u_64(raw[4]),
+          unlockedInputs: dco_decode_usize(raw[5]),
+        );
+      case 6:
+        return WalletEventDto_ScanProgress(
+          handle: dco_decode_u_64(raw[1]),
+          progress: dco_decode_box_autoadd_scan_progress_dto(raw[2]),
+        );
+      case 7:
+        return WalletEventDto_ScanCompleted(
+          handle: dco_decode_u_64(raw[1]),
+          result: dco_decode_box_autoadd_scan_result_dto(raw[2]),
+        );
+      case 8:
+        return WalletEventDto_UpdaterProgress(
+          handle: dco_decode_u_64(raw[1]),
+          message: dco_decode_box_autoadd_updater_message_dto(raw[2]),
+        );
+      case 9:
+        return WalletEventDto_NodeConnectivityChanged(
+          reachable: dco_decode_bool(raw[1]),
+          tipHeight: dco_decode_opt_box_autoadd_u_64(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  WalletInfoDto dco_decode_wallet_info_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return WalletInfoDto(
+      refreshedFromNode: dco_decode_bool(arr[0]),
+      activeAccount: dco_decode_String(arr[1]),
+      lastConfirmedHeight: dco_decode_u_64(arr[2]),
+      minimumConfirmations: dco_decode_u_64(arr[3]),
+      total: dco_decode_u_64(arr[4]),
+      amountAwaitingFinalization: dco_decode_u_64(arr[5]),
+      amountAwaitingConfirmation: dco_decode_u_64(arr[6]),
+      amountImmature: dco_decode_u_64(arr[7]),
+      amountCurrentlySpendable: dco_decode_u_64(arr[8]),
+      amountLocked: dco_decode_u_64(arr[9]),
+      amountReverted: dco_decode_u_64(arr[10]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<WalletEventDto> sse_decode_StreamSink_wallet_event_dto_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AccountDto sse_decode_account_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_label = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_isActive = sse_decode_bool(deserializer);
+    return AccountDto(label: var_label, path: var_path, isActive: var_isActive);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  ProcessSlatepackOptions sse_decode_box_autoadd_process_slatepack_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_process_slatepack_options(deserializer));
+  }
+
+  @protected
+  ScanProgressDto sse_decode_box_autoadd_scan_progress_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_progress_dto(deserializer));
+  }
+
+  @protected
+  ScanResultDto sse_decode_box_autoadd_scan_result_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_scan_result_dto(deserializer));
+  }
+
+  @protected
+  SendOptions sse_decode_box_autoadd_send_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_send_options(deserializer));
+  }
+
+  @protected
+  SlateExpectation sse_decode_box_autoadd_slate_expectation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_slate_expectation(deserializer));
+  }
+
+  @protected
+  SlatepackFileTarget sse_decode_box_autoadd_slatepack_file_target(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_slatepack_file_target(deserializer));
+  }
+
+  @protected
+  TransactionDto sse_decode_box_autoadd_transaction_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_transaction_dto(deserializer));
+  }
+
+  @protected
+  TxMetadataDto sse_decode_box_autoadd_tx_metadata_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_tx_metadata_dto(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  UpdaterMessageDto sse_decode_box_autoadd_updater_message_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_updater_message_dto(deserializer));
+  }
+
+  @protected
+  WalletChainType sse_decode_box_autoadd_wallet_chain_type(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_chain_type(deserializer));
+  }
+
+  @protected
+  WalletInfoDto sse_decode_box_autoadd_wallet_info_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wallet_info_dto(deserializer));
+  }
+
+  @protected
+  BridgeError sse_decode_bridge_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_NotInitialized(detail: var_detail);
+      case 1:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_WalletNotOpen(detail: var_detail);
+      case 2:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_WalletAlreadyOpen(detail: var_detail);
+      case 3:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_WrongPassword(detail: var_detail);
+      case 4:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_InsufficientFunds(detail: var_detail);
+      case 5:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_NodeUnreachable(detail: var_detail);
+      case 6:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_InvalidSlatepack(detail: var_detail);
+      case 7:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_InvalidAddress(detail: var_detail);
+      case 8:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_InvalidInput(detail: var_detail);
+      case 9:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_AccountError(detail: var_detail);
+      case 10:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_TransactionError(detail: var_detail);
+      case 11:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_PaymentProofError(detail: var_detail);
+      case 12:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_TorFailed(detail: var_detail);
+      case 13:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_ListenerFailed(detail: var_detail);
+      case 14:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_Cancelled(detail: var_detail);
+      case 15:
+        var var_detail = sse_decode_String(deserializer);
+        return BridgeError_Internal(detail: var_detail);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  BridgeLocale sse_decode_bridge_locale(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BridgeLocale.values[inner];
+  }
+
+  @protected
+  CreatedWallet sse_decode_created_wallet(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_handle = sse_decode_u_64(deserializer);
+    var var_mnemonic = sse_decode_String(deserializer);
+    return CreatedWallet(handle: var_handle, mnemonic: var_mnemonic);
+  }
+
+  @protected
+  DirectSendResultDto sse_decode_direct_send_result_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txSlateId = sse_decode_String(deserializer);
+    var var_delivered = sse_decode_bool(deserializer);
+    var var_slatepack = sse_decode_opt_String(deserializer);
+    var var_fallbackReason = sse_decode_opt_String(deserializer);
+    return DirectSendResultDto(
+        txSlateId: var_txSlateId,
+        delivered: var_delivered,
+        slatepack: var_slatepack,
+        fallbackReason: var_fallbackReason);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AccountDto> sse_decode_list_account_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccountDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_account_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutputDto> sse_decode_list_output_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutputDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_output_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PayoutItem> sse_decode_list_payout_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PayoutItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payout_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PayoutResultDto> sse_decode_list_payout_result_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PayoutResultDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payout_result_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<TransactionDto> sse_decode_list_transaction_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TransactionDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transaction_dto(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SlatepackFileTarget? sse_decode_opt_box_autoadd_slatepack_file_target(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_slatepack_file_target(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TxMetadataDto? sse_decode_opt_box_autoadd_tx_metadata_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_tx_metadata_dto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  UpdaterMessageDto? sse_decode_opt_box_autoadd_updater_message_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_updater_message_dto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WalletChainType? sse_decode_opt_box_autoadd_wallet_chain_type(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_wallet_chain_type(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutputDto sse_decode_output_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_commitment = sse_decode_String(deserializer);
+    var var_value = sse_decode_u_64(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_height = sse_decode_u_64(deserializer);
+    var var_lockHeight = sse_decode_u_64(deserializer);
+    var var_isCoinbase = sse_decode_bool(deserializer);
+    var var_mmrIndex = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_txLogId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_confirmations = sse_decode_u_64(deserializer);
+    var var_spendable = sse_decode_bool(deserializer);
+    return OutputDto(
+        commitment: var_commitment,
+        value: var_value,
+        status: var_status,
+        height: var_height,
+        lockHeight: var_lockHeight,
+        isCoinbase: var_isCoinbase,
+        mmrIndex: var_mmrIndex,
+        txLogId: var_txLogId,
+        confirmations: var_confirmations,
+        spendable: var_spendable);
+  }
+
+  @protected
+  OwnerListenerStatusDto sse_decode_owner_listener_status_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_running = sse_decode_bool(deserializer);
+    var var_listenAddr = sse_decode_String(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    return OwnerListenerStatusDto(
+        running: var_running, listenAddr: var_listenAddr, message: var_message);
+  }
+
+  @protected
+  PaymentProofDto sse_decode_payment_proof_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txId = sse_decode_u_32(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_excess = sse_decode_String(deserializer);
+    var var_recipientAddress = sse_decode_String(deserializer);
+    var var_recipientSig = sse_decode_String(deserializer);
+    var var_senderAddress = sse_decode_String(deserializer);
+    var var_senderSig = sse_decode_String(deserializer);
+    var var_proofJson = sse_decode_String(deserializer);
+    return PaymentProofDto(
+        txId: var_txId,
+        amount: var_amount,
+        excess: var_excess,
+        recipientAddress: var_recipientAddress,
+        recipientSig: var_recipientSig,
+        senderAddress: var_senderAddress,
+        senderSig: var_senderSig,
+        proofJson: var_proofJson);
+  }
+
+  @protected
+  PaymentProofState sse_decode_payment_proof_state(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaymentProofState.values[inner];
+  }
+
+  @protected
+  PaymentProofVerificationDto sse_decode_payment_proof_verification_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_isSender = sse_decode_bool(deserializer);
+    var var_isRecipient = sse_decode_bool(deserializer);
+    return PaymentProofVerificationDto(
+        isSender: var_isSender, isRecipient: var_isRecipient);
+  }
+
+  @protected
+  PayoutItem sse_decode_payout_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_memo = sse_decode_opt_String(deserializer);
+    return PayoutItem(address: var_address, amount: var_amount, memo: var_memo);
+  }
+
+  @protected
+  PayoutReportDto sse_decode_payout_report_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_items = sse_decode_list_payout_result_dto(deserializer);
+    var var_delivered = sse_decode_u_32(deserializer);
+    var var_manual = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_u_32(deserializer);
+    var var_failed = sse_decode_u_32(deserializer);
+    var var_totalCommitted = sse_decode_u_64(deserializer);
+    return PayoutReportDto(
+        items: var_items,
+        delivered: var_delivered,
+        manual: var_manual,
+        skipped: var_skipped,
+        failed: var_failed,
+        totalCommitted: var_totalCommitted);
+  }
+
+  @protected
+  PayoutResultDto sse_decode_payout_result_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_memo = sse_decode_opt_String(deserializer);
+    var var_status = sse_decode_payout_status(deserializer);
+    var var_txSlateId = sse_decode_opt_String(deserializer);
+    var var_fee = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_slatepack = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return PayoutResultDto(
+        address: var_address,
+        amount: var_amount,
+        memo: var_memo,
+        status: var_status,
+        txSlateId: var_txSlateId,
+        fee: var_fee,
+        slatepack: var_slatepack,
+        error: var_error);
+  }
+
+  @protected
+  PayoutStatus sse_decode_payout_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PayoutStatus.values[inner];
+  }
+
+  @protected
+  ProcessSlatepackOptions sse_decode_process_slatepack_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_send = sse_decode_send_options(deserializer);
+    var var_postTx = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_fluff = sse_decode_opt_box_autoadd_bool(deserializer);
+    return ProcessSlatepackOptions(
+        send: var_send, postTx: var_postTx, fluff: var_fluff);
+  }
+
+  @protected
+  ProcessedSlatepackDto sse_decode_processed_slatepack_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_slatepack_action(deserializer);
+    var var_slateId = sse_decode_String(deserializer);
+    var var_state = sse_decode_slate_code(deserializer);
+    var var_posted = sse_decode_bool(deserializer);
+    var var_response = sse_decode_String(deserializer);
+    return ProcessedSlatepackDto(
+        action: var_action,
+        slateId: var_slateId,
+        state: var_state,
+        posted: var_posted,
+        response: var_response);
+  }
+
+  @protected
+  QrScanProgressDto sse_decode_qr_scan_progress_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_received = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    return QrScanProgressDto(
+        received: var_received, total: var_total, message: var_message);
+  }
+
+  @protected
+  RestoreResultDto sse_decode_restore_result_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_handle = sse_decode_u_64(deserializer);
+    var var_completed = sse_decode_bool(deserializer);
+    var var_resumeHeight = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return RestoreResultDto(
+        handle: var_handle,
+        completed: var_completed,
+        resumeHeight: var_resumeHeight);
+  }
+
+  @protected
+  ScanProgressDto sse_decode_scan_progress_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startHeight = sse_decode_u_64(deserializer);
+    var var_currentHeight = sse_decode_u_64(deserializer);
+    var var_tipHeight = sse_decode_u_64(deserializer);
+    var var_percent = sse_decode_u_8(deserializer);
+    return ScanProgressDto(
+        startHeight: var_startHeight,
+        currentHeight: var_currentHeight,
+        tipHeight: var_tipHeight,
+        percent: var_percent);
+  }
+
+  @protected
+  ScanResultDto sse_decode_scan_result_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deleteUnconfirmed = sse_decode_bool(deserializer);
+    var var_startHeight = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_backwardsFromTip = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_performedAtEpochSecs = sse_decode_u_64(deserializer);
+    return ScanResultDto(
+        deleteUnconfirmed: var_deleteUnconfirmed,
+        startHeight: var_startHeight,
+        backwardsFromTip: var_backwardsFromTip,
+        performedAtEpochSecs: var_performedAtEpochSecs);
+  }
+
+  @protected
+  SendEstimateDto sse_decode_send_estimate_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_fee = sse_decode_u_64(deserializer);
+    var var_numInputs = sse_decode_usize(deserializer);
+    var var_totalInputs = sse_decode_u_64(deserializer);
+    var var_change = sse_decode_u_64(deserializer);
+    var var_sufficient = sse_decode_bool(deserializer);
+    return SendEstimateDto(
+        amount: var_amount,
+        fee: var_fee,
+        numInputs: var_numInputs,
+        totalInputs: var_totalInputs,
+        change: var_change,
+        sufficient: var_sufficient);
+  }
+
+  @protected
+  SendOptions sse_decode_send_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minimumConfirmations =
+        sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxOutputs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_numChangeOutputs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_selectionStrategyIsUseAll =
+        sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_amountIncludesFee = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_sendAll = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_srcAcctName = sse_decode_opt_String(deserializer);
+    var var_requestPaymentProof = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_ttlBlocks = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_lateLock = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_fluff = sse_decode_opt_box_autoadd_bool(deserializer);
+    return SendOptions(
+        minimumConfirmations: var_minimumConfirmations,
+        maxOutputs: var_maxOutputs,
+        numChangeOutputs: var_numChangeOutputs,
+        selectionStrategyIsUseAll: var_selectionStrategyIsUseAll,
+        amountIncludesFee: var_amountIncludesFee,
+        sendAll: var_sendAll,
+        srcAcctName: var_srcAcctName,
+        requestPaymentProof: var_requestPaymentProof,
+        ttlBlocks: var_ttlBlocks,
+        lateLock: var_lateLock,
+        fluff: var_fluff);
+  }
+
+  @protected
+  SlateCode sse_decode_slate_code(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SlateCode.values[inner];
+  }
+
+  @protected
+  SlateExpectation sse_decode_slate_expectation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxFee = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return SlateExpectation(amount: var_amount, maxFee: var_maxFee);
+  }
+
+  @protected
+  SlateInspectionDto sse_decode_slate_inspection_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_slate_code(deserializer);
+    var var_slateId = sse_decode_String(deserializer);
+    var var_state = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_fee = sse_decode_u_64(deserializer);
+    var var_numParticipants = sse_decode_u_16(deserializer);
+    var var_kernelExcess = sse_decode_opt_String(deserializer);
+    return SlateInspectionDto(
+        code: var_code,
+        slateId: var_slateId,
+        state: var_state,
+        amount: var_amount,
+        fee: var_fee,
+        numParticipants: var_numParticipants,
+        kernelExcess: var_kernelExcess);
+  }
+
+  @protected
+  SlateReviewDto sse_decode_slate_review_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_slateId = sse_decode_String(deserializer);
+    var var_state = sse_decode_slate_code(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_fee = sse_decode_u_64(deserializer);
+    var var_senderAddress = sse_decode_opt_String(deserializer);
+    var var_encryptedToUs = sse_decode_bool(deserializer);
+    var var_ttlCutoffHeight = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_paymentProofRequested = sse_decode_bool(deserializer);
+    return SlateReviewDto(
+        slateId: var_slateId,
+        state: var_state,
+        amount: var_amount,
+        fee: var_fee,
+        senderAddress: var_senderAddress,
+        encryptedToUs: var_encryptedToUs,
+        ttlCutoffHeight: var_ttlCutoffHeight,
+        paymentProofRequested: var_paymentProofRequested);
+  }
+
+  @protected
+  SlatepackAction sse_decode_slatepack_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SlatepackAction.values[inner];
+  }
+
+  @protected
+  SlatepackFileDto sse_decode_slatepack_file_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_binary = sse_decode_bool(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    return SlatepackFileDto(
+        path: var_path, binary: var_binary, sizeBytes: var_sizeBytes);
+  }
+
+  @protected
+  SlatepackFileTarget sse_decode_slatepack_file_target(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_binary = sse_decode_bool(deserializer);
+    var var_overwrite = sse_decode_bool(deserializer);
+    return SlatepackFileTarget(
+        path: var_path, binary: var_binary, overwrite: var_overwrite);
+  }
+
+  @protected
+  TorStatusDto sse_decode_tor_status_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_running = sse_decode_bool(deserializer);
+    var var_onionAddress = sse_decode_opt_String(deserializer);
+    var var_slatepackAddress = sse_decode_opt_String(deserializer);
+    return TorStatusDto(
+        running: var_running,
+        onionAddress: var_onionAddress,
+        slatepackAddress: var_slatepackAddress);
+  }
+
+  @protected
+  TransactionDto sse_decode_transaction_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_parentKeyId = sse_decode_String(deserializer);
+    var var_txSlateId = sse_decode_opt_String(deserializer);
+    var var_txType = sse_decode_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_direction = sse_decode_String(deserializer);
+    var var_creationTs = sse_decode_String(deserializer);
+    var var_confirmationTs = sse_decode_opt_String(deserializer);
+    var var_confirmed = sse_decode_bool(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_fee = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_numInputs = sse_decode_usize(deserializer);
+    var var_numOutputs = sse_decode_usize(deserializer);
+    var var_hasProof = sse_decode_bool(deserializer);
+    var var_paymentProofState = sse_decode_payment_proof_state(deserializer);
+    var var_kernelExcess = sse_decode_opt_String(deserializer);
+    var var_ttlCutoffHeight = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_revertedAfterSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_confirmations = sse_decode_u_64(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_tx_metadata_dto(deserializer);
+    return TransactionDto(
+        id: var_id,
+        parentKeyId: var_parentKeyId,
+        txSlateId: var_txSlateId,
+        txType: var_txType,
+        status: var_status,
+        direction: var_direction,
+        creationTs: var_creationTs,
+        confirmationTs: var_confirmationTs,
+        confirmed: var_confirmed,
+        amount: var_amount,
+        fee: var_fee,
+        numInputs: var_numInputs,
+        numOutputs: var_numOutputs,
+        hasProof: var_hasProof,
+        paymentProofState: var_paymentProofState,
+        kernelExcess: var_kernelExcess,
+        ttlCutoffHeight: var_ttlCutoffHeight,
+        revertedAfterSecs: var_revertedAfterSecs,
+        confirmations: var_confirmations,
+        metadata: var_metadata);
+  }
+
+  @protected
+  TxMetadataDto sse_decode_tx_metadata_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_note = sse_decode_opt_String(deserializer);
+    var var_category = sse_decode_opt_String(deserializer);
+    var var_counterparty = sse_decode_opt_String(deserializer);
+    var var_updatedAt = sse_decode_opt_String(deserializer);
+    return TxMetadataDto(
+        note: var_note,
+        category: var_category,
+        counterparty: var_counterparty,
+        updatedAt: var_updatedAt);
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UpdaterMessageDto sse_decode_updater_message_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_message = sse_decode_String(deserializer);
+        return UpdaterMessageDto_UpdatingOutputs(message: var_message);
+      case 1:
+        var var_message = sse_decode_String(deserializer);
+        return UpdaterMessageDto_UpdatingTransactions(message: var_message);
+      case 2:
+        var var_message = sse_decode_String(deserializer);
+        return UpdaterMessageDto_FullScanWarn(message: var_message);
+      case 3:
+        var var_message = sse_decode_String(deserializer);
+        var var_percent = sse_decode_u_8(deserializer);
+        return UpdaterMessageDto_Scanning(
+            message: var_message, percent: var_percent);
+      case 4:
+        var var_message = sse_decode_String(deserializer);
+        return UpdaterMessageDto_ScanningComplete(message: var_message);
+      case 5:
+        var var_message = sse_decode_String(deserializer);
+        return UpdaterMessageDto_UpdateWarning(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  UpdaterStatusDto sse_decode_updater_status_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_running = sse_decode_bool(deserializer);
+    var var_intervalSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_lastConfirmedHeight = sse_decode_u_64(deserializer);
+    var var_lastMessage =
+        sse_decode_opt_box_autoadd_updater_message_dto(deserializer);
+    return UpdaterStatusDto(
+        running: var_running,
+        intervalSecs: var_intervalSecs,
+        lastConfirmedHeight: var_lastConfirmedHeight,
+        lastMessage: var_lastMessage);
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WalletChainType sse_decode_wallet_chain_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WalletChainType.values[inner];
+  }
+
+  @protected
+  WalletCloseReportDto sse_decode_wallet_close_report_dto(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_handle = sse_decode_u_64(deserializer);
+    var var_foreignListenerStopped = sse_decode_bool(deserializer);
+    var var_ownerListenerStopped = sse_decode_bool(deserializer);
+    var var_torStopped = sse_decode_bool(deserializer);
+    var var_updaterStopped = sse_decode_bool(deserializer);
+    var var_keychainCleared = sse_decode_bool(deserializer);
+    return WalletCloseReportDto(
+        handle: var_handle,
+        foreignListenerStopped: var_foreignListenerStopped,
+        ownerListenerStopped: var_ownerListenerStopped,
+        torStopped: var_torStopped,
+        updaterStopped: var_updaterStopped,
+        keychainCleared: var_keychainCleared);
+  }
+
+  @protected
+  WalletEventDto sse_decode_wallet_event_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_idleSecs = sse_decode_u_64(deserializer);
+        return WalletEventDto_AutoLocked(
+            handle: var_handle, idleSecs: var_idleSecs);
+      case 1:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_tx = sse_decode_box_autoadd_transaction_dto(deserializer);
+        return WalletEventDto_IncomingSlate(handle: var_handle, tx: var_tx);
+      case 2:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_txId = sse_decode_u_32(deserializer);
+        var var_confirmations = sse_decode_u_64(deserializer);
+        return WalletEventDto_ConfirmationsChanged(
+            handle: var_handle,
+            txId: var_txId,
+            confirmations: var_confirmations);
+      case 3:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_balance = sse_decode_box_autoadd_wallet_info_dto(deserializer);
+        return WalletEventDto_BalanceChanged(
+            handle: var_handle, balance: var_balance);
+      case 4:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_txId = sse_decode_u_32(deserializer);
+        return WalletEventDto_TxReverted(handle: var_handle, txId: var_txId);
+      case 5:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_txId = sse_decode_u_32(deserializer);
+        var var_txSlateId = sse_decode_opt_String(deserializer);
+        var var_unlockedAmount = sse_decode_u_64(deserializer);
+        var var_unlockedInputs = sse_decode_usize(deserializer);
+        return WalletEventDto_TxExpired(
+            handle: var_handle,
+            txId: var_txId,
+            txSlateId: var_txSlateId,
+            unlockedAmount: var_unlockedAmount,
+            unlockedInputs: var_unlockedInputs);
+      case 6:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_progress =
+            sse_decode_box_autoadd_scan_progress_dto(deserializer);
+        return WalletEventDto_ScanProgress(
+            handle: var_handle, progress: var_progress);
+      case 7:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_result = sse_decode_box_autoadd_scan_result_dto(deserializer);
+        return WalletEventDto_ScanCompleted(
+            handle: var_handle, result: var_result);
+      case 8:
+        var var_handle = sse_decode_u_64(deserializer);
+        var var_message =
+            sse_decode_box_autoadd_updater_message_dto(deserializer);
+        return WalletEventDto_UpdaterProgress(
+            handle: var_handle, message: var_message);
+      case 9:
+        var var_reachable = sse_decode_bool(deserializer);
+        var var_tipHeight = sse_decode_opt_box_autoadd_u_64(deserializer);
+        return WalletEventDto_NodeConnectivityChanged(
+            reachable: var_reachable, tipHeight: var_tipHeight);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  WalletInfoDto sse_decode_wallet_info_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_refreshedFromNode = sse_decode_bool(deserializer);
+    var var_activeAccount = sse_decode_String(deserializer);
+    var var_lastConfirmedHeight = sse_decode_u_64(deserializer);
+    var var_minimumConfirmations = sse_decode_u_64(deserializer);
+    var var_total = sse_decode_u_64(deserializer);
+    var var_amountAwaitingFinalization = sse_decode_u_64(deserializer);
+    var var_amountAwaitingConfirmation = sse_decode_u_64(deserializer);
+    var var_amountImmature = sse_decode_u_64(deserializer);
+    var var_amountCurrentlySpendable = sse_decode_u_64(deserializer);
+    var var_amountLocked = sse_decode_u_64(deserializer);
+    var var_amountReverted = sse_decode_u_64(deserializer);
+    return WalletInfoDto(
+        refreshedFromNode: var_refreshedFromNode,
+        activeAccount: var_activeAccount,
+        lastConfirmedHeight: var_lastConfirmedHeight,
+        minimumConfirmations: var_minimumConfirmations,
+        total: var_total,
+        amountAwaitingFinalization: var_amountAwaitingFinalization,
+        amountAwaitingConfirmation: var_amountAwaitingConfirmation,
+        amountImmature: var_amountImmature,
+        amountCurrentlySpendable: var_amountCurrentlySpendable,
+        amountLocked: var_amountLocked,
+        amountReverted: var_amountReverted);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_wallet_event_dto_Sse(
+      RustStreamSink<WalletEventDto> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_wallet_event_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_account_dto(AccountDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.label, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.isActive, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_process_slatepack_options(
+      ProcessSlatepackOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_process_slatepack_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scan_progress_dto(
+      ScanProgressDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_progress_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_scan_result_dto(
+      ScanResultDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_scan_result_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_send_options(
+      SendOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_send_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_slate_expectation(
+      SlateExpectation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_slate_expectation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_slatepack_file_target(
+      SlatepackFileTarget self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_slatepack_file_target(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_transaction_dto(
+      TransactionDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transaction_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_tx_metadata_dto(
+      TxMetadataDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tx_metadata_dto(self, serializer);
+  }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_updater_message_dto(
+      UpdaterMessageDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_updater_message_dto(self, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_box_autoadd_wallet_chain_type(
+      WalletChainType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wallet_chain_type(self, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_wallet_info_dto(
+      WalletInfoDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_wallet_info_dto(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_bridge_error(BridgeError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    switch (self) {
+      case BridgeError_NotInitialized(detail: final detail):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_WalletNotOpen(detail: final detail):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_WalletAlreadyOpen(detail: final detail):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_WrongPassword(detail: final detail):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_InsufficientFunds(detail: final detail):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_NodeUnreachable(detail: final detail):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_InvalidSlatepack(detail: final detail):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_InvalidAddress(detail: final detail):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_InvalidInput(detail: final detail):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_AccountError(detail: final detail):
+        sse_encode_i_32(9, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_TransactionError(detail: final detail):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_PaymentProofError(detail: final detail):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_TorFailed(detail: final detail):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_ListenerFailed(detail: final detail):
+        sse_encode_i_32(13, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_Cancelled(detail: final detail):
+        sse_encode_i_32(14, serializer);
+        sse_encode_String(detail, serializer);
+      case BridgeError_Internal(detail: final detail):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(detail, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_bridge_locale(BridgeLocale self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_created_wallet(CreatedWallet self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.handle, serializer);
+    sse_encode_String(self.mnemonic, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_direct_send_result_dto(
+      DirectSendResultDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_String(self.txSlateId, serializer);
+    sse_encode_bool(self.delivered, serializer);
+    sse_encode_opt_String(self.slatepack, serializer);
+    sse_encode_opt_String(self.fallbackReason, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
+  void sse_encode_list_account_dto(
+      List<AccountDto> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_account_dto(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_output_dto(
+      List<OutputDto> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_output_dto(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_payout_item(
+      List<PayoutItem> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payout_item(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_list_payout_result_dto(
+      List<PayoutResultDto> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payout_result_dto(item, serializer);
+    }
   }
 
   @protected
@@ -1195,6 +4308,68 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_transaction_dto(
+      List<TransactionDto> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_transaction_dto(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_slatepack_file_target(
+      SlatepackFileTarget? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_slatepack_file_target(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_tx_metadata_dto(
+      TxMetadataDto? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_tx_metadata_dto(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1205,6 +4380,323 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_updater_message_dto(
+      UpdaterMessageDto? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_updater_message_dto(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_wallet_chain_type(
+      WalletChainType? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_wallet_chain_type(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_output_dto(OutputDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.commitment, serializer);
+    sse_encode_u_64(self.value, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_u_64(self.height, serializer);
+    sse_encode_u_64(self.lockHeight, serializer);
+    sse_encode_bool(self.isCoinbase, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.mmrIndex, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.txLogId, serializer);
+    sse_encode_u_64(self.confirmations, serializer);
+    sse_encode_bool(self.spendable, serializer);
+  }
+
+  @protected
+  void sse_encode_owner_listener_status_dto(
+      OwnerListenerStatusDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.running, serializer);
+    sse_encode_String(self.listenAddr, serializer);
+    sse_encode_opt_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_proof_dto(
+      PaymentProofDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.txId, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_String(self.excess, serializer);
+    sse_encode_String(self.recipientAddress, serializer);
+    sse_encode_String(self.recipientSig, serializer);
+    sse_encode_String(self.senderAddress, serializer);
+    sse_encode_String(self.senderSig, serializer);
+    sse_encode_String(self.proofJson, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_proof_state(
+      PaymentProofState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_proof_verification_dto(
+      PaymentProofVerificationDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.isSender, serializer);
+    sse_encode_bool(self.isRecipient, serializer);
+  }
+
+  @protected
+  void sse_encode_payout_item(PayoutItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_opt_String(self.memo, serializer);
+  }
+
+  @protected
+  void sse_encode_payout_report_dto(
+      PayoutReportDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_payout_result_dto(self.items, serializer);
+    sse_encode_u_32(self.delivered, serializer);
+    sse_encode_u_32(self.manual, serializer);
+    sse_encode_u_32(self.skipped, serializer);
+    sse_encode_u_32(self.failed, serializer);
+    sse_encode_u_64(self.totalCommitted, serializer);
+  }
+
+  @protected
+  void sse_encode_payout_result_dto(
+      PayoutResultDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_opt_String(self.memo, serializer);
+    sse_encode_payout_status(self.status, serializer);
+    sse_encode_opt_String(self.txSlateId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.fee, serializer);
+    sse_encode_opt_String(self.slatepack, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_payout_status(PayoutStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_process_slatepack_options(
+      ProcessSlatepackOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_send_options(self.send, serializer);
+    sse_encode_opt_box_autoadd_bool(self.postTx, serializer);
+    sse_encode_opt_box_autoadd_bool(self.fluff, serializer);
+  }
+
+  @protected
+  void sse_encode_processed_slatepack_dto(
+      ProcessedSlatepackDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_slatepack_action(self.action, serializer);
+    sse_encode_String(self.slateId, serializer);
+    sse_encode_slate_code(self.state, serializer);
+    sse_encode_bool(self.posted, serializer);
+    sse_encode_String(self.response, serializer);
+  }
+
+  @protected
+  void sse_encode_qr_scan_progress_dto(
+      QrScanProgressDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.received, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_opt_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_restore_result_dto(
+      RestoreResultDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.handle, serializer);
+    sse_encode_bool(self.completed, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.resumeHeight, serializer);
+  }
+
+  @protected
+  void sse_encode_scan_progress_dto(
+      ScanProgressDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.startHeight, serializer);
+    sse_encode_u_64(self.currentHeight, serializer);
+    sse_encode_u_64(self.tipHeight, serializer);
+    sse_encode_u_8(self.percent, serializer);
+  }
+
+  @protected
+  void sse_encode_scan_result_dto(
+      ScanResultDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.deleteUnconfirmed, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.startHeight, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.backwardsFromTip, serializer);
+    sse_encode_u_64(self.performedAtEpochSecs, serializer);
+  }
+
+  @protected
+  void sse_encode_send_estimate_dto(
+      SendEstimateDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.fee, serializer);
+    sse_encode_usize(self.numInputs, serializer);
+    sse_encode_u_64(self.totalInputs, serializer);
+    sse_encode_u_64(self.change, serializer);
+    sse_encode_bool(self.sufficient, serializer);
+  }
+
+  @protected
+  void sse_encode_send_options(SendOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.minimumConfirmations, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxOutputs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.numChangeOutputs, serializer);
+    sse_encode_opt_box_autoadd_bool(self.selectionStrategyIsUseAll, serializer);
+    sse_encode_opt_box_autoadd_bool(self.amountIncludesFee, serializer);
+    sse_encode_opt_box_autoadd_bool(self.sendAll, serializer);
+    sse_encode_opt_String(self.srcAcctName, serializer);
+    sse_encode_opt_box_autoadd_bool(self.requestPaymentProof, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.ttlBlocks, serializer);
+    sse_encode_opt_box_autoadd_bool(self.lateLock, serializer);
+    sse_encode_opt_box_autoadd_bool(self.fluff, serializer);
+  }
+
+  @protected
+  void sse_encode_slate_code(SlateCode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_slate_expectation(
+      SlateExpectation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.amount, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFee, serializer);
+  }
+
+  @protected
+  void sse_encode_slate_inspection_dto(
+      SlateInspectionDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_slate_code(self.code, serializer);
+    sse_encode_String(self.slateId, serializer);
+    sse_encode_String(self.state, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.fee, serializer);
+    sse_encode_u_16(self.numParticipants, serializer);
+    sse_encode_opt_String(self.kernelExcess, serializer);
+  }
+
+  @protected
+  void sse_encode_slate_review_dto(
+      SlateReviewDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.slateId, serializer);
+    sse_encode_slate_code(self.state, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.fee, serializer);
+    sse_encode_opt_String(self.senderAddress, serializer);
+    sse_encode_bool(self.encryptedToUs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.ttlCutoffHeight, serializer);
+    sse_encode_bool(self.paymentProofRequested, serializer);
+  }
+
+  @protected
+  void sse_encode_slatepack_action(
+      SlatepackAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_slatepack_file_dto(
+      SlatepackFileDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.binary, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_slatepack_file_target(
+      SlatepackFileTarget self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.binary, serializer);
+    sse_encode_bool(self.overwrite, serializer);
+  }
+
+  @protected
+  void sse_encode_tor_status_dto(TorStatusDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.running, serializer);
+    sse_encode_opt_String(self.onionAddress, serializer);
+    sse_encode_opt_String(self.slatepackAddress, serializer);
+  }
+
+  @protected
+  void sse_encode_transaction_dto(
+      TransactionDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_String(self.parentKeyId, serializer);
+    sse_encode_opt_String(self.txSlateId, serializer);
+    sse_encode_String(self.txType, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_String(self.direction, serializer);
+    sse_encode_String(self.creationTs, serializer);
+    sse_encode_opt_String(self.confirmationTs, serializer);
+    sse_encode_bool(self.confirmed, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.fee, serializer);
+    sse_encode_usize(self.numInputs, serializer);
+    sse_encode_usize(self.numOutputs, serializer);
+    sse_encode_bool(self.hasProof, serializer);
+    sse_encode_payment_proof_state(self.paymentProofState, serializer);
+    sse_encode_opt_String(self.kernelExcess, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.ttlCutoffHeight, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.revertedAfterSecs, serializer);
+    sse_encode_u_64(self.confirmations, serializer);
+    sse_encode_opt_box_autoadd_tx_metadata_dto(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_tx_metadata_dto(
+      TxMetadataDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.note, serializer);
+    sse_encode_opt_String(self.category, serializer);
+    sse_encode_opt_String(self.counterparty, serializer);
+    sse_encode_opt_String(self.updatedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1228,12 +4720,153 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_updater_message_dto(
+      UpdaterMessageDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case UpdaterMessageDto_UpdatingOutputs(message: final message):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(message, serializer);
+      case UpdaterMessageDto_UpdatingTransactions(message: final message):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(message, serializer);
+      case UpdaterMessageDto_FullScanWarn(message: final message):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(message, serializer);
+      case UpdaterMessageDto_Scanning(
+          message: final message, percent: final percent):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_u_8(percent, serializer);
+      case UpdaterMessageDto_ScanningComplete(message: final message):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(message, serializer);
+      case UpdaterMessageDto_UpdateWarning(message: final message):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(message, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_updater_status_dto(
+      UpdaterStatusDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.running, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.intervalSecs, serializer);
+    sse_encode_u_64(self.lastConfirmedHeight, serializer);
+    sse_encode_opt_box_autoadd_updater_message_dto(
+        self.lastMessage, serializer);
+  }
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
 
+  @protected
+  void sse_encode_wallet_chain_type(
+      WalletChainType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_close_report_dto(
+      WalletCloseReportDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.handle, serializer);
+    sse_encode_bool(self.foreignListenerStopped, serializer);
+    sse_encode_bool(self.ownerListenerStopped, serializer);
+    sse_encode_bool(self.torStopped, serializer);
+    sse_encode_bool(self.updaterStopped, serializer);
+    sse_encode_bool(self.keychainCleared, serializer);
+  }
+
+  @protected
+  void sse_encode_wallet_event_dto(
+      WalletEventDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case WalletEventDto_AutoLocked(
+          handle: final handle, idleSecs: final idleSecs):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_64(idleSecs, serializer);
+      case WalletEventDto_IncomingSlate(handle: final handle, tx: final tx):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_box_autoadd_transaction_dto(tx, serializer);
+      case WalletEventDto_ConfirmationsChanged(
+          handle: final handle,
+          txId: final txId,
+          confirmations: final confirmations):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_32(txId, serializer);
+        sse_encode_u_64(confirmations, serializer);
+      case WalletEventDto_BalanceChanged(
+          handle: final handle, balance: final balance):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_box_autoadd_wallet_info_dto(balance, serializer);
+      case WalletEventDto_TxReverted(handle: final handle, txId: final txId):
+        sse_encode_i_32(4, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_32(txId, serializer);
+      case WalletEventDto_TxExpired(
+          handle: final handle,
+          txId: final txId,
+          txSlateId: final txSlateId,
+          unlockedAmount: final unlockedAmount,
+          unlockedInputs: final unlockedInputs):
+        sse_encode_i_32(5, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_u_32(txId, serializer);
+        sse_encode_opt_String(txSlateId, serializer);
+        sse_encode_u_64(unlockedAmount, serializer);
+        sse_encode_usize(unlockedInputs, serializer);
+      case WalletEventDto_ScanProgress(
+          handle: final handle, progress: final progress):
+        sse_encode_i_32(6, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_box_autoadd_scan_progress_dto(progress, serializer);
+      case WalletEventDto_ScanCompleted(
+          handle: final handle, result: final result):
+        sse_encode_i_32(7, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_box_autoadd_scan_result_dto(result, serializer);
+      case WalletEventDto_UpdaterProgress(
+          handle: final handle, message: final message):
+        sse_encode_i_32(8, serializer);
+        sse_encode_u_64(handle, serializer);
+        sse_encode_box_autoadd_updater_message_dto(message, serializer);
+      case WalletEventDto_NodeConnectivityChanged(
+          reachable: final reachable, tipHeight: final tipHeight):
+        sse_encode_i_32(9, serializer);
+        sse_encode_bool(reachable, serializer);
+        sse_encode_opt_box_autoadd_u_64(tipHeight, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_wallet_info_dto(
+      WalletInfoDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.refreshedFromNode, serializer);
+    sse_encode_String(self.activeAccount, serializer);
+    sse_encode_u_64(self.lastConfirmedHeight, serializer);
+    sse_encode_u_64(self.minimumConfirmations, serializer);
+    sse_encode_u_64(self.total, serializer);
+    sse_encode_u_64(self.amountAwaitingFinalization, serializer);
+    sse_encode_u_64(self.amountAwaitingConfirmation, serializer);
+    sse_encode_u_64(self.amountImmature, serializer);
+    sse_encode_u_64(self.amountCurrentlySpendable, serializer);
+    sse_encode_u_64(self.amountLocked, serializer);
+    sse_encode_u_64(self.amountReverted, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7,8 +7,13 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
+import 'error.dart';
+import 'events.dart';
 import 'frb_generated.dart';
+import 'i18n.dart';
+import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
+import 'wallet.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -21,21 +26,206 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<WalletEventDto> dco_decode_StreamSink_wallet_event_dto_Sse(
+      dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AccountDto dco_decode_account_dto(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  ProcessSlatepackOptions dco_decode_box_autoadd_process_slatepack_options(
+      dynamic raw);
+
+  @protected
+  ScanProgressDto dco_decode_box_autoadd_scan_progress_dto(dynamic raw);
+
+  @protected
+  ScanResultDto dco_decode_box_autoadd_scan_result_dto(dynamic raw);
+
+  @protected
+  SendOptions dco_decode_box_autoadd_send_options(dynamic raw);
+
+  @protected
+  SlateExpectation dco_decode_box_autoadd_slate_expectation(dynamic raw);
+
+  @protected
+  SlatepackFileTarget dco_decode_box_autoadd_slatepack_file_target(dynamic raw);
+
+  @protected
+  TransactionDto dco_decode_box_autoadd_transaction_dto(dynamic raw);
+
+  @protected
+  TxMetadataDto dco_decode_box_autoadd_tx_metadata_dto(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UpdaterMessageDto dco_decode_box_autoadd_updater_message_dto(dynamic raw);
+
+  @protected
+  WalletChainType dco_decode_box_autoadd_wallet_chain_type(dynamic raw);
+
+  @protected
+  WalletInfoDto dco_decode_box_autoadd_wallet_info_dto(dynamic raw);
+
+  @protected
+  BridgeError dco_decode_bridge_error(dynamic raw);
+
+  @protected
+  BridgeLocale dco_decode_bridge_locale(dynamic raw);
+
+  @protected
+  CreatedWallet dco_decode_created_wallet(dynamic raw);
+
+  @protected
+  DirectSendResultDto dco_decode_direct_send_result_dto(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AccountDto> dco_decode_list_account_dto(dynamic raw);
+
+  @protected
+  List<OutputDto> dco_decode_list_output_dto(dynamic raw);
+
+  @protected
+  List<PayoutItem> dco_decode_list_payout_item(dynamic raw);
+
+  @protected
+  List<PayoutResultDto> dco_decode_list_payout_result_dto(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<TransactionDto> dco_decode_list_transaction_dto(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  SlatepackFileTarget? dco_decode_opt_box_autoadd_slatepack_file_target(
+      dynamic raw);
+
+  @protected
+  TxMetadataDto? dco_decode_opt_box_autoadd_tx_metadata_dto(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UpdaterMessageDto? dco_decode_opt_box_autoadd_updater_message_dto(
+      dynamic raw);
+
+  @protected
+  WalletChainType? dco_decode_opt_box_autoadd_wallet_chain_type(dynamic raw);
+
+  @protected
+  OutputDto dco_decode_output_dto(dynamic raw);
+
+  @protected
+  OwnerListenerStatusDto dco_decode_owner_listener_status_dto(dynamic raw);
+
+  @protected
+  PaymentProofDto dco_decode_payment_proof_dto(dynamic raw);
+
+  @protected
+  PaymentProofState dco_decode_payment_proof_state(dynamic raw);
+
+  @protected
+  PaymentProofVerificationDto dco_decode_payment_proof_verification_dto(
+      dynamic raw);
+
+  @protected
+  PayoutItem dco_decode_payout_item(dynamic raw);
+
+  @protected
+  PayoutReportDto dco_decode_payout_report_dto(dynamic raw);
+
+  @protected
+  PayoutResultDto dco_decode_payout_result_dto(dynamic raw);
+
+  @protected
+  PayoutStatus dco_decode_payout_status(dynamic raw);
+
+  @protected
+  ProcessSlatepackOptions dco_decode_process_slatepack_options(dynamic raw);
+
+  @protected
+  ProcessedSlatepackDto dco_decode_processed_slatepack_dto(dynamic raw);
+
+  @protected
+  QrScanProgressDto dco_decode_qr_scan_progress_dto(dynamic raw);
+
+  @protected
+  RestoreResultDto dco_decode_restore_result_dto(dynamic raw);
+
+  @protected
+  ScanProgressDto dco_decode_scan_progress_dto(dynamic raw);
+
+  @protected
+  ScanResultDto dco_decode_scan_result_dto(dynamic raw);
+
+  @protected
+  SendEstimateDto dco_decode_send_estimate_dto(dynamic raw);
+
+  @protected
+  SendOptions dco_decode_send_options(dynamic raw);
+
+  @protected
+  SlateCode dco_decode_slate_code(dynamic raw);
+
+  @protected
+  SlateExpectation dco_decode_slate_expectation(dynamic raw);
+
+  @protected
+  SlateInspectionDto dco_decode_slate_inspection_dto(dynamic raw);
+
+  @protected
+  SlateReviewDto dco_decode_slate_review_dto(dynamic raw);
+
+  @protected
+  SlatepackAction dco_decode_slatepack_action(dynamic raw);
+
+  @protected
+  SlatepackFileDto dco_decode_slatepack_file_dto(dynamic raw);
+
+  @protected
+  SlatepackFileTarget dco_decode_slatepack_file_target(dynamic raw);
+
+  @protected
+  TorStatusDto dco_decode_tor_status_dto(dynamic raw);
+
+  @protected
+  TransactionDto dco_decode_transaction_dto(dynamic raw);
+
+  @protected
+  TxMetadataDto dco_decode_tx_metadata_dto(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -48,27 +238,251 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UpdaterMessageDto dco_decode_updater_message_dto(dynamic raw);
+
+  @protected
+  UpdaterStatusDto dco_decode_updater_status_dto(dynamic raw);
+
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  WalletChainType dco_decode_wallet_chain_type(dynamic raw);
+
+  @protected
+  WalletCloseReportDto dco_decode_wallet_close_report_dto(dynamic raw);
+
+  @protected
+  WalletEventDto dco_decode_wallet_event_dto(dynamic raw);
+
+  @protected
+  WalletInfoDto dco_decode_wallet_info_dto(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<WalletEventDto> sse_decode_StreamSink_wallet_event_dto_Sse(
+      SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AccountDto sse_decode_account_dto(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  ProcessSlatepackOptions sse_decode_box_autoadd_process_slatepack_options(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanProgressDto sse_decode_box_autoadd_scan_progress_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  ScanResultDto sse_decode_box_autoadd_scan_result_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  SendOptions sse_decode_box_autoadd_send_options(SseDeserializer deserializer);
+
+  @protected
+  SlateExpectation sse_decode_box_autoadd_slate_expectation(
+      SseDeserializer deserializer);
+
+  @protected
+  SlatepackFileTarget sse_decode_box_autoadd_slatepack_file_target(
+      SseDeserializer deserializer);
+
+  @protected
+  TransactionDto sse_decode_box_autoadd_transaction_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  TxMetadataDto sse_decode_box_autoadd_tx_metadata_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UpdaterMessageDto sse_decode_box_autoadd_updater_message_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  WalletChainType sse_decode_box_autoadd_wallet_chain_type(
+      SseDeserializer deserializer);
+
+  @protected
+  WalletInfoDto sse_decode_box_autoadd_wallet_info_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  BridgeError sse_decode_bridge_error(SseDeserializer deserializer);
+
+  @protected
+  BridgeLocale sse_decode_bridge_locale(SseDeserializer deserializer);
+
+  @protected
+  CreatedWallet sse_decode_created_wallet(SseDeserializer deserializer);
+
+  @protected
+  DirectSendResultDto sse_decode_direct_send_result_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AccountDto> sse_decode_list_account_dto(SseDeserializer deserializer);
+
+  @protected
+  List<OutputDto> sse_decode_list_output_dto(SseDeserializer deserializer);
+
+  @protected
+  List<PayoutItem> sse_decode_list_payout_item(SseDeserializer deserializer);
+
+  @protected
+  List<PayoutResultDto> sse_decode_list_payout_result_dto(
+      SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<TransactionDto> sse_decode_list_transaction_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  SlatepackFileTarget? sse_decode_opt_box_autoadd_slatepack_file_target(
+      SseDeserializer deserializer);
+
+  @protected
+  TxMetadataDto? sse_decode_opt_box_autoadd_tx_metadata_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UpdaterMessageDto? sse_decode_opt_box_autoadd_updater_message_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  WalletChainType? sse_decode_opt_box_autoadd_wallet_chain_type(
+      SseDeserializer deserializer);
+
+  @protected
+  OutputDto sse_decode_output_dto(SseDeserializer deserializer);
+
+  @protected
+  OwnerListenerStatusDto sse_decode_owner_listener_status_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  PaymentProofDto sse_decode_payment_proof_dto(SseDeserializer deserializer);
+
+  @protected
+  PaymentProofState sse_decode_payment_proof_state(
+      SseDeserializer deserializer);
+
+  @protected
+  PaymentProofVerificationDto sse_decode_payment_proof_verification_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  PayoutItem sse_decode_payout_item(SseDeserializer deserializer);
+
+  @protected
+  PayoutReportDto sse_decode_payout_report_dto(SseDeserializer deserializer);
+
+  @protected
+  PayoutResultDto sse_decode_payout_result_dto(SseDeserializer deserializer);
+
+  @protected
+  PayoutStatus sse_decode_payout_status(SseDeserializer deserializer);
+
+  @protected
+  ProcessSlatepackOptions sse_decode_process_slatepack_options(
+      SseDeserializer deserializer);
+
+  @protected
+  ProcessedSlatepackDto sse_decode_processed_slatepack_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  QrScanProgressDto sse_decode_qr_scan_progress_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  RestoreResultDto sse_decode_restore_result_dto(SseDeserializer deserializer);
+
+  @protected
+  ScanProgressDto sse_decode_scan_progress_dto(SseDeserializer deserializer);
+
+  @protected
+  ScanResultDto sse_decode_scan_result_dto(SseDeserializer deserializer);
+
+  @protected
+  SendEstimateDto sse_decode_send_estimate_dto(SseDeserializer deserializer);
+
+  @protected
+  SendOptions sse_decode_send_options(SseDeserializer deserializer);
+
+  @protected
+  SlateCode sse_decode_slate_code(SseDeserializer deserializer);
+
+  @protected
+  SlateExpectation sse_decode_slate_expectation(SseDeserializer deserializer);
+
+  @protected
+  SlateInspectionDto sse_decode_slate_inspection_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  SlateReviewDto sse_decode_slate_review_dto(SseDeserializer deserializer);
+
+  @protected
+  SlatepackAction sse_decode_slatepack_action(SseDeserializer deserializer);
+
+  @protected
+  SlatepackFileDto sse_decode_slatepack_file_dto(SseDeserializer deserializer);
+
+  @protected
+  SlatepackFileTarget sse_decode_slatepack_file_target(
+      SseDeserializer deserializer);
+
+  @protected
+  TorStatusDto sse_decode_tor_status_dto(SseDeserializer deserializer);
+
+  @protected
+  TransactionDto sse_decode_transaction_dto(SseDeserializer deserializer);
+
+  @protected
+  TxMetadataDto sse_decode_tx_metadata_dto(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -81,9 +495,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UpdaterMessageDto sse_decode_updater_message_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  UpdaterStatusDto sse_decode_updater_status_dto(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  WalletChainType sse_decode_wallet_chain_type(SseDeserializer deserializer);
+
+  @protected
+  WalletCloseReportDto sse_decode_wallet_close_report_dto(
+      SseDeserializer deserializer);
+
+  @protected
+  WalletEventDto sse_decode_wallet_event_dto(SseDeserializer deserializer);
+
+  @protected
+  WalletInfoDto sse_decode_wallet_info_dto(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
@@ -91,22 +525,243 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StreamSink_wallet_event_dto_Sse(
+      RustStreamSink<WalletEventDto> self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_account_dto(AccountDto self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_process_slatepack_options(
+      ProcessSlatepackOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_scan_progress_dto(
+      ScanProgressDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_scan_result_dto(
+      ScanResultDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_send_options(
+      SendOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_slate_expectation(
+      SlateExpectation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_slatepack_file_target(
+      SlatepackFileTarget self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_transaction_dto(
+      TransactionDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_tx_metadata_dto(
+      TxMetadataDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_updater_message_dto(
+      UpdaterMessageDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_wallet_chain_type(
+      WalletChainType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_wallet_info_dto(
+      WalletInfoDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bridge_error(BridgeError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bridge_locale(BridgeLocale self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_created_wallet(CreatedWallet self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_direct_send_result_dto(
+      DirectSendResultDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_account_dto(
+      List<AccountDto> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_output_dto(
+      List<OutputDto> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_payout_item(
+      List<PayoutItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_payout_result_dto(
+      List<PayoutResultDto> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_transaction_dto(
+      List<TransactionDto> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_slatepack_file_target(
+      SlatepackFileTarget? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_tx_metadata_dto(
+      TxMetadataDto? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_updater_message_dto(
+      UpdaterMessageDto? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_wallet_chain_type(
+      WalletChainType? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_output_dto(OutputDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_owner_listener_status_dto(
+      OwnerListenerStatusDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payment_proof_dto(
+      PaymentProofDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payment_proof_state(
+      PaymentProofState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payment_proof_verification_dto(
+      PaymentProofVerificationDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payout_item(PayoutItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payout_report_dto(
+      PayoutReportDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payout_result_dto(
+      PayoutResultDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payout_status(PayoutStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_process_slatepack_options(
+      ProcessSlatepackOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_processed_slatepack_dto(
+      ProcessedSlatepackDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_qr_scan_progress_dto(
+      QrScanProgressDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_restore_result_dto(
+      RestoreResultDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_progress_dto(
+      ScanProgressDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_result_dto(ScanResultDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_send_estimate_dto(
+      SendEstimateDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_send_options(SendOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slate_code(SlateCode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slate_expectation(
+      SlateExpectation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slate_inspection_dto(
+      SlateInspectionDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slate_review_dto(
+      SlateReviewDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slatepack_action(
+      SlatepackAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slatepack_file_dto(
+      SlatepackFileDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_slatepack_file_target(
+      SlatepackFileTarget self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tor_status_dto(TorStatusDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_transaction_dto(
+      TransactionDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tx_metadata_dto(TxMetadataDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -119,9 +774,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_updater_message_dto(
+      UpdaterMessageDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_updater_status_dto(
+      UpdaterStatusDto self, SseSerializer serializer);
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_wallet_chain_type(
+      WalletChainType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wallet_close_report_dto(
+      WalletCloseReportDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wallet_event_dto(
+      WalletEventDto self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_wallet_info_dto(WalletInfoDto self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 }
@@ -0,0 +1,1161 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+part 'models.freezed.dart';
+
+class AccountDto {
+  final String label;
+  final String path;
+  final bool isActive;
+
+  const AccountDto({
+    required this.label,
+    required this.path,
+    required this.isActive,
+  });
+
+  @override
+  int get hashCode => label.hashCode ^ path.hashCode ^ isActive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountDto &&
+          runtimeType == other.runtimeType &&
+          label == other.label &&
+          path == other.path &&
+          isActive == other.isActive;
+}
+
+/// Ergebnis von `create_wallet`: Handle der neuen Wallet plus Seedphrase zur Sicherung.
+class CreatedWallet {
+  final BigInt handle;
+  final String mnemonic;
+
+  const CreatedWallet({
+    required this.handle,
+    required this.mnemonic,
+  });
+
+  @override
+  int get hashCode => handle.hashCode ^ mnemonic.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreatedWallet &&
+          runtimeType == other.runtimeType &&
+          handle == other.handle &&
+          mnemonic == other.mnemonic;
+}
+
+/// Ergebnis von `send_via_tor` und `send_via_http`. Ohne `delivered` muss Dart das
+/// `slatepack` wie bei einem normalen Send selbst weitergeben.
+class DirectSendResultDto {
+  final String txSlateId;
+  final bool delivered;
+  final String? slatepack;
+  final String? fallbackReason;
+
+  const DirectSendResultDto({
+    required this.txSlateId,
+    required this.delivered,
+    this.slatepack,
+    this.fallbackReason,
+  });
+
+  @override
+  int get hashCode =>
+      txSlateId.hashCode ^
+      delivered.hashCode ^
+      slatepack.hashCode ^
+      fallbackReason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DirectSendResultDto &&
+          runtimeType == other.runtimeType &&
+          txSlateId == other.txSlateId &&
+          delivered == other.delivered &&
+          slatepack == other.slatepack &&
+          fallbackReason == other.fallbackReason;
+}
+
+class OutputDto {
+  final String commitment;
+  final BigInt value;
+  final String status;
+  final BigInt height;
+  final BigInt lockHeight;
+  final bool isCoinbase;
+  final BigInt? mmrIndex;
+  final int? txLogId;
+  final BigInt confirmations;
+  final bool spendable;
+
+  const OutputDto({
+    required this.commitment,
+    required this.value,
+    required this.status,
+    required this.height,
+    required this.lockHeight,
+    required this.isCoinbase,
+    this.mmrIndex,
+    this.txLogId,
+    required this.confirmations,
+    required this.spendable,
+  });
+
+  @override
+  int get hashCode =>
+      commitment.hashCode ^
+      value.hashCode ^
+      status.hashCode ^
+      height.hashCode ^
+      lockHeight.hashCode ^
+      isCoinbase.hashCode ^
+      mmrIndex.hashCode ^
+      txLogId.hashCode ^
+      confirmations.hashCode ^
+      spendable.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OutputDto &&
+          runtimeType == other.runtimeType &&
+          commitment == other.commitment &&
+          value == other.value &&
+          status == other.status &&
+          height == other.height &&
+          lockHeight == other.lockHeight &&
+          isCoinbase == other.isCoinbase &&
+          mmrIndex == other.mmrIndex &&
+          txLogId == other.txLogId &&
+          confirmations == other.confirmations &&
+          spendable == other.spendable;
+}
+
+class OwnerListenerStatusDto {
+  final bool running;
+  final String listenAddr;
+  final String? message;
+
+  const OwnerListenerStatusDto({
+    required this.running,
+    required this.listenAddr,
+    this.message,
+  });
+
+  @override
+  int get hashCode => running.hashCode ^ listenAddr.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OwnerListenerStatusDto &&
+          runtimeType == other.runtimeType &&
+          running == other.running &&
+          listenAddr == other.listenAddr &&
+          message == other.message;
+}
+
+/// Payment Proof in lesbaren Feldern; `proof_json` ist das Original fuer
+/// `wallet_verify_payment_proof`.
+class PaymentProofDto {
+  final int txId;
+  final BigInt amount;
+  final String excess;
+  final String recipientAddress;
+  final String recipientSig;
+  final String senderAddress;
+  final String senderSig;
+  final String proofJson;
+
+  const PaymentProofDto({
+    required this.txId,
+    required this.amount,
+    required this.excess,
+    required this.recipientAddress,
+    required this.recipientSig,
+    required this.senderAddress,
+    required this.senderSig,
+    required this.proofJson,
+  });
+
+  @override
+  int get hashCode =>
+      txId.hashCode ^
+      amount.hashCode ^
+      excess.hashCode ^
+      recipientAddress.hashCode ^
+      recipientSig.hashCode ^
+      senderAddress.hashCode ^
+      senderSig.hashCode ^
+      proofJson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentProofDto &&
+          runtimeType == other.runtimeType &&
+          txId == other.txId &&
+          amount == other.amount &&
+          excess == other.excess &&
+          recipientAddress == other.recipientAddress &&
+          recipientSig == other.recipientSig &&
+          senderAddress == other.senderAddress &&
+          senderSig == other.senderSig &&
+          proofJson == other.proofJson;
+}
+
+/// Stand des Zahlungsnachweises einer Tx.
+enum PaymentProofState {
+  none,
+  /// Angefordert, der Empfaenger hat aber noch nicht signiert.
+  requested,
+  received,
+  ;
+}
+
+class PaymentProofVerificationDto {
+  final bool isSender;
+  final bool isRecipient;
+
+  const PaymentProofVerificationDto({
+    required this.isSender,
+    required this.isRecipient,
+  });
+
+  @override
+  int get hashCode => isSender.hashCode ^ isRecipient.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentProofVerificationDto &&
+          runtimeType == other.runtimeType &&
+          isSender == other.isSender &&
+          isRecipient == other.isRecipient;
+}
+
+/// Eine Zahlung eines Batch-Payouts. Leere `address` erzeugt ein unverschluesseltes Slatepack.
+class PayoutItem {
+  final String address;
+  final BigInt amount;
+  final String? memo;
+
+  const PayoutItem({
+    required this.address,
+    required this.amount,
+    this.memo,
+  });
+
+  @override
+  int get hashCode => address.hashCode ^ amount.hashCode ^ memo.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PayoutItem &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          amount == other.amount &&
+          memo == other.memo;
+}
+
+class PayoutReportDto {
+  final List<PayoutResultDto> items;
+  final int delivered;
+  final int manual;
+  final int skipped;
+  final int failed;
+  /// Betrag plus Gebuehr aller gesendeten oder als Slatepack vorbereiteten Zahlungen.
+  final BigInt totalCommitted;
+
+  const PayoutReportDto({
+    required this.items,
+    required this.delivered,
+    required this.manual,
+    required this.skipped,
+    required this.failed,
+    required this.totalCommitted,
+  });
+
+  @override
+  int get hashCode =>
+      items.hashCode ^
+      delivered.hashCode ^
+      manual.hashCode ^
+      skipped.hashCode ^
+      failed.hashCode ^
+      totalCommitted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PayoutReportDto &&
+          runtimeType == other.runtimeType &&
+          items == other.items &&
+          delivered == other.delivered &&
+          manual == other.manual &&
+          skipped == other.skipped &&
+          failed == other.failed &&
+          totalCommitted == other.totalCommitted;
+}
+
+class PayoutResultDto {
+  final String address;
+  final BigInt amount;
+  final String? memo;
+  final PayoutStatus status;
+  final String? txSlateId;
+  final BigInt? fee;
+  final String? slatepack;
+  final String? error;
+
+  const PayoutResultDto({
+    required this.address,
+    required this.amount,
+    this.memo,
+    required this.status,
+    this.txSlateId,
+    this.fee,
+    this.slatepack,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      address.hashCode ^
+      amount.hashCode ^
+      memo.hashCode ^
+      status.hashCode ^
+      txSlateId.hashCode ^
+      fee.hashCode ^
+      slatepack.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PayoutResultDto &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          amount == other.amount &&
+          memo == other.memo &&
+          status == other.status &&
+          txSlateId == other.txSlateId &&
+          fee == other.fee &&
+          slatepack == other.slatepack &&
+          error == other.error;
+}
+
+enum PayoutStatus {
+  /// Direkt abgeschlossen.
+  delivered,
+  /// Slatepack muss weitergegeben werden.
+  manual,
+  /// Guthaben reicht samt Fee nicht mehr.
+  skipped,
+  failed,
+  ;
+}
+
+/// Optionen fuer `process_slatepack`. `send` gilt nur, wenn eine Rechnung bezahlt wird.
+class ProcessSlatepackOptions {
+  final SendOptions send;
+  /// Finalisierte Tx gleich posten; Standard ja.
+  final bool? postTx;
+  final bool? fluff;
+
+  const ProcessSlatepackOptions({
+    required this.send,
+    this.postTx,
+    this.fluff,
+  });
+
+  @override
+  int get hashCode => send.hashCode ^ postTx.hashCode ^ fluff.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessSlatepackOptions &&
+          runtimeType == other.runtimeType &&
+          send == other.send &&
+          postTx == other.postTx &&
+          fluff == other.fluff;
+}
+
+/// Ergebnis von `process_slatepack`; `response` geht nach `Received` und `PaidInvoice` an die
+/// Gegenseite zurueck.
+class ProcessedSlatepackDto {
+  final SlatepackAction action;
+  final String slateId;
+  /// Status des eingegangenen Slates (`S1`, `I1`, `S2`, `I2`).
+  final SlateCode state;
+  final bool posted;
+  final String response;
+
+  const ProcessedSlatepackDto({
+    required this.action,
+    required this.slateId,
+    required this.state,
+    required this.posted,
+    required this.response,
+  });
+
+  @override
+  int get hashCode =>
+      action.hashCode ^
+      slateId.hashCode ^
+      state.hashCode ^
+      posted.hashCode ^
+      response.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessedSlatepackDto &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          slateId == other.slateId &&
+          state == other.state &&
+          posted == other.posted &&
+          response == other.response;
+}
+
+/// Stand einer QR-Scan-Session; `message` ist gesetzt, sobald alle Frames da sind.
+class QrScanProgressDto {
+  final int received;
+  final int total;
+  final String? message;
+
+  const QrScanProgressDto({
+    required this.received,
+    required this.total,
+    this.message,
+  });
+
+  @override
+  int get hashCode => received.hashCode ^ total.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QrScanProgressDto &&
+          runtimeType == other.runtimeType &&
+          received == other.received &&
+          total == other.total &&
+          message == other.message;
+}
+
+/// Ergebnis von `restore_wallet_from_seed`. `completed == false` heisst: Scan wurde abgebrochen
+/// und laesst sich ab `resume_height` fortsetzen.
+class RestoreResultDto {
+  final BigInt handle;
+  final bool completed;
+  final BigInt? resumeHeight;
+
+  const RestoreResultDto({
+    required this.handle,
+    required this.completed,
+    this.resumeHeight,
+  });
+
+  @override
+  int get hashCode =>
+      handle.hashCode ^ completed.hashCode ^ resumeHeight.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestoreResultDto &&
+          runtimeType == other.runtimeType &&
+          handle == other.handle &&
+          completed == other.completed &&
+          resumeHeight == other.resumeHeight;
+}
+
+/// Fortschritt eines Scans nach einem abgeschlossenen Hoehenfenster.
+class ScanProgressDto {
+  final BigInt startHeight;
+  final BigInt currentHeight;
+  final BigInt tipHeight;
+  final int percent;
+
+  const ScanProgressDto({
+    required this.startHeight,
+    required this.currentHeight,
+    required this.tipHeight,
+    required this.percent,
+  });
+
+  @override
+  int get hashCode =>
+      startHeight.hashCode ^
+      currentHeight.hashCode ^
+      tipHeight.hashCode ^
+      percent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanProgressDto &&
+          runtimeType == other.runtimeType &&
+          startHeight == other.startHeight &&
+          currentHeight == other.currentHeight &&
+          tipHeight == other.tipHeight &&
+          percent == other.percent;
+}
+
+class ScanResultDto {
+  final bool deleteUnconfirmed;
+  final BigInt? startHeight;
+  final BigInt? backwardsFromTip;
+  final BigInt performedAtEpochSecs;
+
+  const ScanResultDto({
+    required this.deleteUnconfirmed,
+    this.startHeight,
+    this.backwardsFromTip,
+    required this.performedAtEpochSecs,
+  });
+
+  @override
+  int get hashCode =>
+      deleteUnconfirmed.hashCode ^
+      startHeight.hashCode ^
+      backwardsFromTip.hashCode ^
+      performedAtEpochSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanResultDto &&
+          runtimeType == other.runtimeType &&
+          deleteUnconfirmed == other.deleteUnconfirmed &&
+          startHeight == other.startHeight &&
+          backwardsFromTip == other.backwardsFromTip &&
+          performedAtEpochSecs == other.performedAtEpochSecs;
+}
+
+/// Ergebnis von `estimate_send`. `amount` ist, was beim Empfaenger ankommt. Bei
+/// `sufficient == false` ist `fee` die Gebuehr, die fuer den Betrag noetig waere, und
+/// `total_inputs` das verfuegbare Guthaben. `num_inputs` ist nachgerechnet, weil libwallet nur
+/// die Summe meldet; es bleibt eine Schaetzung, bis die Tx wirklich erzeugt wird.
+class SendEstimateDto {
+  final BigInt amount;
+  final BigInt fee;
+  final BigInt numInputs;
+  final BigInt totalInputs;
+  final BigInt change;
+  final bool sufficient;
+
+  const SendEstimateDto({
+    required this.amount,
+    required this.fee,
+    required this.numInputs,
+    required this.totalInputs,
+    required this.change,
+    required this.sufficient,
+  });
+
+  @override
+  int get hashCode =>
+      amount.hashCode ^
+      fee.hashCode ^
+      numInputs.hashCode ^
+      totalInputs.hashCode ^
+      change.hashCode ^
+      sufficient.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SendEstimateDto &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          fee == other.fee &&
+          numInputs == other.numInputs &&
+          totalInputs == other.totalInputs &&
+          change == other.change &&
+          sufficient == other.sufficient;
+}
+
+/// Optionen fuer Senden und Gebuehrenschaetzung. `None` nimmt den bisherigen Standardwert.
+class SendOptions {
+  final BigInt? minimumConfirmations;
+  final int? maxOutputs;
+  final int? numChangeOutputs;
+  final bool? selectionStrategyIsUseAll;
+  /// Gebuehr geht vom Betrag ab statt zusaetzlich aus dem Guthaben.
+  final bool? amountIncludesFee;
+  /// Sendet das gesamte verfuegbare Guthaben abzueglich Gebuehr; der uebergebene Betrag
+  /// wird ignoriert.
+  final bool? sendAll;
+  /// Account, aus dem bezahlt wird; `None` nimmt den aktiven Account, ohne ihn zu aendern.
+  final String? srcAcctName;
+  /// Payment Proof vom Empfaenger verlangen. `None` verlangt einen, sobald eine
+  /// Slatepack-Adresse angegeben ist.
+  final bool? requestPaymentProof;
+  /// Gueltigkeit in Bloecken; `None` nimmt den Standard der Wallet, 0 setzt keinen TTL.
+  final BigInt? ttlBlocks;
+  /// Inputs erst beim Finalisieren auswaehlen und sperren statt schon beim Erzeugen von S1.
+  final bool? lateLock;
+  /// Direkt gesendete Txs per Fluff statt Dandelion posten; Standard nein.
+  final bool? fluff;
+
+  const SendOptions({
+    this.minimumConfirmations,
+    this.maxOutputs,
+    this.numChangeOutputs,
+    this.selectionStrategyIsUseAll,
+    this.amountIncludesFee,
+    this.sendAll,
+    this.srcAcctName,
+    this.requestPaymentProof,
+    this.ttlBlocks,
+    this.lateLock,
+    this.fluff,
+  });
+
+  @override
+  int get hashCode =>
+      minimumConfirmations.hashCode ^
+      maxOutputs.hashCode ^
+      numChangeOutputs.hashCode ^
+      selectionStrategyIsUseAll.hashCode ^
+      amountIncludesFee.hashCode ^
+      sendAll.hashCode ^
+      srcAcctName.hashCode ^
+      requestPaymentProof.hashCode ^
+      ttlBlocks.hashCode ^
+      lateLock.hashCode ^
+      fluff.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SendOptions &&
+          runtimeType == other.runtimeType &&
+          minimumConfirmations == other.minimumConfirmations &&
+          maxOutputs == other.maxOutputs &&
+          numChangeOutputs == other.numChangeOutputs &&
+          selectionStrategyIsUseAll == other.selectionStrategyIsUseAll &&
+          amountIncludesFee == other.amountIncludesFee &&
+          sendAll == other.sendAll &&
+          srcAcctName == other.srcAcctName &&
+          requestPaymentProof == other.requestPaymentProof &&
+          ttlBlocks == other.ttlBlocks &&
+          lateLock == other.lateLock &&
+          fluff == other.fluff;
+}
+
+/// Slate-Status in der Kurzform von grin-wallet (`S1` .. `I3`).
+enum SlateCode {
+  s1,
+  s2,
+  s3,
+  i1,
+  i2,
+  i3,
+  unknown,
+  ;
+}
+
+/// Erwartung an ein eingehendes Slate; `review_slatepack` lehnt Abweichungen ab.
+class SlateExpectation {
+  final BigInt? amount;
+  final BigInt? maxFee;
+
+  const SlateExpectation({
+    this.amount,
+    this.maxFee,
+  });
+
+  @override
+  int get hashCode => amount.hashCode ^ maxFee.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SlateExpectation &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          maxFee == other.maxFee;
+}
+
+class SlateInspectionDto {
+  final SlateCode code;
+  final String slateId;
+  final String state;
+  final BigInt amount;
+  final BigInt fee;
+  final int numParticipants;
+  final String? kernelExcess;
+
+  const SlateInspectionDto({
+    required this.code,
+    required this.slateId,
+    required this.state,
+    required this.amount,
+    required this.fee,
+    required this.numParticipants,
+    this.kernelExcess,
+  });
+
+  @override
+  int get hashCode =>
+      code.hashCode ^
+      slateId.hashCode ^
+      state.hashCode ^
+      amount.hashCode ^
+      fee.hashCode ^
+      numParticipants.hashCode ^
+      kernelExcess.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SlateInspectionDto &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          slateId == other.slateId &&
+          state == other.state &&
+          amount == other.amount &&
+          fee == other.fee &&
+          numParticipants == other.numParticipants &&
+          kernelExcess == other.kernelExcess;
+}
+
+/// Zusammenfassung vor dem Signieren. Bei I1 ist `fee` die Gebuehr, die wir beim Bezahlen
+/// tragen wuerden (geschaetzt mit den Optionen des Aufrufs), bei S1 die des Senders.
+class SlateReviewDto {
+  final String slateId;
+  /// `S1` (Empfang) oder `I1` (Rechnung).
+  final SlateCode state;
+  final BigInt amount;
+  final BigInt fee;
+  final String? senderAddress;
+  final bool encryptedToUs;
+  final BigInt? ttlCutoffHeight;
+  final bool paymentProofRequested;
+
+  const SlateReviewDto({
+    required this.slateId,
+    required this.state,
+    required this.amount,
+    required this.fee,
+    this.senderAddress,
+    required this.encryptedToUs,
+    this.ttlCutoffHeight,
+    required this.paymentProofRequested,
+  });
+
+  @override
+  int get hashCode =>
+      slateId.hashCode ^
+      state.hashCode ^
+      amount.hashCode ^
+      fee.hashCode ^
+      senderAddress.hashCode ^
+      encryptedToUs.hashCode ^
+      ttlCutoffHeight.hashCode ^
+      paymentProofRequested.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SlateReviewDto &&
+          runtimeType == other.runtimeType &&
+          slateId == other.slateId &&
+          state == other.state &&
+          amount == other.amount &&
+          fee == other.fee &&
+          senderAddress == other.senderAddress &&
+          encryptedToUs == other.encryptedToUs &&
+          ttlCutoffHeight == other.ttlCutoffHeight &&
+          paymentProofRequested == other.paymentProofRequested;
+}
+
+/// Was `process_slatepack` mit einem Slatepack gemacht hat.
+enum SlatepackAction {
+  received,
+  paidInvoice,
+  finalized,
+  ;
+}
+
+/// Ergebnis von `write_slatepack_file`; `path` inklusive ergaenzter Endung.
+class SlatepackFileDto {
+  final String path;
+  final bool binary;
+  final BigInt sizeBytes;
+
+  const SlatepackFileDto({
+    required this.path,
+    required this.binary,
+    required this.sizeBytes,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ binary.hashCode ^ sizeBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SlatepackFileDto &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          binary == other.binary &&
+          sizeBytes == other.sizeBytes;
+}
+
+/// Datei, in die Senden, Empfangen, Rechnung bezahlen und Finalisieren ihr Slatepack
+/// zusaetzlich schreiben; Pfad und Flags wie bei `write_slatepack_file`.
+class SlatepackFileTarget {
+  final String path;
+  final bool binary;
+  final bool overwrite;
+
+  const SlatepackFileTarget({
+    required this.path,
+    required this.binary,
+    required this.overwrite,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ binary.hashCode ^ overwrite.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SlatepackFileTarget &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          binary == other.binary &&
+          overwrite == other.overwrite;
+}
+
+class TorStatusDto {
+  final bool running;
+  final String? onionAddress;
+  final String? slatepackAddress;
+
+  const TorStatusDto({
+    required this.running,
+    this.onionAddress,
+    this.slatepackAddress,
+  });
+
+  @override
+  int get hashCode =>
+      running.hashCode ^ onionAddress.hashCode ^ slatepackAddress.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TorStatusDto &&
+          runtimeType == other.runtimeType &&
+          running == other.running &&
+          onionAddress == other.onionAddress &&
+          slatepackAddress == other.slatepackAddress;
+}
+
+class TransactionDto {
+  final int id;
+  /// Parent-Key (hex) des Accounts; `id` gilt nur innerhalb dieses Accounts.
+  final String parentKeyId;
+  final String? txSlateId;
+  final String txType;
+  final String status;
+  final String direction;
+  final String creationTs;
+  final String? confirmationTs;
+  final bool confirmed;
+  final BigInt amount;
+  final BigInt? fee;
+  final BigInt numInputs;
+  final BigInt numOutputs;
+  final bool hasProof;
+  final PaymentProofState paymentProofState;
+  final String? kernelExcess;
+  final BigInt? ttlCutoffHeight;
+  final BigInt? revertedAfterSecs;
+  final BigInt confirmations;
+  /// Notizen aus dem Metadaten-Store der Bridge, falls vorhanden.
+  final TxMetadataDto? metadata;
+
+  const TransactionDto({
+    required this.id,
+    required this.parentKeyId,
+    this.txSlateId,
+    required this.txType,
+    required this.status,
+    required this.direction,
+    required this.creationTs,
+    this.confirmationTs,
+    required this.confirmed,
+    required this.amount,
+    this.fee,
+    required this.numInputs,
+    required this.numOutputs,
+    required this.hasProof,
+    required this.paymentProofState,
+    this.kernelExcess,
+    this.ttlCutoffHeight,
+    this.revertedAfterSecs,
+    required this.confirmations,
+    this.metadata,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      parentKeyId.hashCode ^
+      txSlateId.hashCode ^
+      txType.hashCode ^
+      status.hashCode ^
+      direction.hashCode ^
+      creationTs.hashCode ^
+      confirmationTs.hashCode ^
+      confirmed.hashCode ^
+      amount.hashCode ^
+      fee.hashCode ^
+      numInputs.hashCode ^
+      numOutputs.hashCode ^
+      hasProof.hashCode ^
+      paymentProofState.hashCode ^
+      kernelExcess.hashCode ^
+      ttlCutoffHeight.hashCode ^
+      revertedAfterSecs.hashCode ^
+      confirmations.hashCode ^
+      metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TransactionDto &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          parentKeyId == other.parentKeyId &&
+          txSlateId == other.txSlateId &&
+          txType == other.txType &&
+          status == other.status &&
+          direction == other.direction &&
+          creationTs == other.creationTs &&
+          confirmationTs == other.confirmationTs &&
+          confirmed == other.confirmed &&
+          amount == other.amount &&
+          fee == other.fee &&
+          numInputs == other.numInputs &&
+          numOutputs == other.numOutputs &&
+          hasProof == other.hasProof &&
+          paymentProofState == other.paymentProofState &&
+          kernelExcess == other.kernelExcess &&
+          ttlCutoffHeight == other.ttlCutoffHeight &&
+          revertedAfterSecs == other.revertedAfterSecs &&
+          confirmations == other.confirmations &&
+          metadata == other.metadata;
+}
+
+/// Vom Nutzer gepflegte Angaben zu einer Tx. `updated_at` setzt die Bridge beim Speichern.
+class TxMetadataDto {
+  final String? note;
+  final String? category;
+  final String? counterparty;
+  final String? updatedAt;
+
+  const TxMetadataDto({
+    this.note,
+    this.category,
+    this.counterparty,
+    this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      note.hashCode ^
+      category.hashCode ^
+      counterparty.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TxMetadataDto &&
+          runtimeType == other.runtimeType &&
+          note == other.note &&
+          category == other.category &&
+          counterparty == other.counterparty &&
+          updatedAt == other.updatedAt;
+}
+
+/// Statusmeldung des grin-wallet Updaters bzw. eines Scans.
+@freezed
+sealed class UpdaterMessageDto with _$UpdaterMessageDto {
+  const UpdaterMessageDto._();
+
+  const factory UpdaterMessageDto.updatingOutputs({
+    required String message,
+  }) = UpdaterMessageDto_UpdatingOutputs;
+
+  const factory UpdaterMessageDto.updatingTransactions({
+    required String message,
+  }) = UpdaterMessageDto_UpdatingTransactions;
+
+  const factory UpdaterMessageDto.fullScanWarn({
+    required String message,
+  }) = UpdaterMessageDto_FullScanWarn;
+
+  const factory UpdaterMessageDto.scanning({
+    required String message,
+    required int percent,
+  }) = UpdaterMessageDto_Scanning;
+
+  const factory UpdaterMessageDto.scanningComplete({
+    required String message,
+  }) = UpdaterMessageDto_ScanningComplete;
+
+  const factory UpdaterMessageDto.updateWarning({
+    required String message,
+  }) = UpdaterMessageDto_UpdateWarning;
+}
+
+class UpdaterStatusDto {
+  final bool running;
+  final BigInt? intervalSecs;
+  final BigInt lastConfirmedHeight;
+  final UpdaterMessageDto? lastMessage;
+
+  const UpdaterStatusDto({
+    required this.running,
+    this.intervalSecs,
+    required this.lastConfirmedHeight,
+    this.lastMessage,
+  });
+
+  @override
+  int get hashCode =>
+      running.hashCode ^
+      intervalSecs.hashCode ^
+      lastConfirmedHeight.hashCode ^
+      lastMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UpdaterStatusDto &&
+          runtimeType == other.runtimeType &&
+          running == other.running &&
+          intervalSecs == other.intervalSecs &&
+          lastConfirmedHeight == other.lastConfirmedHeight &&
+          lastMessage == other.lastMessage;
+}
+
+class WalletCloseReportDto {
+  final BigInt handle;
+  final bool foreignListenerStopped;
+  final bool ownerListenerStopped;
+  final bool torStopped;
+  final bool updaterStopped;
+  final bool keychainCleared;
+
+  const WalletCloseReportDto({
+    required this.handle,
+    required this.foreignListenerStopped,
+    required this.ownerListenerStopped,
+    required this.torStopped,
+    required this.updaterStopped,
+    required this.keychainCleared,
+  });
+
+  @override
+  int get hashCode =>
+      handle.hashCode ^
+      foreignListenerStopped.hashCode ^
+      ownerListenerStopped.hashCode ^
+      torStopped.hashCode ^
+      updaterStopped.hashCode ^
+      keychainCleared.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletCloseReportDto &&
+          runtimeType == other.runtimeType &&
+          handle == other.handle &&
+          foreignListenerStopped == other.foreignListenerStopped &&
+          ownerListenerStopped == other.ownerListenerStopped &&
+          torStopped == other.torStopped &&
+          updaterStopped == other.updaterStopped &&
+          keychainCleared == other.keychainCleared;
+}
+
+class WalletInfoDto {
+  final bool refreshedFromNode;
+  final String activeAccount;
+  final BigInt lastConfirmedHeight;
+  final BigInt minimumConfirmations;
+  final BigInt total;
+  final BigInt amountAwaitingFinalization;
+  final BigInt amountAwaitingConfirmation;
+  final BigInt amountImmature;
+  final BigInt amountCurrentlySpendable;
+  final BigInt amountLocked;
+  final BigInt amountReverted;
+
+  const WalletInfoDto({
+    required this.refreshedFromNode,
+    required this.activeAccount,
+    required this.lastConfirmedHeight,
+    required this.minimumConfirmations,
+    required this.total,
+    required this.amountAwaitingFinalization,
+    required this.amountAwaitingConfirmation,
+    required this.amountImmature,
+    required this.amountCurrentlySpendable,
+    required this.amountLocked,
+    required this.amountReverted,
+  });
+
+  @override
+  int get hashCode =>
+      refreshedFromNode.hashCode ^
+      activeAccount.hashCode ^
+      lastConfirmedHeight.hashCode ^
+      minimumConfirmations.hashCode ^
+      total.hashCode ^
+      amountAwaitingFinalization.hashCode ^
+      amountAwaitingConfirmation.hashCode ^
+      amountImmature.hashCode ^
+      amountCurrentlySpendable.hashCode ^
+      amountLocked.hashCode ^
+      amountReverted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WalletInfoDto &&
+          runtimeType == other.runtimeType &&
+          refreshedFromNode == other.refreshedFromNode &&
+          activeAccount == other.activeAccount &&
+          lastConfirmedHeight == other.lastConfirmedHeight &&
+          minimumConfirmations == other.minimumConfirmations &&
+          total == other.total &&
+          amountAwaitingFinalization == other.amountAwaitingFinalization &&
+          amountAwaitingConfirmation == other.amountAwaitingConfirmation &&
+          amountImmature == other.amountImmature &&
+          amountCurrentlySpendable == other.amountCurrentlySpendable &&
+          amountLocked == other.amountLocked &&
+          amountReverted == other.amountReverted;
+}
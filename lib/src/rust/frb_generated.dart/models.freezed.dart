@@ -0,0 +1,1246 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'models.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$UpdaterMessageDto {
+  String get message => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) updatingOutputs,
+    required TResult Function(String message) updatingTransactions,
+    required TResult Function(String message) fullScanWarn,
+    required TResult Function(String message, int percent) scanning,
+    required TResult Function(String message) scanningComplete,
+    required TResult Function(String message) updateWarning,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? updatingOutputs,
+    TResult? Function(String message)? updatingTransactions,
+    TResult? Function(String message)? fullScanWarn,
+    TResult? Function(String message, int percent)? scanning,
+    TResult? Function(String message)? scanningComplete,
+    TResult? Function(String message)? updateWarning,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? updatingOutputs,
+    TResult Function(String message)? updatingTransactions,
+    TResult Function(String message)? fullScanWarn,
+    TResult Function(String message, int percent)? scanning,
+    TResult Function(String message)? scanningComplete,
+    TResult Function(String message)? updateWarning,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UpdaterMessageDto_UpdatingOutputs value)
+        updatingOutputs,
+    required TResult Function(UpdaterMessageDto_UpdatingTransactions value)
+        updatingTransactions,
+    required TResult Function(UpdaterMessageDto_FullScanWarn value)
+        fullScanWarn,
+    required TResult Function(UpdaterMessageDto_Scanning value) scanning,
+    required TResult Function(UpdaterMessageDto_ScanningComplete value)
+        scanningComplete,
+    required TResult Function(UpdaterMessageDto_UpdateWarning value)
+        updateWarning,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult? Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult? Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult? Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult? Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult? Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $UpdaterMessageDtoCopyWith<UpdaterMessageDto> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $UpdaterMessageDtoCopyWith<$Res> {
+  factory $UpdaterMessageDtoCopyWith(
+          UpdaterMessageDto value, $Res Function(UpdaterMessageDto) then) =
+      _$UpdaterMessageDtoCopyWithImpl<$Res, UpdaterMessageDto>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class _$UpdaterMessageDtoCopyWithImpl<$Res, $Val extends UpdaterMessageDto>
+    implements $UpdaterMessageDtoCopyWith<$Res> {
+  _$UpdaterMessageDtoCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_value.copyWith(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$UpdaterMessageDto_UpdatingOutputsImplCopyWith<$Res>
+    implements $UpdaterMessageDtoCopyWith<$Res> {
+  factory _$$UpdaterMessageDto_UpdatingOutputsImplCopyWith(
+          _$UpdaterMessageDto_UpdatingOutputsImpl value,
+          $Res Function(_$UpdaterMessageDto_UpdatingOutputsImpl) then) =
+      __$$UpdaterMessageDto_UpdatingOutputsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$UpdaterMessageDto_UpdatingOutputsImplCopyWithImpl<$Res>
+    extends _$UpdaterMessageDtoCopyWithImpl<$Res, _$UpdaterMessageDto_UpdatingOutputsImpl>
+    implements _$$UpdaterMessageDto_UpdatingOutputsImplCopyWith<$Res> {
+  __$$UpdaterMessageDto_UpdatingOutputsImplCopyWithImpl(
+      _$UpdaterMessageDto_UpdatingOutputsImpl _value,
+      $Res Function(_$UpdaterMessageDto_UpdatingOutputsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$UpdaterMessageDto_UpdatingOutputsImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UpdaterMessageDto_UpdatingOutputsImpl
+    extends UpdaterMessageDto_UpdatingOutputs {
+  const _$UpdaterMessageDto_UpdatingOutputsImpl(
+      {required this.message})
+      : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'UpdaterMessageDto.updatingOutputs(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UpdaterMessageDto_UpdatingOutputsImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UpdaterMessageDto_UpdatingOutputsImplCopyWith<_$UpdaterMessageDto_UpdatingOutputsImpl>
+      get copyWith => __$$UpdaterMessageDto_UpdatingOutputsImplCopyWithImpl<
+          _$UpdaterMessageDto_UpdatingOutputsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) updatingOutputs,
+    required TResult Function(String message) updatingTransactions,
+    required TResult Function(String message) fullScanWarn,
+    required TResult Function(String message, int percent) scanning,
+    required TResult Function(String message) scanningComplete,
+    required TResult Function(String message) updateWarning,
+  }) {
+    return updatingOutputs(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? updatingOutputs,
+    TResult? Function(String message)? updatingTransactions,
+    TResult? Function(String message)? fullScanWarn,
+    TResult? Function(String message, int percent)? scanning,
+    TResult? Function(String message)? scanningComplete,
+    TResult? Function(String message)? updateWarning,
+  }) {
+    return updatingOutputs?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? updatingOutputs,
+    TResult Function(String message)? updatingTransactions,
+    TResult Function(String message)? fullScanWarn,
+    TResult Function(String message, int percent)? scanning,
+    TResult Function(String message)? scanningComplete,
+    TResult Function(String message)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (updatingOutputs != null) {
+      return updatingOutputs(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UpdaterMessageDto_UpdatingOutputs value)
+        updatingOutputs,
+    required TResult Function(UpdaterMessageDto_UpdatingTransactions value)
+        updatingTransactions,
+    required TResult Function(UpdaterMessageDto_FullScanWarn value)
+        fullScanWarn,
+    required TResult Function(UpdaterMessageDto_Scanning value) scanning,
+    required TResult Function(UpdaterMessageDto_ScanningComplete value)
+        scanningComplete,
+    required TResult Function(UpdaterMessageDto_UpdateWarning value)
+        updateWarning,
+  }) {
+    return updatingOutputs(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult? Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult? Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult? Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult? Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult? Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+  }) {
+    return updatingOutputs?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (updatingOutputs != null) {
+      return updatingOutputs(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UpdaterMessageDto_UpdatingOutputs extends UpdaterMessageDto {
+  const factory UpdaterMessageDto_UpdatingOutputs(
+      {required final String message}) = _$UpdaterMessageDto_UpdatingOutputsImpl;
+  const UpdaterMessageDto_UpdatingOutputs._() : super._();
+
+  @override
+  String get message;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$UpdaterMessageDto_UpdatingOutputsImplCopyWith<_$UpdaterMessageDto_UpdatingOutputsImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$UpdaterMessageDto_UpdatingTransactionsImplCopyWith<$Res>
+    implements $UpdaterMessageDtoCopyWith<$Res> {
+  factory _$$UpdaterMessageDto_UpdatingTransactionsImplCopyWith(
+          _$UpdaterMessageDto_UpdatingTransactionsImpl value,
+          $Res Function(_$UpdaterMessageDto_UpdatingTransactionsImpl) then) =
+      __$$UpdaterMessageDto_UpdatingTransactionsImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$UpdaterMessageDto_UpdatingTransactionsImplCopyWithImpl<$Res>
+    extends _$UpdaterMessageDtoCopyWithImpl<$Res, _$UpdaterMessageDto_UpdatingTransactionsImpl>
+    implements _$$UpdaterMessageDto_UpdatingTransactionsImplCopyWith<$Res> {
+  __$$UpdaterMessageDto_UpdatingTransactionsImplCopyWithImpl(
+      _$UpdaterMessageDto_UpdatingTransactionsImpl _value,
+      $Res Function(_$UpdaterMessageDto_UpdatingTransactionsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$UpdaterMessageDto_UpdatingTransactionsImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UpdaterMessageDto_UpdatingTransactionsImpl
+    extends UpdaterMessageDto_UpdatingTransactions {
+  const _$UpdaterMessageDto_UpdatingTransactionsImpl(
+      {required this.message})
+      : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'UpdaterMessageDto.updatingTransactions(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UpdaterMessageDto_UpdatingTransactionsImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UpdaterMessageDto_UpdatingTransactionsImplCopyWith<_$UpdaterMessageDto_UpdatingTransactionsImpl>
+      get copyWith => __$$UpdaterMessageDto_UpdatingTransactionsImplCopyWithImpl<
+          _$UpdaterMessageDto_UpdatingTransactionsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) updatingOutputs,
+    required TResult Function(String message) updatingTransactions,
+    required TResult Function(String message) fullScanWarn,
+    required TResult Function(String message, int percent) scanning,
+    required TResult Function(String message) scanningComplete,
+    required TResult Function(String message) updateWarning,
+  }) {
+    return updatingTransactions(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? updatingOutputs,
+    TResult? Function(String message)? updatingTransactions,
+    TResult? Function(String message)? fullScanWarn,
+    TResult? Function(String message, int percent)? scanning,
+    TResult? Function(String message)? scanningComplete,
+    TResult? Function(String message)? updateWarning,
+  }) {
+    return updatingTransactions?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? updatingOutputs,
+    TResult Function(String message)? updatingTransactions,
+    TResult Function(String message)? fullScanWarn,
+    TResult Function(String message, int percent)? scanning,
+    TResult Function(String message)? scanningComplete,
+    TResult Function(String message)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (updatingTransactions != null) {
+      return updatingTransactions(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UpdaterMessageDto_UpdatingOutputs value)
+        updatingOutputs,
+    required TResult Function(UpdaterMessageDto_UpdatingTransactions value)
+        updatingTransactions,
+    required TResult Function(UpdaterMessageDto_FullScanWarn value)
+        fullScanWarn,
+    required TResult Function(UpdaterMessageDto_Scanning value) scanning,
+    required TResult Function(UpdaterMessageDto_ScanningComplete value)
+        scanningComplete,
+    required TResult Function(UpdaterMessageDto_UpdateWarning value)
+        updateWarning,
+  }) {
+    return updatingTransactions(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult? Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult? Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult? Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult? Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult? Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+  }) {
+    return updatingTransactions?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (updatingTransactions != null) {
+      return updatingTransactions(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UpdaterMessageDto_UpdatingTransactions extends UpdaterMessageDto {
+  const factory UpdaterMessageDto_UpdatingTransactions(
+      {required final String message}) = _$UpdaterMessageDto_UpdatingTransactionsImpl;
+  const UpdaterMessageDto_UpdatingTransactions._() : super._();
+
+  @override
+  String get message;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$UpdaterMessageDto_UpdatingTransactionsImplCopyWith<_$UpdaterMessageDto_UpdatingTransactionsImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$UpdaterMessageDto_FullScanWarnImplCopyWith<$Res>
+    implements $UpdaterMessageDtoCopyWith<$Res> {
+  factory _$$UpdaterMessageDto_FullScanWarnImplCopyWith(
+          _$UpdaterMessageDto_FullScanWarnImpl value,
+          $Res Function(_$UpdaterMessageDto_FullScanWarnImpl) then) =
+      __$$UpdaterMessageDto_FullScanWarnImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$UpdaterMessageDto_FullScanWarnImplCopyWithImpl<$Res>
+    extends _$UpdaterMessageDtoCopyWithImpl<$Res, _$UpdaterMessageDto_FullScanWarnImpl>
+    implements _$$UpdaterMessageDto_FullScanWarnImplCopyWith<$Res> {
+  __$$UpdaterMessageDto_FullScanWarnImplCopyWithImpl(
+      _$UpdaterMessageDto_FullScanWarnImpl _value,
+      $Res Function(_$UpdaterMessageDto_FullScanWarnImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$UpdaterMessageDto_FullScanWarnImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UpdaterMessageDto_FullScanWarnImpl
+    extends UpdaterMessageDto_FullScanWarn {
+  const _$UpdaterMessageDto_FullScanWarnImpl(
+      {required this.message})
+      : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'UpdaterMessageDto.fullScanWarn(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UpdaterMessageDto_FullScanWarnImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UpdaterMessageDto_FullScanWarnImplCopyWith<_$UpdaterMessageDto_FullScanWarnImpl>
+      get copyWith => __$$UpdaterMessageDto_FullScanWarnImplCopyWithImpl<
+          _$UpdaterMessageDto_FullScanWarnImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) updatingOutputs,
+    required TResult Function(String message) updatingTransactions,
+    required TResult Function(String message) fullScanWarn,
+    required TResult Function(String message, int percent) scanning,
+    required TResult Function(String message) scanningComplete,
+    required TResult Function(String message) updateWarning,
+  }) {
+    return fullScanWarn(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? updatingOutputs,
+    TResult? Function(String message)? updatingTransactions,
+    TResult? Function(String message)? fullScanWarn,
+    TResult? Function(String message, int percent)? scanning,
+    TResult? Function(String message)? scanningComplete,
+    TResult? Function(String message)? updateWarning,
+  }) {
+    return fullScanWarn?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? updatingOutputs,
+    TResult Function(String message)? updatingTransactions,
+    TResult Function(String message)? fullScanWarn,
+    TResult Function(String message, int percent)? scanning,
+    TResult Function(String message)? scanningComplete,
+    TResult Function(String message)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (fullScanWarn != null) {
+      return fullScanWarn(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UpdaterMessageDto_UpdatingOutputs value)
+        updatingOutputs,
+    required TResult Function(UpdaterMessageDto_UpdatingTransactions value)
+        updatingTransactions,
+    required TResult Function(UpdaterMessageDto_FullScanWarn value)
+        fullScanWarn,
+    required TResult Function(UpdaterMessageDto_Scanning value) scanning,
+    required TResult Function(UpdaterMessageDto_ScanningComplete value)
+        scanningComplete,
+    required TResult Function(UpdaterMessageDto_UpdateWarning value)
+        updateWarning,
+  }) {
+    return fullScanWarn(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult? Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult? Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult? Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult? Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult? Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+  }) {
+    return fullScanWarn?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (fullScanWarn != null) {
+      return fullScanWarn(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UpdaterMessageDto_FullScanWarn extends UpdaterMessageDto {
+  const factory UpdaterMessageDto_FullScanWarn(
+      {required final String message}) = _$UpdaterMessageDto_FullScanWarnImpl;
+  const UpdaterMessageDto_FullScanWarn._() : super._();
+
+  @override
+  String get message;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$UpdaterMessageDto_FullScanWarnImplCopyWith<_$UpdaterMessageDto_FullScanWarnImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$UpdaterMessageDto_ScanningImplCopyWith<$Res>
+    implements $UpdaterMessageDtoCopyWith<$Res> {
+  factory _$$UpdaterMessageDto_ScanningImplCopyWith(
+          _$UpdaterMessageDto_ScanningImpl value,
+          $Res Function(_$UpdaterMessageDto_ScanningImpl) then) =
+      __$$UpdaterMessageDto_ScanningImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String message, int percent});
+}
+
+/// @nodoc
+class __$$UpdaterMessageDto_ScanningImplCopyWithImpl<$Res>
+    extends _$UpdaterMessageDtoCopyWithImpl<$Res, _$UpdaterMessageDto_ScanningImpl>
+    implements _$$UpdaterMessageDto_ScanningImplCopyWith<$Res> {
+  __$$UpdaterMessageDto_ScanningImplCopyWithImpl(
+      _$UpdaterMessageDto_ScanningImpl _value,
+      $Res Function(_$UpdaterMessageDto_ScanningImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+    Object? percent = null,
+  }) {
+    return _then(_$UpdaterMessageDto_ScanningImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+      percent: null == percent
+          ? _value.percent
+          : percent // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UpdaterMessageDto_ScanningImpl extends UpdaterMessageDto_Scanning {
+  const _$UpdaterMessageDto_ScanningImpl(
+      {required this.message, required this.percent})
+      : super._();
+
+  @override
+  final String message;
+  @override
+  final int percent;
+
+  @override
+  String toString() {
+    return 'UpdaterMessageDto.scanning(message: $message, percent: $percent)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UpdaterMessageDto_ScanningImpl &&
+            (identical(other.message, message) || other.message == message) &&
+            (identical(other.percent, percent) || other.percent == percent));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message, percent);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UpdaterMessageDto_ScanningImplCopyWith<_$UpdaterMessageDto_ScanningImpl>
+      get copyWith => __$$UpdaterMessageDto_ScanningImplCopyWithImpl<
+          _$UpdaterMessageDto_ScanningImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) updatingOutputs,
+    required TResult Function(String message) updatingTransactions,
+    required TResult Function(String message) fullScanWarn,
+    required TResult Function(String message, int percent) scanning,
+    required TResult Function(String message) scanningComplete,
+    required TResult Function(String message) updateWarning,
+  }) {
+    return scanning(message, percent);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? updatingOutputs,
+    TResult? Function(String message)? updatingTransactions,
+    TResult? Function(String message)? fullScanWarn,
+    TResult? Function(String message, int percent)? scanning,
+    TResult? Function(String message)? scanningComplete,
+    TResult? Function(String message)? updateWarning,
+  }) {
+    return scanning?.call(message, percent);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? updatingOutputs,
+    TResult Function(String message)? updatingTransactions,
+    TResult Function(String message)? fullScanWarn,
+    TResult Function(String message, int percent)? scanning,
+    TResult Function(String message)? scanningComplete,
+    TResult Function(String message)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (scanning != null) {
+      return scanning(message, percent);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UpdaterMessageDto_UpdatingOutputs value)
+        updatingOutputs,
+    required TResult Function(UpdaterMessageDto_UpdatingTransactions value)
+        updatingTransactions,
+    required TResult Function(UpdaterMessageDto_FullScanWarn value)
+        fullScanWarn,
+    required TResult Function(UpdaterMessageDto_Scanning value) scanning,
+    required TResult Function(UpdaterMessageDto_ScanningComplete value)
+        scanningComplete,
+    required TResult Function(UpdaterMessageDto_UpdateWarning value)
+        updateWarning,
+  }) {
+    return scanning(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult? Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult? Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult? Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult? Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult? Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+  }) {
+    return scanning?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (scanning != null) {
+      return scanning(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UpdaterMessageDto_Scanning extends UpdaterMessageDto {
+  const factory UpdaterMessageDto_Scanning(
+      {required final String message,
+      required final int percent}) = _$UpdaterMessageDto_ScanningImpl;
+  const UpdaterMessageDto_Scanning._() : super._();
+
+  @override
+  String get message;
+  int get percent;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$UpdaterMessageDto_ScanningImplCopyWith<_$UpdaterMessageDto_ScanningImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$UpdaterMessageDto_ScanningCompleteImplCopyWith<$Res>
+    implements $UpdaterMessageDtoCopyWith<$Res> {
+  factory _$$UpdaterMessageDto_ScanningCompleteImplCopyWith(
+          _$UpdaterMessageDto_ScanningCompleteImpl value,
+          $Res Function(_$UpdaterMessageDto_ScanningCompleteImpl) then) =
+      __$$UpdaterMessageDto_ScanningCompleteImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$UpdaterMessageDto_ScanningCompleteImplCopyWithImpl<$Res>
+    extends _$UpdaterMessageDtoCopyWithImpl<$Res, _$UpdaterMessageDto_ScanningCompleteImpl>
+    implements _$$UpdaterMessageDto_ScanningCompleteImplCopyWith<$Res> {
+  __$$UpdaterMessageDto_ScanningCompleteImplCopyWithImpl(
+      _$UpdaterMessageDto_ScanningCompleteImpl _value,
+      $Res Function(_$UpdaterMessageDto_ScanningCompleteImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$UpdaterMessageDto_ScanningCompleteImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UpdaterMessageDto_ScanningCompleteImpl
+    extends UpdaterMessageDto_ScanningComplete {
+  const _$UpdaterMessageDto_ScanningCompleteImpl(
+      {required this.message})
+      : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'UpdaterMessageDto.scanningComplete(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UpdaterMessageDto_ScanningCompleteImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UpdaterMessageDto_ScanningCompleteImplCopyWith<_$UpdaterMessageDto_ScanningCompleteImpl>
+      get copyWith => __$$UpdaterMessageDto_ScanningCompleteImplCopyWithImpl<
+          _$UpdaterMessageDto_ScanningCompleteImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) updatingOutputs,
+    required TResult Function(String message) updatingTransactions,
+    required TResult Function(String message) fullScanWarn,
+    required TResult Function(String message, int percent) scanning,
+    required TResult Function(String message) scanningComplete,
+    required TResult Function(String message) updateWarning,
+  }) {
+    return scanningComplete(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? updatingOutputs,
+    TResult? Function(String message)? updatingTransactions,
+    TResult? Function(String message)? fullScanWarn,
+    TResult? Function(String message, int percent)? scanning,
+    TResult? Function(String message)? scanningComplete,
+    TResult? Function(String message)? updateWarning,
+  }) {
+    return scanningComplete?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? updatingOutputs,
+    TResult Function(String message)? updatingTransactions,
+    TResult Function(String message)? fullScanWarn,
+    TResult Function(String message, int percent)? scanning,
+    TResult Function(String message)? scanningComplete,
+    TResult Function(String message)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (scanningComplete != null) {
+      return scanningComplete(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UpdaterMessageDto_UpdatingOutputs value)
+        updatingOutputs,
+    required TResult Function(UpdaterMessageDto_UpdatingTransactions value)
+        updatingTransactions,
+    required TResult Function(UpdaterMessageDto_FullScanWarn value)
+        fullScanWarn,
+    required TResult Function(UpdaterMessageDto_Scanning value) scanning,
+    required TResult Function(UpdaterMessageDto_ScanningComplete value)
+        scanningComplete,
+    required TResult Function(UpdaterMessageDto_UpdateWarning value)
+        updateWarning,
+  }) {
+    return scanningComplete(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult? Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult? Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult? Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult? Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult? Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+  }) {
+    return scanningComplete?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (scanningComplete != null) {
+      return scanningComplete(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UpdaterMessageDto_ScanningComplete extends UpdaterMessageDto {
+  const factory UpdaterMessageDto_ScanningComplete(
+      {required final String message}) = _$UpdaterMessageDto_ScanningCompleteImpl;
+  const UpdaterMessageDto_ScanningComplete._() : super._();
+
+  @override
+  String get message;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$UpdaterMessageDto_ScanningCompleteImplCopyWith<_$UpdaterMessageDto_ScanningCompleteImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+/// @nodoc
+abstract class _$$UpdaterMessageDto_UpdateWarningImplCopyWith<$Res>
+    implements $UpdaterMessageDtoCopyWith<$Res> {
+  factory _$$UpdaterMessageDto_UpdateWarningImplCopyWith(
+          _$UpdaterMessageDto_UpdateWarningImpl value,
+          $Res Function(_$UpdaterMessageDto_UpdateWarningImpl) then) =
+      __$$UpdaterMessageDto_UpdateWarningImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$UpdaterMessageDto_UpdateWarningImplCopyWithImpl<$Res>
+    extends _$UpdaterMessageDtoCopyWithImpl<$Res, _$UpdaterMessageDto_UpdateWarningImpl>
+    implements _$$UpdaterMessageDto_UpdateWarningImplCopyWith<$Res> {
+  __$$UpdaterMessageDto_UpdateWarningImplCopyWithImpl(
+      _$UpdaterMessageDto_UpdateWarningImpl _value,
+      $Res Function(_$UpdaterMessageDto_UpdateWarningImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$UpdaterMessageDto_UpdateWarningImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$UpdaterMessageDto_UpdateWarningImpl
+    extends UpdaterMessageDto_UpdateWarning {
+  const _$UpdaterMessageDto_UpdateWarningImpl(
+      {required this.message})
+      : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'UpdaterMessageDto.updateWarning(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$UpdaterMessageDto_UpdateWarningImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$UpdaterMessageDto_UpdateWarningImplCopyWith<_$UpdaterMessageDto_UpdateWarningImpl>
+      get copyWith => __$$UpdaterMessageDto_UpdateWarningImplCopyWithImpl<
+          _$UpdaterMessageDto_UpdateWarningImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) updatingOutputs,
+    required TResult Function(String message) updatingTransactions,
+    required TResult Function(String message) fullScanWarn,
+    required TResult Function(String message, int percent) scanning,
+    required TResult Function(String message) scanningComplete,
+    required TResult Function(String message) updateWarning,
+  }) {
+    return updateWarning(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? updatingOutputs,
+    TResult? Function(String message)? updatingTransactions,
+    TResult? Function(String message)? fullScanWarn,
+    TResult? Function(String message, int percent)? scanning,
+    TResult? Function(String message)? scanningComplete,
+    TResult? Function(String message)? updateWarning,
+  }) {
+    return updateWarning?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? updatingOutputs,
+    TResult Function(String message)? updatingTransactions,
+    TResult Function(String message)? fullScanWarn,
+    TResult Function(String message, int percent)? scanning,
+    TResult Function(String message)? scanningComplete,
+    TResult Function(String message)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (updateWarning != null) {
+      return updateWarning(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(UpdaterMessageDto_UpdatingOutputs value)
+        updatingOutputs,
+    required TResult Function(UpdaterMessageDto_UpdatingTransactions value)
+        updatingTransactions,
+    required TResult Function(UpdaterMessageDto_FullScanWarn value)
+        fullScanWarn,
+    required TResult Function(UpdaterMessageDto_Scanning value) scanning,
+    required TResult Function(UpdaterMessageDto_ScanningComplete value)
+        scanningComplete,
+    required TResult Function(UpdaterMessageDto_UpdateWarning value)
+        updateWarning,
+  }) {
+    return updateWarning(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult? Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult? Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult? Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult? Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult? Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+  }) {
+    return updateWarning?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(UpdaterMessageDto_UpdatingOutputs value)? updatingOutputs,
+    TResult Function(UpdaterMessageDto_UpdatingTransactions value)?
+        updatingTransactions,
+    TResult Function(UpdaterMessageDto_FullScanWarn value)? fullScanWarn,
+    TResult Function(UpdaterMessageDto_Scanning value)? scanning,
+    TResult Function(UpdaterMessageDto_ScanningComplete value)?
+        scanningComplete,
+    TResult Function(UpdaterMessageDto_UpdateWarning value)? updateWarning,
+    required TResult orElse(),
+  }) {
+    if (updateWarning != null) {
+      return updateWarning(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class UpdaterMessageDto_UpdateWarning extends UpdaterMessageDto {
+  const factory UpdaterMessageDto_UpdateWarning(
+      {required final String message}) = _$UpdaterMessageDto_UpdateWarningImpl;
+  const UpdaterMessageDto_UpdateWarning._() : super._();
+
+  @override
+  String get message;
+
+  /// Create a copy of UpdaterMessageDto
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$UpdaterMessageDto_UpdateWarningImplCopyWith<_$UpdaterMessageDto_UpdateWarningImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
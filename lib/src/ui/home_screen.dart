@@ -8,7 +8,7 @@ import 'package:provider/provider.dart';
 
 import '../localization/loc.dart';
 import '../localization/locale_store.dart';
-import '../rust/frb_generated.dart/api.dart' as bridge;
+import '../rust/frb_generated.dart/models.dart';
 import '../wallet/models.dart';
 import '../wallet/wallet_store.dart';
 
@@ -147,13 +147,10 @@ class _HomeScreenState extends State<HomeScreen> {
         context.trNow('Preparing wallet...', 'Wallet wird vorbereitet...'),
         () async {
           if (create) {
-            await bridge.walletCreate(
-              dataDir: dir,
-              passphrase: passToUse,
-              mnemonicLength: BigInt.from(24),
-            );
+            await walletStore.service.createWallet(dir, passToUse);
+          } else {
+            await walletStore.service.openWallet(dir, passToUse);
           }
-          await bridge.walletInitOrOpen(dataDir: dir, passphrase: passToUse);
         },
       );
       _walletPass = passToUse;
@@ -174,7 +171,7 @@ class _HomeScreenState extends State<HomeScreen> {
           'Running full sync, please wait...',
           'Voller Sync laeuft, bitte warten...',
         ),
-        () async => bridge.walletSync(),
+        () async => _walletStore?.service.sync(),
       );
       final store = _walletStore;
       if (store != null) {
@@ -285,9 +282,9 @@ class _HomeScreenState extends State<HomeScreen> {
     if (result == null) return;
     try {
       append('[S1] Creating slatepack...');
-      final slate = await bridge.walletSendSlatepack(
-        to: result.address?.trim() ?? '',
-        amountNano: result.amountNano,
+      final slate = await _walletStore!.service.sendSlatepack(
+        result.address?.trim() ?? '',
+        result.amountNano,
       );
       await _showSlateResultDialog(
         title: context.trNow('Share this slatepack with the receiver', 'Teile dieses Slatepack mit dem Empfaenger'),
@@ -309,7 +306,7 @@ class _HomeScreenState extends State<HomeScreen> {
     if (result == null) return;
     try {
       append('[I1] Creating invoice slatepack...');
-      final slate = await bridge.walletIssueInvoice(amountNano: result.amountNano);
+      final slate = await _walletStore!.service.issueInvoice(result.amountNano);
       await _showSlateResultDialog(
         title: context.trNow('Share this invoice with the sender', 'Teile dieses Invoice-Slate mit dem Sender'),
         code: 'I1',
@@ -336,11 +333,7 @@ class _HomeScreenState extends State<HomeScreen> {
     if (store == null) return;
     try {
       append('[$code] Finalizing and posting...');
-      final finalized = await bridge.walletFinalizeSlatepack(
-        message: slate,
-        postTx: true,
-        fluff: fluffTx,
-      );
+      final finalized = await store.service.finalizeSlatepack(slate, fluff: fluffTx);
       append('[$code] Slate finalized (auto-post requested). Refreshing wallet data...');
       await Future.wait([
         store.refreshOverview(),
@@ -488,11 +481,11 @@ class _HomeScreenState extends State<HomeScreen> {
     final isIncoming = _isIncoming(tx);
     final amountAccent = isIncoming ? _receiveAccent : _sendAccent;
     try {
-      final slate = await bridge.walletTransactionSlatepack(txId: tx.id);
+      final service = _walletStore!.service;
+      final slate = await service.transactionSlatepack(tx.id);
       SlateInspection? inspection;
       try {
-        final raw = await bridge.walletInspectSlatepack(message: slate);
-        inspection = SlateInspection.fromJson(jsonDecode(raw) as Map<String, dynamic>);
+        inspection = SlateInspection.fromDto(await service.inspectSlatepack(slate));
       } catch (e) {
         append('Inspect failed for Tx ${tx.id}: $e');
       }
@@ -561,8 +554,8 @@ class _HomeScreenState extends State<HomeScreen> {
     try {
       append('[$code] Preparing response...');
       final nextSlate = isInvoice
-          ? await bridge.walletProcessInvoice(message: slate)
-          : await bridge.walletReceiveSlatepack(message: slate);
+          ? await _walletStore!.service.processInvoice(slate)
+          : await _walletStore!.service.receiveSlatepack(slate);
       final title = isInvoice
           ? context.trNow('Share this invoice response with the sender', 'Teile diese Invoice-Antwort mit dem Sender')
           : context.trNow('Share this response with the counterparty', 'Teile diese Antwort mit dem Gegenueber');
@@ -580,8 +573,7 @@ class _HomeScreenState extends State<HomeScreen> {
   Future<void> _showIncomingActionDialog(String slate) async {
     SlateInspection? inspection;
     try {
-      final raw = await bridge.walletInspectSlatepack(message: slate);
-      inspection = SlateInspection.fromJson(jsonDecode(raw) as Map<String, dynamic>);
+      inspection = SlateInspection.fromDto(await _walletStore!.service.inspectSlatepack(slate));
     } catch (e) {
       append('Inspect failed: $e');
     }
@@ -925,7 +917,8 @@ class _HomeScreenState extends State<HomeScreen> {
       final credentials = base64.encode(utf8.encode('grin:$secret'));
       final client = HttpClient();
       try {
-        final request = await client.postUrl(Uri.parse('http://127.0.0.1:3415/v2/foreign'));
+        final addr = await store?.service.foreignListenerAddr() ?? '127.0.0.1:3415';
+        final request = await client.postUrl(Uri.parse('http://$addr/v2/foreign'));
         request.headers.set(HttpHeaders.contentTypeHeader, 'application/json');
         request.headers.set(HttpHeaders.authorizationHeader, 'Basic $credentials');
         request.add(utf8.encode(jsonEncode({
@@ -1922,11 +1915,7 @@ class _HomeScreenState extends State<HomeScreen> {
       await _blockingTask(
         context.trNow('Restoring wallet...', 'Wallet wird wiederhergestellt...'),
         () async {
-          await bridge.walletRestoreFromSeed(
-            dataDir: dir,
-            passphrase: pass,
-            phrase: normalized,
-          );
+          await _walletStore!.service.restoreWallet(dir, pass, normalized);
         },
       );
       _walletPass = pass;
@@ -2043,7 +2032,7 @@ class _HomeScreenState extends State<HomeScreen> {
       await _blockingTask(
         context.trNow('Retrieving seed phrase...', 'Seed Phrase wird geladen...'),
         () async {
-          phrase = await bridge.walletSeedPhrase(dataDir: dir, passphrase: pass);
+          phrase = await _walletStore!.service.seedPhrase(dir, pass);
         },
       );
     } catch (e) {
@@ -2103,8 +2092,7 @@ class _HomeScreenState extends State<HomeScreen> {
     if (shouldLogout != true) return;
     final store = _walletStore;
     if (store != null) {
-      store.unlocked = false;
-      store.notifyListeners();
+      await store.lock();
     }
     setState(() {
       _walletPass = null;
@@ -2150,14 +2138,14 @@ class SlateInspection {
     this.kernelExcess,
   });
 
-  factory SlateInspection.fromJson(Map<String, dynamic> json) => SlateInspection(
-        code: json['code'] as String? ?? 'unknown',
-        slateId: json['slateId'] as String? ?? '',
-        state: json['state'] as String? ?? 'unknown',
-        amount: _parseInt(json['amount']),
-        fee: _parseInt(json['fee']),
-        numParticipants: _parseInt(json['numParticipants']),
-        kernelExcess: json['kernelExcess'] as String?,
+  factory SlateInspection.fromDto(SlateInspectionDto dto) => SlateInspection(
+        code: dto.code.name.toUpperCase(),
+        slateId: dto.slateId,
+        state: dto.state,
+        amount: dto.amount.toInt(),
+        fee: dto.fee.toInt(),
+        numParticipants: dto.numParticipants,
+        kernelExcess: dto.kernelExcess,
       );
 
   final String code;
@@ -2167,15 +2155,4 @@ class SlateInspection {
   final int fee;
   final int numParticipants;
   final String? kernelExcess;
-
-  static int _parseInt(dynamic value) {
-    if (value == null) return 0;
-    if (value is int) return value;
-    if (value is num) return value.toInt();
-    if (value is String && value.isNotEmpty) {
-      return int.tryParse(value) ?? 0;
-    }
-    return 0;
-  }
 }
-
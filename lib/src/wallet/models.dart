@@ -1,28 +1,6 @@
 import 'dart:convert';
 
-BigInt _bigInt(dynamic value) {
-  if (value is BigInt) return value;
-  if (value is int) return BigInt.from(value);
-  if (value is num) return BigInt.from(value.toInt());
-  if (value is String) return BigInt.parse(value);
-  throw ArgumentError('Unsupported numeric value: $value');
-}
-
-int _int(dynamic value) {
-  final result = _intOrNull(value);
-  if (result == null) {
-    throw ArgumentError('Expected int-compatible value but got $value');
-  }
-  return result;
-}
-
-int? _intOrNull(dynamic value) {
-  if (value == null) return null;
-  if (value is int) return value;
-  if (value is num) return value.toInt();
-  if (value is String && value.isNotEmpty) return int.parse(value);
-  return null;
-}
+import '../rust/frb_generated.dart/models.dart';
 
 class WalletInfoModel {
   WalletInfoModel({
@@ -31,12 +9,10 @@ class WalletInfoModel {
     required this.activeAccount,
   });
 
-  factory WalletInfoModel.fromJson(Map<String, dynamic> json) => WalletInfoModel(
-        refreshedFromNode: json['refreshedFromNode'] as bool? ?? false,
-        info: WalletInfoDetails.fromJson(
-          Map<String, dynamic>.from(json['info'] as Map),
-        ),
-        activeAccount: json['activeAccount'] as String? ?? 'default',
+  factory WalletInfoModel.fromDto(WalletInfoDto dto) => WalletInfoModel(
+        refreshedFromNode: dto.refreshedFromNode,
+        info: WalletInfoDetails.fromDto(dto),
+        activeAccount: dto.activeAccount,
       );
 
   final bool refreshedFromNode;
@@ -57,16 +33,16 @@ class WalletInfoDetails {
     required this.reverted,
   });
 
-  factory WalletInfoDetails.fromJson(Map<String, dynamic> json) => WalletInfoDetails(
-        lastConfirmedHeight: _int(json['last_confirmed_height']),
-        minimumConfirmations: _int(json['minimum_confirmations']),
-        total: _bigInt(json['total']),
-        awaitingFinalization: _bigInt(json['amount_awaiting_finalization']),
-        awaitingConfirmation: _bigInt(json['amount_awaiting_confirmation']),
-        immature: _bigInt(json['amount_immature']),
-        currentlySpendable: _bigInt(json['amount_currently_spendable']),
-        locked: _bigInt(json['amount_locked']),
-        reverted: _bigInt(json['amount_reverted']),
+  factory WalletInfoDetails.fromDto(WalletInfoDto dto) => WalletInfoDetails(
+        lastConfirmedHeight: dto.lastConfirmedHeight.toInt(),
+        minimumConfirmations: dto.minimumConfirmations.toInt(),
+        total: dto.total,
+        awaitingFinalization: dto.amountAwaitingFinalization,
+        awaitingConfirmation: dto.amountAwaitingConfirmation,
+        immature: dto.amountImmature,
+        currentlySpendable: dto.amountCurrentlySpendable,
+        locked: dto.amountLocked,
+        reverted: dto.amountReverted,
       );
 
   final int lastConfirmedHeight;
@@ -101,24 +77,24 @@ class TransactionModel {
     required this.confirmations,
   });
 
-  factory TransactionModel.fromJson(Map<String, dynamic> json) => TransactionModel(
-        id: json['id'] as int,
-        txSlateId: json['txSlateId'] as String?,
-        txType: json['txType'] as String? ?? 'unknown',
-        status: json['status'] as String? ?? 'pending',
-        direction: json['direction'] as String? ?? 'received',
-        creationTime: DateTime.parse(json['creationTs'] as String),
-        confirmationTime: (json['confirmationTs'] as String?)?.let(DateTime.parse),
-        confirmed: json['confirmed'] as bool? ?? false,
-        amount: _bigInt(json['amount']),
-        fee: json['fee'] == null ? null : _bigInt(json['fee']),
-        inputs: _intOrNull(json['numInputs']) ?? 0,
-        outputs: _intOrNull(json['numOutputs']) ?? 0,
-        hasProof: json['hasProof'] as bool? ?? false,
-        kernelExcess: json['kernelExcess'] as String?,
-        ttlCutoffHeight: _intOrNull(json['ttlCutoffHeight']),
-        revertedAfterSecs: _intOrNull(json['revertedAfterSecs']),
-        confirmations: _intOrNull(json['confirmations']) ?? 0,
+  factory TransactionModel.fromDto(TransactionDto dto) => TransactionModel(
+        id: dto.id,
+        txSlateId: dto.txSlateId,
+        txType: dto.txType,
+        status: dto.status,
+        direction: dto.direction,
+        creationTime: DateTime.parse(dto.creationTs),
+        confirmationTime: dto.confirmationTs?.let(DateTime.parse),
+        confirmed: dto.confirmed,
+        amount: dto.amount,
+        fee: dto.fee,
+        inputs: dto.numInputs.toInt(),
+        outputs: dto.numOutputs.toInt(),
+        hasProof: dto.hasProof,
+        kernelExcess: dto.kernelExcess,
+        ttlCutoffHeight: dto.ttlCutoffHeight?.toInt(),
+        revertedAfterSecs: dto.revertedAfterSecs?.toInt(),
+        confirmations: dto.confirmations.toInt(),
       );
 
   final int id;
@@ -158,17 +134,17 @@ class OutputModel {
     required this.spendable,
   });
 
-  factory OutputModel.fromJson(Map<String, dynamic> json) => OutputModel(
-        commitment: json['commitment'] as String? ?? '',
-        value: _bigInt(json['value']),
-        status: json['status'] as String? ?? 'unspent',
-        height: _int(json['height']),
-        lockHeight: _int(json['lockHeight']),
-        isCoinbase: json['isCoinbase'] as bool? ?? false,
-        mmrIndex: _intOrNull(json['mmrIndex']),
-        txLogId: _intOrNull(json['txLogId']),
-        confirmations: _int(json['confirmations']),
-        spendable: json['spendable'] as bool? ?? false,
+  factory OutputModel.fromDto(OutputDto dto) => OutputModel(
+        commitment: dto.commitment,
+        value: dto.value,
+        status: dto.status,
+        height: dto.height.toInt(),
+        lockHeight: dto.lockHeight.toInt(),
+        isCoinbase: dto.isCoinbase,
+        mmrIndex: dto.mmrIndex?.toInt(),
+        txLogId: dto.txLogId,
+        confirmations: dto.confirmations.toInt(),
+        spendable: dto.spendable,
       );
 
   final String commitment;
@@ -190,10 +166,10 @@ class AccountModel {
     required this.isActive,
   });
 
-  factory AccountModel.fromJson(Map<String, dynamic> json) => AccountModel(
-        label: json['label'] as String? ?? 'default',
-        path: json['path'] as String? ?? 'm/0/0',
-        isActive: json['isActive'] as bool? ?? false,
+  factory AccountModel.fromDto(AccountDto dto) => AccountModel(
+        label: dto.label,
+        path: dto.path,
+        isActive: dto.isActive,
       );
 
   final String label;
@@ -209,11 +185,11 @@ class ScanResultModel {
     required this.performedAtEpochSecs,
   });
 
-  factory ScanResultModel.fromJson(Map<String, dynamic> json) => ScanResultModel(
-        deleteUnconfirmed: json['deleteUnconfirmed'] as bool? ?? false,
-        startHeight: _intOrNull(json['startHeight']),
-        backwardsFromTip: _intOrNull(json['backwardsFromTip']),
-        performedAtEpochSecs: _int(json['performedAtEpochSecs']),
+  factory ScanResultModel.fromDto(ScanResultDto dto) => ScanResultModel(
+        deleteUnconfirmed: dto.deleteUnconfirmed,
+        startHeight: dto.startHeight?.toInt(),
+        backwardsFromTip: dto.backwardsFromTip?.toInt(),
+        performedAtEpochSecs: dto.performedAtEpochSecs.toInt(),
       );
 
   final bool deleteUnconfirmed;
@@ -232,11 +208,10 @@ class PaymentProofModel {
     required this.raw,
   });
 
-  factory PaymentProofModel.fromJson(Map<String, dynamic> json, {required String raw}) =>
-      PaymentProofModel(
-        txId: json['txId'] as int,
-        proof: Map<String, dynamic>.from(json['proof'] as Map),
-        raw: raw,
+  factory PaymentProofModel.fromDto(PaymentProofDto dto) => PaymentProofModel(
+        txId: dto.txId,
+        proof: Map<String, dynamic>.from(jsonDecode(dto.proofJson) as Map),
+        raw: dto.proofJson,
       );
 
   final int txId;
@@ -252,10 +227,10 @@ class PaymentProofVerification {
     required this.isRecipient,
   });
 
-  factory PaymentProofVerification.fromJson(Map<String, dynamic> json) =>
+  factory PaymentProofVerification.fromDto(PaymentProofVerificationDto dto) =>
       PaymentProofVerification(
-        isSender: json['isSender'] as bool? ?? false,
-        isRecipient: json['isRecipient'] as bool? ?? false,
+        isSender: dto.isSender,
+        isRecipient: dto.isRecipient,
       );
 
   final bool isSender;
@@ -269,11 +244,11 @@ class OwnerListenerStatusModel {
     this.message,
   });
 
-  factory OwnerListenerStatusModel.fromJson(Map<String, dynamic> json) =>
+  factory OwnerListenerStatusModel.fromDto(OwnerListenerStatusDto dto) =>
       OwnerListenerStatusModel(
-        running: json['running'] as bool? ?? false,
-        listenAddr: json['listenAddr'] as String? ?? '127.0.0.1:3420',
-        message: json['message'] as String?,
+        running: dto.running,
+        listenAddr: dto.listenAddr,
+        message: dto.message,
       );
 
   final bool running;
@@ -288,10 +263,10 @@ class TorStatusModel {
     this.slatepackAddress,
   });
 
-  factory TorStatusModel.fromJson(Map<String, dynamic> json) => TorStatusModel(
-        running: json['running'] as bool? ?? false,
-        onionAddress: json['onionAddress'] as String?,
-        slatepackAddress: json['slatepackAddress'] as String?,
+  factory TorStatusModel.fromDto(TorStatusDto dto) => TorStatusModel(
+        running: dto.running,
+        onionAddress: dto.onionAddress,
+        slatepackAddress: dto.slatepackAddress,
       );
 
   final bool running;
@@ -1,125 +1,225 @@
-import 'dart:convert';
-
 import '../rust/frb_generated.dart/api.dart' as bridge;
+import '../rust/frb_generated.dart/models.dart';
+import '../rust/frb_generated.dart/wallet.dart';
 import 'models.dart';
 
 class WalletService {
+  BigInt? _handle;
+
+  bool get isOpen => _handle != null;
+
+  BigInt get _openHandle {
+    final handle = _handle;
+    if (handle == null) {
+      throw StateError('No wallet is open.');
+    }
+    return handle;
+  }
+
   Future<void> setNodeUrl(String url) => bridge.setNodeUrl(url: url.trim());
 
   Future<String> getNodeUrl() => bridge.getNodeUrl();
 
   Future<BigInt> getNodeTip() => bridge.getNodeTip();
 
-  Future<String> fetchAddress() => bridge.walletGetAddress();
+  // --- Wallet lifecycle ---
+  Future<void> openWallet(String dataDir, String passphrase) async {
+    _handle = await bridge.walletInitOrOpen(dataDir: dataDir, passphrase: passphrase);
+  }
+
+  Future<String> createWallet(
+    String dataDir,
+    String passphrase, {
+    WalletChainType chainType = WalletChainType.mainnet,
+  }) async {
+    final created = await bridge.walletCreate(
+      dataDir: dataDir,
+      passphrase: passphrase,
+      mnemonicLength: BigInt.from(24),
+      chainType: chainType,
+    );
+    _handle = created.handle;
+    return created.mnemonic;
+  }
+
+  Future<RestoreResultDto> restoreWallet(
+    String dataDir,
+    String passphrase,
+    String phrase, {
+    WalletChainType chainType = WalletChainType.mainnet,
+  }) async {
+    final result = await bridge.walletRestoreFromSeed(
+      dataDir: dataDir,
+      passphrase: passphrase,
+      phrase: phrase,
+      chainType: chainType,
+    );
+    _handle = result.handle;
+    return result;
+  }
+
+  Future<String> seedPhrase(String dataDir, String passphrase) =>
+      bridge.walletSeedPhrase(dataDir: dataDir, passphrase: passphrase);
+
+  Future<void> closeWallet() async {
+    final handle = _handle;
+    if (handle == null) return;
+    _handle = null;
+    await bridge.walletClose(handle: handle);
+  }
+
+  Future<void> sync() => bridge.walletSync(handle: _openHandle);
+
+  Future<String> fetchAddress() => bridge.walletGetAddress(handle: _openHandle);
 
   Future<WalletInfoModel> fetchWalletInfo() async {
-    final raw = await bridge.walletInfo();
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return WalletInfoModel.fromJson(data);
+    final dto = await bridge.walletInfo(handle: _openHandle);
+    return WalletInfoModel.fromDto(dto);
   }
 
   Future<List<TransactionModel>> fetchTransactions({
     required bool refreshFromNode,
   }) async {
-    final raw = await bridge.walletListTransactions(refreshFromNode: refreshFromNode);
-    return _parseList(raw, TransactionModel.fromJson);
+    final list = await bridge.walletListTransactions(
+      handle: _openHandle,
+      refreshFromNode: refreshFromNode,
+    );
+    return list.map(TransactionModel.fromDto).toList();
   }
 
   Future<List<OutputModel>> fetchOutputs({
     required bool includeSpent,
     required bool refreshFromNode,
   }) async {
-    final raw = await bridge.walletListOutputs(
+    final list = await bridge.walletListOutputs(
+      handle: _openHandle,
       includeSpent: includeSpent,
       refreshFromNode: refreshFromNode,
     );
-    return _parseList(raw, OutputModel.fromJson);
+    return list.map(OutputModel.fromDto).toList();
   }
 
-  Future<void> cancelTx(int txId) => bridge.walletCancelTx(txId: txId);
+  Future<void> cancelTx(int txId) => bridge.walletCancelTx(handle: _openHandle, txId: txId);
 
   Future<void> repostTx(int txId, {required bool fluff}) =>
-      bridge.walletRepostTx(txId: txId, fluff: fluff);
+      bridge.walletRepostTx(handle: _openHandle, txId: txId, fluff: fluff);
 
   Future<ScanResultModel> scan({
     required bool deleteUnconfirmed,
     int? startHeight,
     int? backwardsFromTip,
   }) async {
-    final raw = await bridge.walletScan(
+    final dto = await bridge.walletScan(
+      handle: _openHandle,
       deleteUnconfirmed: deleteUnconfirmed,
       startHeight: startHeight == null ? null : BigInt.from(startHeight),
       backwardsFromTip: backwardsFromTip == null ? null : BigInt.from(backwardsFromTip),
     );
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return ScanResultModel.fromJson(data);
+    return ScanResultModel.fromDto(dto);
   }
 
   Future<List<AccountModel>> fetchAccounts() async {
-    final raw = await bridge.walletListAccounts();
-    return _parseList(raw, AccountModel.fromJson);
+    final list = await bridge.walletListAccounts(handle: _openHandle);
+    return list.map(AccountModel.fromDto).toList();
   }
 
   Future<AccountModel> createAccount(String label) async {
-    final raw = await bridge.walletCreateAccount(label: label);
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return AccountModel.fromJson(data);
+    final dto = await bridge.walletCreateAccount(handle: _openHandle, label: label);
+    return AccountModel.fromDto(dto);
   }
 
   Future<AccountModel> setActiveAccount(String label) async {
-    final raw = await bridge.walletSetActiveAccount(label: label);
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return AccountModel.fromJson(data);
+    final dto = await bridge.walletSetActiveAccount(handle: _openHandle, label: label);
+    return AccountModel.fromDto(dto);
   }
 
-  Future<String> activeAccount() => bridge.walletActiveAccount();
+  Future<String> activeAccount() => bridge.walletActiveAccount(handle: _openHandle);
 
   Future<PaymentProofModel> fetchPaymentProof(int txId) async {
-    final raw = await bridge.walletPaymentProof(txId: txId);
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return PaymentProofModel.fromJson(data, raw: raw);
+    final dto = await bridge.walletPaymentProof(handle: _openHandle, txId: txId);
+    return PaymentProofModel.fromDto(dto);
   }
 
   Future<PaymentProofVerification> verifyPaymentProof(String payload) async {
-    final raw = await bridge.walletVerifyPaymentProof(payload: payload);
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return PaymentProofVerification.fromJson(data);
+    final dto = await bridge.walletVerifyPaymentProof(handle: _openHandle, payload: payload);
+    return PaymentProofVerification.fromDto(dto);
   }
 
-  List<T> _parseList<T>(
-    String raw,
-    T Function(Map<String, dynamic> json) mapper,
-  ) {
-    final list = jsonDecode(raw) as List<dynamic>;
-    return list
-        .map((item) => mapper(Map<String, dynamic>.from(item as Map)))
-        .toList();
-  }
+  // --- Slatepacks ---
+  Future<String> sendSlatepack(
+    String to,
+    BigInt amountNano, {
+    SlatepackFileTarget? outputFile,
+  }) =>
+      bridge.walletSendSlatepack(
+        handle: _openHandle,
+        to: to,
+        amountNano: amountNano,
+        options: const SendOptions(),
+        outputFile: outputFile,
+      );
+
+  Future<String> issueInvoice(BigInt amountNano) =>
+      bridge.walletIssueInvoice(handle: _openHandle, amountNano: amountNano);
+
+  Future<String> receiveSlatepack(String message, {SlatepackFileTarget? outputFile}) =>
+      bridge.walletReceiveSlatepack(
+        handle: _openHandle,
+        message: message,
+        outputFile: outputFile,
+      );
+
+  Future<String> processInvoice(String message, {SlatepackFileTarget? outputFile}) =>
+      bridge.walletProcessInvoice(
+        handle: _openHandle,
+        message: message,
+        options: const SendOptions(),
+        outputFile: outputFile,
+      );
+
+  Future<String> finalizeSlatepack(
+    String message, {
+    required bool fluff,
+    SlatepackFileTarget? outputFile,
+  }) =>
+      bridge.walletFinalizeSlatepack(
+        handle: _openHandle,
+        message: message,
+        postTx: true,
+        fluff: fluff,
+        outputFile: outputFile,
+      );
+
+  Future<SlateInspectionDto> inspectSlatepack(String message) =>
+      bridge.walletInspectSlatepack(handle: _openHandle, message: message);
+
+  Future<String> transactionSlatepack(int txId) =>
+      bridge.walletTransactionSlatepack(handle: _openHandle, txId: txId);
 
   // --- Tor service ---
   Future<TorStatusModel> torStatus() async {
-    final raw = await bridge.torStatus();
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return TorStatusModel.fromJson(data);
+    final dto = await bridge.torStatus(handle: _openHandle);
+    return TorStatusModel.fromDto(dto);
   }
 
-  Future<TorStatusModel> torStart({String listenAddr = '127.0.0.1:3415'}) async {
-    final raw = await bridge.torStart(listenAddr: listenAddr);
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return TorStatusModel.fromJson(data);
+  Future<TorStatusModel> torStart({String? listenAddr}) async {
+    final handle = _openHandle;
+    final addr = listenAddr ?? await bridge.foreignListenerAddr(handle: handle);
+    final dto = await bridge.torStart(handle: handle, listenAddr: addr);
+    return TorStatusModel.fromDto(dto);
   }
 
-  Future<void> torStop() => bridge.torStop();
+  Future<void> torStop() => bridge.torStop(handle: _openHandle);
+
+  Future<String> foreignListenerAddr() => bridge.foreignListenerAddr(handle: _openHandle);
 
   Future<OwnerListenerStatusModel> fetchOwnerListenerStatus() async {
-    final raw = await bridge.ownerListenerStatus();
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return OwnerListenerStatusModel.fromJson(data);
+    final dto = await bridge.ownerListenerStatus(handle: _openHandle);
+    return OwnerListenerStatusModel.fromDto(dto);
   }
 
   Future<OwnerListenerStatusModel> startOwnerListener() async {
-    final raw = await bridge.ownerListenerStart();
-    final Map<String, dynamic> data = jsonDecode(raw) as Map<String, dynamic>;
-    return OwnerListenerStatusModel.fromJson(data);
+    final dto = await bridge.ownerListenerStart(handle: _openHandle);
+    return OwnerListenerStatusModel.fromDto(dto);
   }
 }
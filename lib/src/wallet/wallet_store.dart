@@ -11,6 +11,8 @@ class WalletStore extends ChangeNotifier {
 
   final WalletService _service;
 
+  WalletService get service => _service;
+
   WalletInfoModel? overview;
   List<TransactionModel> transactions = const [];
   List<OutputModel> outputs = const [];
@@ -51,6 +53,14 @@ class WalletStore extends ChangeNotifier {
     notifyListeners();
   }
 
+  Future<void> lock() async {
+    _autoRefresh?.cancel();
+    _autoRefresh = null;
+    unlocked = false;
+    notifyListeners();
+    await _service.closeWallet();
+  }
+
   Future<void> refreshAll() async {
     if (!unlocked) return;
     await Future.wait([
@@ -1,6 +1,6 @@
 // lib/test_support/fakes.dart
-import 'dart:convert';
-
+import 'package:grin_frb_win/src/rust/frb_generated.dart/models.dart';
+import 'package:grin_frb_win/src/rust/frb_generated.dart/wallet.dart';
 import 'package:grin_frb_win/src/wallet/wallet_service.dart';
 import 'package:grin_frb_win/src/wallet/wallet_store.dart';
 import 'package:grin_frb_win/src/wallet/models.dart';
@@ -11,6 +11,50 @@ class FakeWalletService extends WalletService {
   bool _torRunning = false;
   bool _ownerRunning = false;
   String _activeAccount = 'default';
+  bool _open = false;
+
+  @override
+  bool get isOpen => _open;
+
+  @override
+  Future<void> openWallet(String dataDir, String passphrase) async {
+    _open = true;
+  }
+
+  @override
+  Future<String> createWallet(
+    String dataDir,
+    String passphrase, {
+    WalletChainType chainType = WalletChainType.mainnet,
+  }) async {
+    _open = true;
+    return List.filled(24, 'abandon').join(' ');
+  }
+
+  @override
+  Future<RestoreResultDto> restoreWallet(
+    String dataDir,
+    String passphrase,
+    String phrase, {
+    WalletChainType chainType = WalletChainType.mainnet,
+  }) async {
+    _open = true;
+    return RestoreResultDto(handle: BigInt.one, completed: true);
+  }
+
+  @override
+  Future<String> seedPhrase(String dataDir, String passphrase) async =>
+      List.filled(24, 'abandon').join(' ');
+
+  @override
+  Future<void> closeWallet() async {
+    _open = false;
+  }
+
+  @override
+  Future<void> sync() async {
+    // no-op
+  }
 
   @override
   Future<void> setNodeUrl(String url) async {
@@ -51,27 +95,20 @@ class FakeWalletService extends WalletService {
   Future<List<TransactionModel>> fetchTransactions({
     required bool refreshFromNode,
   }) async {
-    final tx = TransactionModel.fromJson({
-      'id': 1,
-      'txSlateId': null,
-      'txType': 'received',
-      'status': 'confirmed',
-      'direction': 'received',
-      'creationTs': DateTime.now()
-          .subtract(const Duration(days: 1))
-          .toIso8601String(),
-      'confirmationTs': DateTime.now().toIso8601String(),
-      'confirmed': true,
-      'amount': '100000000',
-      'fee': null,
-      'numInputs': 0,
-      'numOutputs': 1,
-      'hasProof': false,
-      'kernelExcess': null,
-      'ttlCutoffHeight': null,
-      'revertedAfterSecs': null,
-      'confirmations': 12,
-    });
+    final tx = TransactionModel(
+      id: 1,
+      txType: 'received',
+      status: 'confirmed',
+      direction: 'received',
+      creationTime: DateTime.now().subtract(const Duration(days: 1)),
+      confirmationTime: DateTime.now(),
+      confirmed: true,
+      amount: BigInt.from(100000000),
+      inputs: 0,
+      outputs: 1,
+      hasProof: false,
+      confirmations: 12,
+    );
     return [tx];
   }
 
@@ -80,18 +117,18 @@ class FakeWalletService extends WalletService {
     required bool includeSpent,
     required bool refreshFromNode,
   }) async {
-    final out = OutputModel.fromJson({
-      'commitment': '08abc...',
-      'value': '100000000',
-      'status': 'unspent',
-      'height': 123400,
-      'lockHeight': 0,
-      'isCoinbase': false,
-      'mmrIndex': 1,
-      'txLogId': 1,
-      'confirmations': 50,
-      'spendable': true,
-    });
+    final out = OutputModel(
+      commitment: '08abc...',
+      value: BigInt.from(100000000),
+      status: 'unspent',
+      height: 123400,
+      lockHeight: 0,
+      isCoinbase: false,
+      mmrIndex: 1,
+      txLogId: 1,
+      confirmations: 50,
+      spendable: true,
+    );
     return [out];
   }
 
@@ -122,37 +159,37 @@ class FakeWalletService extends WalletService {
   @override
   Future<List<AccountModel>> fetchAccounts() async {
     return <AccountModel>[
-      AccountModel.fromJson({
-        'label': 'default',
-        'path': 'm/0',
-        'isActive': _activeAccount == 'default',
-      }),
-      AccountModel.fromJson({
-        'label': 'savings',
-        'path': 'm/1',
-        'isActive': _activeAccount == 'savings',
-      }),
+      AccountModel(
+        label: 'default',
+        path: 'm/0',
+        isActive: _activeAccount == 'default',
+      ),
+      AccountModel(
+        label: 'savings',
+        path: 'm/1',
+        isActive: _activeAccount == 'savings',
+      ),
     ];
   }
 
   @override
   Future<AccountModel> createAccount(String label) async {
     _activeAccount = label;
-    return AccountModel.fromJson({
-      'label': _activeAccount,
-      'path': 'm/9',
-      'isActive': true,
-    });
+    return AccountModel(
+      label: _activeAccount,
+      path: 'm/9',
+      isActive: true,
+    );
   }
 
   @override
   Future<AccountModel> setActiveAccount(String label) async {
     _activeAccount = label;
-    return AccountModel.fromJson({
-      'label': _activeAccount,
-      'path': 'm/0',
-      'isActive': true,
-    });
+    return AccountModel(
+      label: _activeAccount,
+      path: 'm/0',
+      isActive: true,
+    );
   }
 
   @override
@@ -160,19 +197,16 @@ class FakeWalletService extends WalletService {
 
   @override
   Future<PaymentProofModel> fetchPaymentProof(int txId) async {
-    final proofMap = {
-      'txId': txId,
-      'proof': {
-        'receiver': 'grin1qq...',
-        'amount': '100000000',
-        'height': 123456,
-      },
-    };
-    final raw = jsonEncode(proofMap);
-    return PaymentProofModel.fromJson(
-      Map<String, dynamic>.from(proofMap),
-      raw: raw,
-    );
+    return PaymentProofModel.fromDto(PaymentProofDto(
+      txId: txId,
+      amount: BigInt.from(100000000),
+      excess: '08def...',
+      recipientAddress: 'grin1qq...',
+      recipientSig: 'sig...',
+      senderAddress: 'grin1ss...',
+      senderSig: 'sig...',
+      proofJson: '{"receiver":"grin1qq...","amount":"100000000","height":123456}',
+    ));
   }
 
   @override
@@ -184,6 +218,51 @@ class FakeWalletService extends WalletService {
     );
   }
 
+  // --- Slatepacks ---
+
+  @override
+  Future<String> sendSlatepack(
+    String to,
+    BigInt amountNano, {
+    SlatepackFileTarget? outputFile,
+  }) async =>
+      'BEGINSLATEPACK. fake send. ENDSLATEPACK.';
+
+  @override
+  Future<String> issueInvoice(BigInt amountNano) async =>
+      'BEGINSLATEPACK. fake invoice. ENDSLATEPACK.';
+
+  @override
+  Future<String> receiveSlatepack(String message, {SlatepackFileTarget? outputFile}) async =>
+      'BEGINSLATEPACK. fake response. ENDSLATEPACK.';
+
+  @override
+  Future<String> processInvoice(String message, {SlatepackFileTarget? outputFile}) async =>
+      'BEGINSLATEPACK. fake invoice response. ENDSLATEPACK.';
+
+  @override
+  Future<String> finalizeSlatepack(
+    String message, {
+    required bool fluff,
+    SlatepackFileTarget? outputFile,
+  }) async =>
+      'BEGINSLATEPACK. fake finalized. ENDSLATEPACK.';
+
+  @override
+  Future<SlateInspectionDto> inspectSlatepack(String message) async =>
+      SlateInspectionDto(
+        code: SlateCode.s1,
+        slateId: '00000000-0000-0000-0000-000000000001',
+        state: 'Standard1',
+        amount: BigInt.from(100000000),
+        fee: BigInt.from(7000000),
+        numParticipants: 2,
+      );
+
+  @override
+  Future<String> transactionSlatepack(int txId) async =>
+      'BEGINSLATEPACK. fake tx $txId. ENDSLATEPACK.';
+
   // --- Tor service ---
 
   @override
@@ -196,7 +275,7 @@ class FakeWalletService extends WalletService {
     }
 
   @override
-  Future<TorStatusModel> torStart({String listenAddr = '127.0.0.1:3415'}) async {
+  Future<TorStatusModel> torStart({String? listenAddr}) async {
     _torRunning = true;
     return TorStatusModel(
       running: true,
@@ -210,6 +289,9 @@ class FakeWalletService extends WalletService {
     _torRunning = false;
   }
 
+  @override
+  Future<String> foreignListenerAddr() async => '127.0.0.1:3415';
+
   @override
   Future<OwnerListenerStatusModel> fetchOwnerListenerStatus() async {
     return OwnerListenerStatusModel(
@@ -1,27 +1,57 @@
-use crate::wallet;
-use anyhow::{anyhow, Result};
+use crate::cancel::{self, CancelToken};
+use crate::error::BridgeError;
+use crate::events::{self, WalletEventDto};
+use crate::frb_generated::StreamSink;
+use crate::i18n::{self, tr, BridgeLocale, Msg};
+use crate::models::{
+    AccountDto, CreatedWallet, DirectSendResultDto, OutputDto, OwnerListenerStatusDto,
+    PaymentProofDto, PaymentProofVerificationDto, PayoutItem, PayoutReportDto,
+    ProcessSlatepackOptions, ProcessedSlatepackDto, QrScanProgressDto, RestoreResultDto,
+    ScanResultDto, SendEstimateDto, SendOptions, SlateExpectation, SlateInspectionDto,
+    SlateReviewDto, SlatepackFileDto, SlatepackFileTarget, TorStatusDto, TransactionDto,
+    TxMetadataDto, UpdaterStatusDto, WalletCloseReportDto, WalletInfoDto,
+};
+use crate::qr::{self, QrSession};
+use crate::slatepack_file;
+use crate::wallet::{self, WalletChainType, WalletHandle};
 use flutter_rust_bridge::frb;
 
 #[frb]
-pub fn set_node_url(url: String) -> Result<()> {
+pub fn set_node_url(url: String) -> Result<(), BridgeError> {
     wallet::update_node_url(&url)?;
     Ok(())
 }
 
 #[frb]
-pub fn get_node_url() -> Result<String> {
-    wallet::current_node_url()
+pub fn get_node_url() -> Result<String, BridgeError> {
+    Ok(wallet::current_node_url()?)
 }
 
+/// Sprache fuer Fehler- und Statusmeldungen der Bridge; die App ruft das beim Start und bei
+/// jedem Wechsel im `LocaleStore` auf.
 #[frb]
-pub async fn get_node_tip() -> Result<u64> {
+pub fn set_locale(locale: BridgeLocale) {
+    i18n::set_locale(locale);
+}
+
+#[frb]
+pub fn get_locale() -> BridgeLocale {
+    i18n::current_locale()
+}
+
+#[frb]
+pub async fn get_node_tip() -> Result<u64, BridgeError> {
     run_blocking(|| wallet::node_tip()).await
 }
 
 #[frb]
-pub async fn wallet_init_or_open(data_dir: String, passphrase: String) -> Result<()> {
+pub async fn wallet_init_or_open(
+    data_dir: String,
+    passphrase: String,
+    chain_type: Option<WalletChainType>,
+) -> Result<WalletHandle, BridgeError> {
     let dir = data_dir.trim().to_string();
-    run_blocking(move || wallet::init_or_open(&dir, &passphrase)).await
+    run_blocking(move || wallet::init_or_open(&dir, &passphrase, chain_type)).await
 }
 
 #[frb]
@@ -29,13 +59,18 @@ pub async fn wallet_create(
     data_dir: String,
     passphrase: String,
     mnemonic_length: usize,
-) -> Result<String> {
+    chain_type: WalletChainType,
+) -> Result<CreatedWallet, BridgeError> {
     let dir = data_dir.trim().to_string();
-    run_blocking(move || wallet::create_wallet(&dir, &passphrase, mnemonic_length)).await
+    run_blocking(move || wallet::create_wallet(&dir, &passphrase, mnemonic_length, chain_type))
+        .await
 }
 
 #[frb]
-pub async fn wallet_seed_phrase(data_dir: String, passphrase: String) -> Result<String> {
+pub async fn wallet_seed_phrase(
+    data_dir: String,
+    passphrase: String,
+) -> Result<String, BridgeError> {
     let dir = data_dir.trim().to_string();
     run_blocking(move || wallet::seed_phrase(&dir, &passphrase)).await
 }
@@ -45,171 +80,490 @@ pub async fn wallet_restore_from_seed(
     data_dir: String,
     passphrase: String,
     phrase: String,
-) -> Result<()> {
+    chain_type: WalletChainType,
+    cancel_token: Option<CancelToken>,
+) -> Result<RestoreResultDto, BridgeError> {
     let dir = data_dir.trim().to_string();
     let seed = phrase.trim().to_string();
-    run_blocking(move || wallet::restore_wallet_from_seed(&dir, &passphrase, &seed)).await
+    run_blocking(move || {
+        wallet::restore_wallet_from_seed(&dir, &passphrase, &seed, chain_type, cancel_token)
+    })
+    .await
+}
+
+/// Neues Token fuer `wallet_scan`, `wallet_resume_scan` oder `wallet_restore_from_seed`.
+#[frb]
+pub fn create_cancel_token() -> CancelToken {
+    cancel::create()
 }
 
 #[frb]
-pub async fn wallet_get_address() -> Result<String> {
-    run_blocking(|| wallet::slatepack_address()).await
+pub fn cancel_operation(token: CancelToken) -> bool {
+    cancel::cancel(token)
 }
 
 #[frb]
-pub async fn wallet_sync() -> Result<()> {
-    run_blocking(|| wallet::sync()).await
+pub async fn wallet_close(handle: WalletHandle) -> Result<WalletCloseReportDto, BridgeError> {
+    run_blocking(move || wallet::close_wallet(handle)).await
 }
 
 #[frb]
-pub async fn wallet_get_balance() -> Result<u64> {
-    run_blocking(|| wallet::balance()).await
+pub async fn wallet_set_auto_lock(
+    handle: WalletHandle,
+    timeout_secs: u64,
+) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::set_auto_lock(handle, timeout_secs)).await
 }
 
+/// Standard-TTL in Bloecken fuer neue Sends und Rechnungen; 0 schaltet ihn ab. Bleibt gespeichert.
+/// Abgelaufene Txs storniert der Updater (`wallet_start_updater`) beim Refresh.
 #[frb]
-pub async fn wallet_send_slatepack(to: String, amount_nano: u64) -> Result<String> {
+pub async fn wallet_set_default_ttl(
+    handle: WalletHandle,
+    ttl_blocks: u64,
+) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::set_default_ttl(handle, ttl_blocks)).await
+}
+
+#[frb]
+pub fn wallet_events(sink: StreamSink<WalletEventDto>) -> Result<(), BridgeError> {
+    events::subscribe(sink);
+    wallet::prime_wallet_events();
+    Ok(())
+}
+
+#[frb]
+pub async fn wallet_change_password(
+    handle: WalletHandle,
+    old_passphrase: String,
+    new_passphrase: String,
+) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::change_password(handle, &old_passphrase, &new_passphrase)).await
+}
+
+#[frb]
+pub async fn wallet_get_address(handle: WalletHandle) -> Result<String, BridgeError> {
+    run_blocking(move || wallet::slatepack_address(handle)).await
+}
+
+#[frb]
+pub async fn wallet_sync(handle: WalletHandle) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::sync(handle)).await
+}
+
+#[frb]
+pub async fn wallet_start_updater(
+    handle: WalletHandle,
+    interval_secs: u64,
+) -> Result<UpdaterStatusDto, BridgeError> {
+    run_blocking(move || wallet::start_updater(handle, interval_secs)).await
+}
+
+#[frb]
+pub async fn wallet_stop_updater(handle: WalletHandle) -> Result<UpdaterStatusDto, BridgeError> {
+    run_blocking(move || wallet::stop_updater(handle)).await
+}
+
+#[frb]
+pub async fn wallet_updater_status(handle: WalletHandle) -> Result<UpdaterStatusDto, BridgeError> {
+    run_blocking(move || wallet::updater_status(handle)).await
+}
+
+#[frb]
+pub async fn wallet_get_balance(handle: WalletHandle) -> Result<u64, BridgeError> {
+    run_blocking(move || wallet::balance(handle)).await
+}
+
+#[frb]
+pub async fn wallet_send_slatepack(
+    handle: WalletHandle,
+    to: String,
+    amount_nano: u64,
+    options: SendOptions,
+    output_file: Option<SlatepackFileTarget>,
+) -> Result<String, BridgeError> {
     let recipient = to.trim().to_string();
-    run_blocking(move || wallet::send_slatepack(&recipient, amount_nano)).await
+    run_blocking(move || {
+        slatepack_file::with_output(output_file, || {
+            wallet::send_slatepack(handle, &recipient, amount_nano, options)
+        })
+    })
+    .await
 }
 
 #[frb]
-pub async fn wallet_issue_invoice(amount_nano: u64) -> Result<String> {
-    run_blocking(move || wallet::issue_invoice(amount_nano)).await
+pub async fn wallet_send_via_tor(
+    handle: WalletHandle,
+    address: String,
+    amount_nano: u64,
+    options: SendOptions,
+) -> Result<DirectSendResultDto, BridgeError> {
+    run_blocking(move || wallet::send_via_tor(handle, &address, amount_nano, options)).await
 }
 
 #[frb]
-pub async fn wallet_receive_slatepack(message: String) -> Result<String> {
-    run_blocking(move || wallet::receive_slatepack(&message)).await
+pub async fn wallet_send_batch(
+    handle: WalletHandle,
+    items: Vec<PayoutItem>,
+    via_tor: bool,
+    options: SendOptions,
+) -> Result<PayoutReportDto, BridgeError> {
+    run_blocking(move || wallet::send_batch(handle, items, via_tor, options)).await
 }
 
 #[frb]
-pub async fn wallet_process_invoice(message: String) -> Result<String> {
-    run_blocking(move || wallet::process_invoice(&message)).await
+pub async fn wallet_send_via_http(
+    handle: WalletHandle,
+    url: String,
+    amount_nano: u64,
+    options: SendOptions,
+) -> Result<DirectSendResultDto, BridgeError> {
+    run_blocking(move || wallet::send_via_http(handle, &url, amount_nano, options)).await
 }
 
 #[frb]
-pub async fn wallet_inspect_slatepack(message: String) -> Result<String> {
-    run_blocking(move || wallet::inspect_slatepack(&message)).await
+pub async fn wallet_estimate_send(
+    handle: WalletHandle,
+    amount_nano: u64,
+    options: SendOptions,
+) -> Result<SendEstimateDto, BridgeError> {
+    run_blocking(move || wallet::estimate_send(handle, amount_nano, options)).await
+}
+
+#[frb]
+pub async fn wallet_issue_invoice(
+    handle: WalletHandle,
+    amount_nano: u64,
+) -> Result<String, BridgeError> {
+    run_blocking(move || wallet::issue_invoice(handle, amount_nano)).await
+}
+
+#[frb]
+pub async fn wallet_receive_slatepack(
+    handle: WalletHandle,
+    message: String,
+    output_file: Option<SlatepackFileTarget>,
+) -> Result<String, BridgeError> {
+    run_blocking(move || {
+        slatepack_file::with_output(output_file, || wallet::receive_slatepack(handle, &message))
+    })
+    .await
+}
+
+#[frb]
+pub async fn wallet_process_invoice(
+    handle: WalletHandle,
+    message: String,
+    options: SendOptions,
+    output_file: Option<SlatepackFileTarget>,
+) -> Result<String, BridgeError> {
+    run_blocking(move || {
+        slatepack_file::with_output(output_file, || {
+            wallet::process_invoice(handle, &message, options)
+        })
+    })
+    .await
+}
+
+/// Zerlegt ein Slatepack in Frames fuer einen animierten QR-Code.
+#[frb]
+pub fn qr_encode_slatepack(
+    message: String,
+    max_chars_per_frame: Option<u32>,
+) -> Result<Vec<String>, BridgeError> {
+    Ok(qr::encode(&message, max_chars_per_frame)?)
+}
+
+#[frb]
+pub fn qr_create_scan_session() -> QrSession {
+    qr::create_session()
+}
+
+#[frb]
+pub fn qr_add_frame(session: QrSession, frame: String) -> Result<QrScanProgressDto, BridgeError> {
+    Ok(qr::add_frame(session, &frame)?)
+}
+
+#[frb]
+pub fn qr_release_scan_session(session: QrSession) {
+    qr::release_session(session)
+}
+
+/// Schreibt ein ausgehendes Slatepack (Send, Antwort, Rechnung, finalisiert) in eine Datei.
+/// Ohne `overwrite` schlaegt das Schreiben fehl, wenn die Datei schon existiert.
+#[frb]
+pub async fn write_slatepack_file(
+    message: String,
+    path: String,
+    binary: bool,
+    overwrite: bool,
+) -> Result<SlatepackFileDto, BridgeError> {
+    run_blocking(move || slatepack_file::write(&message, &path, binary, overwrite)).await
+}
+
+/// Liest eine armored oder binaere Slatepack-Datei; das Ergebnis geht an die normalen
+/// Slatepack-Funktionen.
+#[frb]
+pub async fn read_slatepack_file(path: String) -> Result<String, BridgeError> {
+    run_blocking(move || slatepack_file::read(&path)).await
+}
+
+#[frb]
+pub async fn wallet_inspect_slatepack(
+    handle: WalletHandle,
+    message: String,
+) -> Result<SlateInspectionDto, BridgeError> {
+    run_blocking(move || wallet::inspect_slatepack(handle, &message)).await
 }
 
 #[frb]
 pub async fn wallet_finalize_slatepack(
+    handle: WalletHandle,
     message: String,
     post_tx: bool,
     fluff: bool,
-) -> Result<String> {
-    run_blocking(move || wallet::finalize_slatepack(&message, post_tx, fluff)).await
+    output_file: Option<SlatepackFileTarget>,
+) -> Result<String, BridgeError> {
+    run_blocking(move || {
+        slatepack_file::with_output(output_file, || {
+            wallet::finalize_slatepack(handle, &message, post_tx, fluff)
+        })
+    })
+    .await
+}
+
+#[frb]
+pub async fn wallet_tx_metadata(
+    handle: WalletHandle,
+    parent_key_id: String,
+    tx_id: u32,
+) -> Result<Option<TxMetadataDto>, BridgeError> {
+    run_blocking(move || wallet::tx_metadata(handle, &parent_key_id, tx_id)).await
+}
+
+#[frb]
+pub async fn wallet_set_tx_metadata(
+    handle: WalletHandle,
+    parent_key_id: String,
+    tx_id: u32,
+    metadata: TxMetadataDto,
+) -> Result<Option<TxMetadataDto>, BridgeError> {
+    run_blocking(move || wallet::set_tx_metadata(handle, &parent_key_id, tx_id, metadata)).await
+}
+
+#[frb]
+pub async fn wallet_set_require_review(
+    handle: WalletHandle,
+    required: bool,
+) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::set_require_review(handle, required)).await
+}
+
+#[frb]
+pub async fn wallet_review_slatepack(
+    handle: WalletHandle,
+    message: String,
+    expectation: SlateExpectation,
+    options: SendOptions,
+) -> Result<SlateReviewDto, BridgeError> {
+    run_blocking(move || wallet::review_slatepack(handle, &message, expectation, options)).await
 }
 
 #[frb]
-pub async fn wallet_info() -> Result<String> {
-    run_blocking(|| wallet::wallet_info()).await
+pub async fn wallet_approve_slatepack(
+    handle: WalletHandle,
+    slate_id: String,
+    options: SendOptions,
+) -> Result<String, BridgeError> {
+    run_blocking(move || wallet::approve_slatepack(handle, &slate_id, options)).await
 }
 
 #[frb]
-pub async fn wallet_list_transactions(refresh_from_node: bool) -> Result<String> {
-    run_blocking(move || wallet::list_transactions(refresh_from_node)).await
+pub async fn wallet_reject_slatepack(
+    handle: WalletHandle,
+    slate_id: String,
+) -> Result<bool, BridgeError> {
+    run_blocking(move || wallet::reject_slatepack(handle, &slate_id)).await
 }
 
+/// Ein Einstieg fuer jedes eingefuegte Slatepack; leitet je nach Slate-Status weiter.
 #[frb]
-pub async fn wallet_list_outputs(include_spent: bool, refresh_from_node: bool) -> Result<String> {
-    run_blocking(move || wallet::list_outputs(include_spent, refresh_from_node)).await
+pub async fn wallet_process_slatepack(
+    handle: WalletHandle,
+    message: String,
+    options: ProcessSlatepackOptions,
+) -> Result<ProcessedSlatepackDto, BridgeError> {
+    run_blocking(move || wallet::process_slatepack(handle, &message, options)).await
 }
 
 #[frb]
-pub async fn wallet_cancel_tx(tx_id: u32) -> Result<()> {
-    run_blocking(move || wallet::cancel_tx(tx_id)).await
+pub async fn wallet_info(handle: WalletHandle) -> Result<WalletInfoDto, BridgeError> {
+    run_blocking(move || wallet::wallet_info(handle)).await
 }
 
 #[frb]
-pub async fn wallet_repost_tx(tx_id: u32, fluff: bool) -> Result<()> {
-    run_blocking(move || wallet::repost_tx(tx_id, fluff)).await
+pub async fn wallet_list_transactions(
+    handle: WalletHandle,
+    refresh_from_node: bool,
+) -> Result<Vec<TransactionDto>, BridgeError> {
+    run_blocking(move || wallet::list_transactions(handle, refresh_from_node)).await
+}
+
+#[frb]
+pub async fn wallet_list_outputs(
+    handle: WalletHandle,
+    include_spent: bool,
+    refresh_from_node: bool,
+) -> Result<Vec<OutputDto>, BridgeError> {
+    run_blocking(move || wallet::list_outputs(handle, include_spent, refresh_from_node)).await
+}
+
+#[frb]
+pub async fn wallet_cancel_tx(handle: WalletHandle, tx_id: u32) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::cancel_tx(handle, tx_id)).await
+}
+
+#[frb]
+pub async fn wallet_repost_tx(
+    handle: WalletHandle,
+    tx_id: u32,
+    fluff: bool,
+) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::repost_tx(handle, tx_id, fluff)).await
 }
 
 #[frb]
 pub async fn wallet_scan(
+    handle: WalletHandle,
     delete_unconfirmed: bool,
     start_height: Option<u64>,
     backwards_from_tip: Option<u64>,
-) -> Result<String> {
-    run_blocking(move || wallet::scan(delete_unconfirmed, start_height, backwards_from_tip)).await
+    cancel_token: Option<CancelToken>,
+) -> Result<ScanResultDto, BridgeError> {
+    run_blocking(move || {
+        wallet::scan(
+            handle,
+            delete_unconfirmed,
+            start_height,
+            backwards_from_tip,
+            cancel_token,
+        )
+    })
+    .await
 }
 
 #[frb]
-pub async fn wallet_list_accounts() -> Result<String> {
-    run_blocking(|| wallet::list_accounts()).await
+pub async fn wallet_resume_scan(
+    handle: WalletHandle,
+    cancel_token: Option<CancelToken>,
+) -> Result<ScanResultDto, BridgeError> {
+    run_blocking(move || wallet::resume_scan(handle, cancel_token)).await
 }
 
 #[frb]
-pub async fn wallet_create_account(label: String) -> Result<String> {
-    run_blocking(move || wallet::create_account(&label)).await
+pub async fn wallet_scan_resume_height(handle: WalletHandle) -> Result<Option<u64>, BridgeError> {
+    run_blocking(move || wallet::scan_resume_height(handle)).await
 }
 
 #[frb]
-pub async fn wallet_set_active_account(label: String) -> Result<String> {
-    run_blocking(move || wallet::set_active_account(&label)).await
+pub async fn wallet_list_accounts(handle: WalletHandle) -> Result<Vec<AccountDto>, BridgeError> {
+    run_blocking(move || wallet::list_accounts(handle)).await
 }
 
 #[frb]
-pub async fn wallet_active_account() -> Result<String> {
-    run_blocking(|| wallet::active_account()).await
+pub async fn wallet_create_account(
+    handle: WalletHandle,
+    label: String,
+) -> Result<AccountDto, BridgeError> {
+    run_blocking(move || wallet::create_account(handle, &label)).await
 }
 
 #[frb]
-pub async fn wallet_payment_proof(tx_id: u32) -> Result<String> {
-    run_blocking(move || wallet::payment_proof(tx_id)).await
+pub async fn wallet_set_active_account(
+    handle: WalletHandle,
+    label: String,
+) -> Result<AccountDto, BridgeError> {
+    run_blocking(move || wallet::set_active_account(handle, &label)).await
 }
 
 #[frb]
-pub async fn wallet_transaction_slatepack(tx_id: u32) -> Result<String> {
-    run_blocking(move || wallet::transaction_slatepack(tx_id)).await
+pub async fn wallet_active_account(handle: WalletHandle) -> Result<String, BridgeError> {
+    run_blocking(move || wallet::active_account(handle)).await
 }
 
 #[frb]
-pub async fn wallet_verify_payment_proof(payload: String) -> Result<String> {
-    run_blocking(move || wallet::verify_payment_proof(&payload)).await
+pub async fn wallet_payment_proof(
+    handle: WalletHandle,
+    tx_id: u32,
+) -> Result<PaymentProofDto, BridgeError> {
+    run_blocking(move || wallet::payment_proof(handle, tx_id)).await
+}
+
+#[frb]
+pub async fn wallet_transaction_slatepack(
+    handle: WalletHandle,
+    tx_id: u32,
+) -> Result<String, BridgeError> {
+    run_blocking(move || wallet::transaction_slatepack(handle, tx_id)).await
+}
+
+#[frb]
+pub async fn wallet_verify_payment_proof(
+    handle: WalletHandle,
+    payload: String,
+) -> Result<PaymentProofVerificationDto, BridgeError> {
+    run_blocking(move || wallet::verify_payment_proof(handle, &payload)).await
 }
 
 // --- Tor service ---
 
 #[frb]
-pub async fn tor_status() -> Result<String> {
-    run_blocking(|| wallet::tor_status()).await
+pub async fn tor_status(handle: WalletHandle) -> Result<TorStatusDto, BridgeError> {
+    run_blocking(move || wallet::tor_status(handle)).await
 }
 
 #[frb]
-pub async fn tor_start(listen_addr: String) -> Result<String> {
+pub async fn tor_start(
+    handle: WalletHandle,
+    listen_addr: String,
+) -> Result<TorStatusDto, BridgeError> {
     let addr = listen_addr.trim().to_string();
-    let a = if addr.is_empty() {
-        "127.0.0.1:3415".to_string()
-    } else {
-        addr
-    };
-    run_blocking(move || wallet::tor_start(&a)).await
+    run_blocking(move || wallet::tor_start(handle, &addr)).await
 }
 
 #[frb]
-pub async fn tor_stop() -> Result<()> {
-    run_blocking(|| wallet::tor_stop()).await
+pub async fn tor_stop(handle: WalletHandle) -> Result<(), BridgeError> {
+    run_blocking(move || wallet::tor_stop(handle)).await
 }
 
 #[frb]
-pub async fn owner_listener_status() -> Result<String> {
-    run_blocking(|| wallet::owner_listener_status()).await
+pub async fn foreign_listener_addr(handle: WalletHandle) -> Result<String, BridgeError> {
+    run_blocking(move || wallet::foreign_listener_addr(handle)).await
 }
 
 #[frb]
-pub async fn owner_listener_start() -> Result<String> {
-    run_blocking(|| wallet::owner_listener_start()).await
+pub async fn owner_listener_status(
+    handle: WalletHandle,
+) -> Result<OwnerListenerStatusDto, BridgeError> {
+    run_blocking(move || wallet::owner_listener_status(handle)).await
 }
 
+#[frb]
+pub async fn owner_listener_start(
+    handle: WalletHandle,
+) -> Result<OwnerListenerStatusDto, BridgeError> {
+    run_blocking(move || wallet::owner_listener_start(handle)).await
+}
 
-async fn run_blocking<F, T>(f: F) -> Result<T>
+async fn run_blocking<F, T>(f: F) -> Result<T, BridgeError>
 where
-    F: FnOnce() -> Result<T> + Send + 'static,
+    F: FnOnce() -> anyhow::Result<T> + Send + 'static,
     T: Send + 'static,
 {
-    tokio::task::spawn_blocking(f)
+    let result = tokio::task::spawn_blocking(f)
         .await
-        .map_err(|e| anyhow!("Hintergrund-Task fehlgeschlagen: {e}"))?
+        .map_err(|e| BridgeError::Internal {
+            detail: format!("{}: {e}", tr(Msg::BackgroundTask)),
+        })?;
+    Ok(result?)
 }
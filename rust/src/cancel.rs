@@ -0,0 +1,43 @@
+//! Abbruch-Tokens fuer lange Operationen. Dart holt sich vorher ein Token, gibt es der
+//! Operation mit und kann es aus einem anderen Aufruf heraus abbrechen.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+
+pub type CancelToken = u64;
+
+static TOKENS: Lazy<Mutex<HashMap<CancelToken, Arc<AtomicBool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_TOKEN: AtomicU64 = AtomicU64::new(1);
+
+pub fn create() -> CancelToken {
+    let token = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut tokens) = TOKENS.lock() {
+        tokens.insert(token, Arc::new(AtomicBool::new(false)));
+    }
+    token
+}
+
+/// Setzt das Abbruch-Flag; `false`, wenn das Token unbekannt oder schon freigegeben ist.
+pub fn cancel(token: CancelToken) -> bool {
+    match flag(token) {
+        Some(flag) => {
+            flag.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
+
+pub(crate) fn flag(token: CancelToken) -> Option<Arc<AtomicBool>> {
+    TOKENS.lock().ok()?.get(&token).cloned()
+}
+
+pub(crate) fn release(token: CancelToken) {
+    if let Ok(mut tokens) = TOKENS.lock() {
+        tokens.remove(&token);
+    }
+}
@@ -0,0 +1,158 @@
+//! Fehlertyp der Bridge. Dart bekommt eine Variante mit stabilem `code()` und die
+//! urspruengliche Meldung als `detail`, statt Fehlertexte vergleichen zu muessen.
+
+use std::fmt;
+
+use grin_wallet_libwallet::Error as LibwalletError;
+
+#[derive(Clone, Debug)]
+pub enum BridgeError {
+    /// Das Wallet-Verzeichnis enthaelt noch keinen Seed.
+    NotInitialized {
+        detail: String,
+    },
+    /// Zum Handle gibt es keine geoeffnete Wallet (geschlossen oder automatisch gesperrt).
+    WalletNotOpen {
+        detail: String,
+    },
+    WalletAlreadyOpen {
+        detail: String,
+    },
+    WrongPassword {
+        detail: String,
+    },
+    InsufficientFunds {
+        detail: String,
+    },
+    NodeUnreachable {
+        detail: String,
+    },
+    InvalidSlatepack {
+        detail: String,
+    },
+    InvalidAddress {
+        detail: String,
+    },
+    /// Ungueltiger Parameter (leerer Name, Betrag 0, falscher Chain-Typ, ...).
+    InvalidInput {
+        detail: String,
+    },
+    AccountError {
+        detail: String,
+    },
+    TransactionError {
+        detail: String,
+    },
+    PaymentProofError {
+        detail: String,
+    },
+    TorFailed {
+        detail: String,
+    },
+    ListenerFailed {
+        detail: String,
+    },
+    /// Ueber ein Abbruch-Token beendet; laesst sich in der Regel fortsetzen.
+    Cancelled {
+        detail: String,
+    },
+    Internal {
+        detail: String,
+    },
+}
+
+impl BridgeError {
+    /// Maschinenlesbarer Code; bleibt ueber Versionen gleich, auch wenn sich Texte aendern.
+    pub fn code(&self) -> String {
+        let code = match self {
+            BridgeError::NotInitialized { .. } => "not_initialized",
+            BridgeError::WalletNotOpen { .. } => "wallet_not_open",
+            BridgeError::WalletAlreadyOpen { .. } => "wallet_already_open",
+            BridgeError::WrongPassword { .. } => "wrong_password",
+            BridgeError::InsufficientFunds { .. } => "insufficient_funds",
+            BridgeError::NodeUnreachable { .. } => "node_unreachable",
+            BridgeError::InvalidSlatepack { .. } => "invalid_slatepack",
+            BridgeError::InvalidAddress { .. } => "invalid_address",
+            BridgeError::InvalidInput { .. } => "invalid_input",
+            BridgeError::AccountError { .. } => "account_error",
+            BridgeError::TransactionError { .. } => "transaction_error",
+            BridgeError::PaymentProofError { .. } => "payment_proof_error",
+            BridgeError::TorFailed { .. } => "tor_failed",
+            BridgeError::ListenerFailed { .. } => "listener_failed",
+            BridgeError::Cancelled { .. } => "cancelled",
+            BridgeError::Internal { .. } => "internal",
+        };
+        code.to_string()
+    }
+
+    pub fn detail(&self) -> String {
+        match self {
+            BridgeError::NotInitialized { detail }
+            | BridgeError::WalletNotOpen { detail }
+            | BridgeError::WalletAlreadyOpen { detail }
+            | BridgeError::WrongPassword { detail }
+            | BridgeError::InsufficientFunds { detail }
+            | BridgeError::NodeUnreachable { detail }
+            | BridgeError::InvalidSlatepack { detail }
+            | BridgeError::InvalidAddress { detail }
+            | BridgeError::InvalidInput { detail }
+            | BridgeError::AccountError { detail }
+            | BridgeError::TransactionError { detail }
+            | BridgeError::PaymentProofError { detail }
+            | BridgeError::TorFailed { detail }
+            | BridgeError::ListenerFailed { detail }
+            | BridgeError::Cancelled { detail }
+            | BridgeError::Internal { detail } => detail.clone(),
+        }
+    }
+
+    /// Ordnet einen libwallet-Fehler einer Variante zu; `detail` ist die komplette Meldung
+    /// inklusive Kontext aus der Bridge.
+    fn from_libwallet(err: &LibwalletError, detail: String) -> Self {
+        match err {
+            LibwalletError::NotEnoughFunds { .. } => BridgeError::InsufficientFunds { detail },
+            LibwalletError::ClientCallback(_) => BridgeError::NodeUnreachable { detail },
+            // Ungueltige Maske heisst gesperrt bzw. nicht geoeffnet; falsche Passwoerter beim
+            // Oeffnen ordnet `wallet::seed_password_error` zu.
+            LibwalletError::InvalidKeychainMask => BridgeError::WalletNotOpen { detail },
+            LibwalletError::SlatepackDeser(_)
+            | LibwalletError::SlatepackSer(_)
+            | LibwalletError::SlatepackDecryption(_) => BridgeError::InvalidSlatepack { detail },
+            LibwalletError::AccountLabelAlreadyExists(_)
+            | LibwalletError::UnknownAccountLabel(_) => BridgeError::AccountError { detail },
+            LibwalletError::TransactionDoesntExist(_)
+            | LibwalletError::TransactionNotCancellable(_)
+            | LibwalletError::TransactionWasCancelled(_)
+            | LibwalletError::TransactionExpired
+            | LibwalletError::StoredTx(_) => BridgeError::TransactionError { detail },
+            LibwalletError::PaymentProof(_) => BridgeError::PaymentProofError { detail },
+            LibwalletError::TorConfig(_) | LibwalletError::TorProcess(_) => {
+                BridgeError::TorFailed { detail }
+            }
+            _ => BridgeError::Internal { detail },
+        }
+    }
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.detail())
+    }
+}
+
+impl std::error::Error for BridgeError {}
+
+/// Sucht in der Ursachenkette zuerst nach einem bereits typisierten `BridgeError`, dann
+/// nach einem libwallet-Fehler. Alles andere landet als `Internal` bei Dart.
+impl From<anyhow::Error> for BridgeError {
+    fn from(err: anyhow::Error) -> Self {
+        if let Some(bridge) = err.chain().find_map(|c| c.downcast_ref::<BridgeError>()) {
+            return bridge.clone();
+        }
+        let detail = format!("{err:#}");
+        match err.chain().find_map(|c| c.downcast_ref::<LibwalletError>()) {
+            Some(wallet_err) => BridgeError::from_libwallet(wallet_err, detail),
+            None => BridgeError::Internal { detail },
+        }
+    }
+}
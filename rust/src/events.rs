@@ -0,0 +1,81 @@
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use crate::frb_generated::StreamSink;
+use crate::models::{
+    ScanProgressDto, ScanResultDto, TransactionDto, UpdaterMessageDto, WalletInfoDto,
+};
+use crate::wallet::WalletHandle;
+
+static SINKS: Lazy<Mutex<Vec<StreamSink<WalletEventDto>>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+#[derive(Clone, Debug)]
+pub enum WalletEventDto {
+    AutoLocked {
+        handle: WalletHandle,
+        idle_secs: u64,
+    },
+    /// Neue, noch unbestaetigte Eingangs-Tx (typischerweise ueber den Foreign Listener).
+    IncomingSlate {
+        handle: WalletHandle,
+        tx: TransactionDto,
+    },
+    ConfirmationsChanged {
+        handle: WalletHandle,
+        tx_id: u32,
+        confirmations: u64,
+    },
+    BalanceChanged {
+        handle: WalletHandle,
+        balance: WalletInfoDto,
+    },
+    TxReverted {
+        handle: WalletHandle,
+        tx_id: u32,
+    },
+    /// Tx wurde nach Ablauf ihres TTL storniert; bei Sends sind die Inputs wieder frei.
+    TxExpired {
+        handle: WalletHandle,
+        tx_id: u32,
+        tx_slate_id: Option<String>,
+        unlocked_amount: u64,
+        unlocked_inputs: usize,
+    },
+    ScanProgress {
+        handle: WalletHandle,
+        progress: ScanProgressDto,
+    },
+    ScanCompleted {
+        handle: WalletHandle,
+        result: ScanResultDto,
+    },
+    /// Fortschritt des Owner-Updaters oder eines Scans (Prozent, aktueller Schritt).
+    UpdaterProgress {
+        handle: WalletHandle,
+        message: UpdaterMessageDto,
+    },
+    /// Gilt fuer alle Wallets, da die Node-URL global ist.
+    NodeConnectivityChanged {
+        reachable: bool,
+        tip_height: Option<u64>,
+    },
+}
+
+pub fn subscribe(sink: StreamSink<WalletEventDto>) {
+    if let Ok(mut sinks) = SINKS.lock() {
+        sinks.push(sink);
+    }
+}
+
+/// Ohne Dart-Listener sparen sich Updater und Listener den Abgleich fuer Events.
+pub fn has_subscribers() -> bool {
+    SINKS.lock().map(|sinks| !sinks.is_empty()).unwrap_or(false)
+}
+
+/// Schickt ein Event an alle Dart-Listener; Sinks, deren Stream geschlossen wurde, fliegen raus.
+pub fn emit(event: &WalletEventDto) {
+    if let Ok(mut sinks) = SINKS.lock() {
+        sinks.retain(|sink| sink.add(event.clone()).is_ok());
+    }
+}
@@ -0,0 +1,101 @@
+//! Client fuer den Foreign API der Gegenseite (`check_version`, `receive_tx`). Ersetzt
+//! `HttpSlateSender`: der startet fuer Tor je Send einen eigenen Tor-Prozess und kennt kein
+//! Gesamt-Timeout. Hier laeuft Tor ueber den SOCKS-Port des Prozesses aus `tor_start`.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use grin_wallet_libwallet::{Slate, SlateVersion, VersionedSlate};
+use reqwest::blocking::Client;
+use serde_json::{json, Value};
+
+use crate::i18n::{tr, Msg};
+
+pub(crate) struct ForeignClient {
+    url: String,
+    client: Client,
+    timeout: Duration,
+}
+
+impl ForeignClient {
+    /// `base_url` ohne abschliessenden Slash, z.B. `https://pool.example`.
+    pub(crate) fn http(base_url: &str, timeout: Duration) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .build()
+            .with_context(|| tr(Msg::ForeignClientCreate))?;
+        Ok(ForeignClient {
+            url: format!("{base_url}/v2/foreign"),
+            client,
+            timeout,
+        })
+    }
+
+    /// `socks5h`, damit auch die Onion-Adresse erst im Tor-Netz aufgeloest wird.
+    pub(crate) fn tor(onion_url: &str, socks_addr: &str, timeout: Duration) -> Result<Self> {
+        let proxy = reqwest::Proxy::all(format!("socks5h://{socks_addr}"))
+            .with_context(|| tr(Msg::ForeignClientCreate))?;
+        let client = Client::builder()
+            .proxy(proxy)
+            .timeout(timeout)
+            .build()
+            .with_context(|| tr(Msg::ForeignClientCreate))?;
+        Ok(ForeignClient {
+            url: format!("{onion_url}/v2/foreign"),
+            client,
+            timeout,
+        })
+    }
+
+    /// Wie `HttpSlateSender::send_tx` ohne Finalisieren: erst die Slate-Version der Gegenseite
+    /// pruefen, dann S1 schicken und S2 zurueckbekommen.
+    pub(crate) fn send_tx(&self, slate: &Slate) -> Result<Slate> {
+        let versions = self.call("check_version", json!([]))?;
+        let supports_v4 = versions["supported_slate_versions"]
+            .as_array()
+            .map(|list| list.iter().any(|v| v.as_str() == Some("V4")))
+            .unwrap_or(false);
+        if !supports_v4 {
+            return Err(anyhow!(tr(Msg::SlateVersionUnsupported)));
+        }
+        let versioned = VersionedSlate::into_version(slate.clone(), SlateVersion::V4)?;
+        let returned = self.call("receive_tx", json!([versioned, null, null]))?;
+        let slate = Slate::deserialize_upgrade(&returned.to_string())?;
+        Ok(slate)
+    }
+
+    /// JSON-RPC-Aufruf; liefert den Inhalt von `result.Ok`.
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&body)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.json::<Value>())
+            .map_err(|e| {
+                if e.is_timeout() {
+                    anyhow!(tr(Msg::DirectSendTimeout {
+                        secs: self.timeout.as_secs()
+                    }))
+                } else {
+                    anyhow::Error::new(e).context(tr(Msg::ForeignCall { method }))
+                }
+            })?;
+        if let Some(ok) = response["result"].get("Ok") {
+            return Ok(ok.clone());
+        }
+        // Fehler kommen entweder als JSON-RPC-`error` oder als `result.Err` des Handlers.
+        let detail = match response.get("error") {
+            Some(error) if !error.is_null() => error.to_string(),
+            _ => response["result"]["Err"].to_string(),
+        };
+        Err(anyhow!("{}: {detail}", tr(Msg::ForeignCall { method })))
+    }
+}
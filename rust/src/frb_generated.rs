@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 96907516;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1317904890;
 
 // Section: executor
 
@@ -45,15 +45,15 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__get_node_tip_impl(
+fn wire__crate__api__cancel_operation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_node_tip",
+            debug_name: "cancel_operation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,20 +67,18 @@ fn wire__crate__api__get_node_tip_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::get_node_tip().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cancel_operation(api_token))?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__get_node_url_impl(
+fn wire__crate__api__create_cancel_token_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -88,7 +86,7 @@ fn wire__crate__api__get_node_url_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_node_url",
+            debug_name: "create_cancel_token",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -104,17 +102,15 @@ fn wire__crate__api__get_node_url_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::get_node_url()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::create_cancel_token())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__owner_listener_start_impl(
+fn wire__crate__api__foreign_listener_addr_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -122,7 +118,7 @@ fn wire__crate__api__owner_listener_start_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "owner_listener_start",
+            debug_name: "foreign_listener_addr",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -136,11 +132,12 @@ fn wire__crate__api__owner_listener_start_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::owner_listener_start().await?;
+                        let output_ok = crate::api::foreign_listener_addr(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -149,15 +146,15 @@ fn wire__crate__api__owner_listener_start_impl(
         },
     )
 }
-fn wire__crate__api__owner_listener_status_impl(
+fn wire__crate__api__get_locale_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "owner_listener_status",
+            debug_name: "get_locale",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -172,27 +169,24 @@ fn wire__crate__api__owner_listener_status_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::owner_listener_status().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_locale())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__set_node_url_impl(
+fn wire__crate__api__get_node_tip_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_node_url",
+            debug_name: "get_node_tip",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -206,28 +200,28 @@ fn wire__crate__api__set_node_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::set_node_url(api_url)?;
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::get_node_tip().await?;
                         Ok(output_ok)
-                    })(),
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__api__tor_start_impl(
+fn wire__crate__api__get_node_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tor_start",
+            debug_name: "get_node_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -241,21 +235,17 @@ fn wire__crate__api__tor_start_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_listen_addr = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::tor_start(api_listen_addr).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::error::BridgeError>((move || {
+                    let output_ok = crate::api::get_node_url()?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__tor_status_impl(
+fn wire__crate__api__owner_listener_start_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -263,7 +253,7 @@ fn wire__crate__api__tor_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tor_status",
+            debug_name: "owner_listener_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -277,11 +267,12 @@ fn wire__crate__api__tor_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::tor_status().await?;
+                        let output_ok = crate::api::owner_listener_start(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -290,7 +281,7 @@ fn wire__crate__api__tor_status_impl(
         },
     )
 }
-fn wire__crate__api__tor_stop_impl(
+fn wire__crate__api__owner_listener_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -298,7 +289,7 @@ fn wire__crate__api__tor_stop_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tor_stop",
+            debug_name: "owner_listener_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -312,11 +303,12 @@ fn wire__crate__api__tor_stop_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::tor_stop().await?;
+                        let output_ok = crate::api::owner_listener_status(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -325,15 +317,15 @@ fn wire__crate__api__tor_stop_impl(
         },
     )
 }
-fn wire__crate__api__wallet_active_account_impl(
+fn wire__crate__api__qr_add_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_active_account",
+            debug_name: "qr_add_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -347,28 +339,27 @@ fn wire__crate__api__wallet_active_account_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session = <u64>::sse_decode(&mut deserializer);
+            let api_frame = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::wallet_active_account().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::error::BridgeError>((move || {
+                    let output_ok = crate::api::qr_add_frame(api_session, api_frame)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__wallet_cancel_tx_impl(
+fn wire__crate__api__qr_create_scan_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_cancel_tx",
+            debug_name: "qr_create_scan_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -382,29 +373,25 @@ fn wire__crate__api__wallet_cancel_tx_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tx_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::wallet_cancel_tx(api_tx_id).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::qr_create_scan_session())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__wallet_create_impl(
+fn wire__crate__api__qr_encode_slatepack_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_create",
+            debug_name: "qr_encode_slatepack",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -418,36 +405,28 @@ fn wire__crate__api__wallet_create_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_passphrase = <String>::sse_decode(&mut deserializer);
-            let api_mnemonic_length = <usize>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_max_chars_per_frame = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::wallet_create(
-                            api_data_dir,
-                            api_passphrase,
-                            api_mnemonic_length,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::error::BridgeError>((move || {
+                    let output_ok =
+                        crate::api::qr_encode_slatepack(api_message, api_max_chars_per_frame)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__wallet_create_account_impl(
+fn wire__crate__api__qr_release_scan_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_create_account",
+            debug_name: "qr_release_scan_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -461,21 +440,20 @@ fn wire__crate__api__wallet_create_account_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_label = <String>::sse_decode(&mut deserializer);
+            let api_session = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::wallet_create_account(api_label).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::qr_release_scan_session(api_session);
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__wallet_finalize_slatepack_impl(
+fn wire__crate__api__read_slatepack_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -483,7 +461,7 @@ fn wire__crate__api__wallet_finalize_slatepack_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_finalize_slatepack",
+            debug_name: "read_slatepack_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -497,19 +475,12 @@ fn wire__crate__api__wallet_finalize_slatepack_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_post_tx = <bool>::sse_decode(&mut deserializer);
-            let api_fluff = <bool>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_finalize_slatepack(
-                            api_message,
-                            api_post_tx,
-                            api_fluff,
-                        )
-                        .await?;
+                        let output_ok = crate::api::read_slatepack_file(api_path).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -518,15 +489,15 @@ fn wire__crate__api__wallet_finalize_slatepack_impl(
         },
     )
 }
-fn wire__crate__api__wallet_get_address_impl(
+fn wire__crate__api__set_locale_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_address",
+            debug_name: "set_locale",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -540,28 +511,28 @@ fn wire__crate__api__wallet_get_address_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_locale = <crate::i18n::BridgeLocale>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::wallet_get_address().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::set_locale(api_locale);
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__wallet_get_balance_impl(
+fn wire__crate__api__set_node_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_get_balance",
+            debug_name: "set_node_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -575,20 +546,18 @@ fn wire__crate__api__wallet_get_balance_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::wallet_get_balance().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::error::BridgeError>((move || {
+                    let output_ok = crate::api::set_node_url(api_url)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__wallet_info_impl(
+fn wire__crate__api__tor_start_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -596,7 +565,7 @@ fn wire__crate__api__wallet_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_info",
+            debug_name: "tor_start",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -610,11 +579,13 @@ fn wire__crate__api__wallet_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_listen_addr = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_info().await?;
+                        let output_ok = crate::api::tor_start(api_handle, api_listen_addr).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -623,7 +594,7 @@ fn wire__crate__api__wallet_info_impl(
         },
     )
 }
-fn wire__crate__api__wallet_init_or_open_impl(
+fn wire__crate__api__tor_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -631,7 +602,7 @@ fn wire__crate__api__wallet_init_or_open_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_init_or_open",
+            debug_name: "tor_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -645,14 +616,12 @@ fn wire__crate__api__wallet_init_or_open_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::wallet_init_or_open(api_data_dir, api_passphrase).await?;
+                        let output_ok = crate::api::tor_status(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -661,7 +630,7 @@ fn wire__crate__api__wallet_init_or_open_impl(
         },
     )
 }
-fn wire__crate__api__wallet_inspect_slatepack_impl(
+fn wire__crate__api__tor_stop_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -669,7 +638,7 @@ fn wire__crate__api__wallet_inspect_slatepack_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_inspect_slatepack",
+            debug_name: "tor_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -683,12 +652,12 @@ fn wire__crate__api__wallet_inspect_slatepack_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_inspect_slatepack(api_message).await?;
+                        let output_ok = crate::api::tor_stop(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -697,7 +666,7 @@ fn wire__crate__api__wallet_inspect_slatepack_impl(
         },
     )
 }
-fn wire__crate__api__wallet_issue_invoice_impl(
+fn wire__crate__api__wallet_active_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -705,7 +674,7 @@ fn wire__crate__api__wallet_issue_invoice_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_issue_invoice",
+            debug_name: "wallet_active_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -719,12 +688,12 @@ fn wire__crate__api__wallet_issue_invoice_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_amount_nano = <u64>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_issue_invoice(api_amount_nano).await?;
+                        let output_ok = crate::api::wallet_active_account(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -733,7 +702,7 @@ fn wire__crate__api__wallet_issue_invoice_impl(
         },
     )
 }
-fn wire__crate__api__wallet_list_accounts_impl(
+fn wire__crate__api__wallet_approve_slatepack_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -741,7 +710,7 @@ fn wire__crate__api__wallet_list_accounts_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_list_accounts",
+            debug_name: "wallet_approve_slatepack",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -755,11 +724,19 @@ fn wire__crate__api__wallet_list_accounts_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_slate_id = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_list_accounts().await?;
+                        let output_ok = crate::api::wallet_approve_slatepack(
+                            api_handle,
+                            api_slate_id,
+                            api_options,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -768,7 +745,7 @@ fn wire__crate__api__wallet_list_accounts_impl(
         },
     )
 }
-fn wire__crate__api__wallet_list_outputs_impl(
+fn wire__crate__api__wallet_cancel_tx_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -776,7 +753,7 @@ fn wire__crate__api__wallet_list_outputs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_list_outputs",
+            debug_name: "wallet_cancel_tx",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -790,17 +767,13 @@ fn wire__crate__api__wallet_list_outputs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_include_spent = <bool>::sse_decode(&mut deserializer);
-            let api_refresh_from_node = <bool>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_tx_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_list_outputs(
-                            api_include_spent,
-                            api_refresh_from_node,
-                        )
-                        .await?;
+                        let output_ok = crate::api::wallet_cancel_tx(api_handle, api_tx_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -809,7 +782,7 @@ fn wire__crate__api__wallet_list_outputs_impl(
         },
     )
 }
-fn wire__crate__api__wallet_list_transactions_impl(
+fn wire__crate__api__wallet_change_password_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -817,7 +790,7 @@ fn wire__crate__api__wallet_list_transactions_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_list_transactions",
+            debug_name: "wallet_change_password",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -831,13 +804,19 @@ fn wire__crate__api__wallet_list_transactions_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_refresh_from_node = <bool>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_old_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_new_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::wallet_list_transactions(api_refresh_from_node).await?;
+                        let output_ok = crate::api::wallet_change_password(
+                            api_handle,
+                            api_old_passphrase,
+                            api_new_passphrase,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -846,7 +825,7 @@ fn wire__crate__api__wallet_list_transactions_impl(
         },
     )
 }
-fn wire__crate__api__wallet_payment_proof_impl(
+fn wire__crate__api__wallet_close_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -854,7 +833,7 @@ fn wire__crate__api__wallet_payment_proof_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_payment_proof",
+            debug_name: "wallet_close",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -868,12 +847,12 @@ fn wire__crate__api__wallet_payment_proof_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tx_id = <u32>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_payment_proof(api_tx_id).await?;
+                        let output_ok = crate::api::wallet_close(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -882,7 +861,7 @@ fn wire__crate__api__wallet_payment_proof_impl(
         },
     )
 }
-fn wire__crate__api__wallet_process_invoice_impl(
+fn wire__crate__api__wallet_create_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -890,7 +869,7 @@ fn wire__crate__api__wallet_process_invoice_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_process_invoice",
+            debug_name: "wallet_create",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -904,12 +883,21 @@ fn wire__crate__api__wallet_process_invoice_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_mnemonic_length = <usize>::sse_decode(&mut deserializer);
+            let api_chain_type = <crate::wallet::WalletChainType>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_process_invoice(api_message).await?;
+                        let output_ok = crate::api::wallet_create(
+                            api_data_dir,
+                            api_passphrase,
+                            api_mnemonic_length,
+                            api_chain_type,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -918,7 +906,7 @@ fn wire__crate__api__wallet_process_invoice_impl(
         },
     )
 }
-fn wire__crate__api__wallet_receive_slatepack_impl(
+fn wire__crate__api__wallet_create_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -926,7 +914,7 @@ fn wire__crate__api__wallet_receive_slatepack_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_receive_slatepack",
+            debug_name: "wallet_create_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -940,12 +928,14 @@ fn wire__crate__api__wallet_receive_slatepack_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_label = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_receive_slatepack(api_message).await?;
+                        let output_ok =
+                            crate::api::wallet_create_account(api_handle, api_label).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -954,7 +944,7 @@ fn wire__crate__api__wallet_receive_slatepack_impl(
         },
     )
 }
-fn wire__crate__api__wallet_repost_tx_impl(
+fn wire__crate__api__wallet_estimate_send_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -962,7 +952,7 @@ fn wire__crate__api__wallet_repost_tx_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_repost_tx",
+            debug_name: "wallet_estimate_send",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -976,13 +966,19 @@ fn wire__crate__api__wallet_repost_tx_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tx_id = <u32>::sse_decode(&mut deserializer);
-            let api_fluff = <bool>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_amount_nano = <u64>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_repost_tx(api_tx_id, api_fluff).await?;
+                        let output_ok = crate::api::wallet_estimate_send(
+                            api_handle,
+                            api_amount_nano,
+                            api_options,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -991,15 +987,15 @@ fn wire__crate__api__wallet_repost_tx_impl(
         },
     )
 }
-fn wire__crate__api__wallet_restore_from_seed_impl(
+fn wire__crate__api__wallet_events_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_restore_from_seed",
+            debug_name: "wallet_events",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1013,28 +1009,21 @@ fn wire__crate__api__wallet_restore_from_seed_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_dir = <String>::sse_decode(&mut deserializer);
-            let api_passphrase = <String>::sse_decode(&mut deserializer);
-            let api_phrase = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::events::WalletEventDto,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::wallet_restore_from_seed(
-                            api_data_dir,
-                            api_passphrase,
-                            api_phrase,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, crate::error::BridgeError>((move || {
+                    let output_ok = crate::api::wallet_events(api_sink)?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__wallet_scan_impl(
+fn wire__crate__api__wallet_finalize_slatepack_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1042,7 +1031,7 @@ fn wire__crate__api__wallet_scan_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_scan",
+            debug_name: "wallet_finalize_slatepack",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1056,17 +1045,22 @@ fn wire__crate__api__wallet_scan_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_delete_unconfirmed = <bool>::sse_decode(&mut deserializer);
-            let api_start_height = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_backwards_from_tip = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_post_tx = <bool>::sse_decode(&mut deserializer);
+            let api_fluff = <bool>::sse_decode(&mut deserializer);
+            let api_output_file =
+                <Option<crate::models::SlatepackFileTarget>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_scan(
-                            api_delete_unconfirmed,
-                            api_start_height,
-                            api_backwards_from_tip,
+                        let output_ok = crate::api::wallet_finalize_slatepack(
+                            api_handle,
+                            api_message,
+                            api_post_tx,
+                            api_fluff,
+                            api_output_file,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1077,7 +1071,7 @@ fn wire__crate__api__wallet_scan_impl(
         },
     )
 }
-fn wire__crate__api__wallet_seed_phrase_impl(
+fn wire__crate__api__wallet_get_address_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1085,7 +1079,115 @@ fn wire__crate__api__wallet_seed_phrase_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_seed_phrase",
+            debug_name: "wallet_get_address",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_get_address(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_get_balance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_get_balance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_get_balance(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_info(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_init_or_open_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_init_or_open",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1101,12 +1203,56 @@ fn wire__crate__api__wallet_seed_phrase_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_data_dir = <String>::sse_decode(&mut deserializer);
             let api_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_chain_type =
+                <Option<crate::wallet::WalletChainType>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_init_or_open(
+                            api_data_dir,
+                            api_passphrase,
+                            api_chain_type,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_inspect_slatepack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_inspect_slatepack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::wallet_seed_phrase(api_data_dir, api_passphrase).await?;
+                            crate::api::wallet_inspect_slatepack(api_handle, api_message).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1115,7 +1261,7 @@ fn wire__crate__api__wallet_seed_phrase_impl(
         },
     )
 }
-fn wire__crate__api__wallet_send_slatepack_impl(
+fn wire__crate__api__wallet_issue_invoice_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1123,7 +1269,7 @@ fn wire__crate__api__wallet_send_slatepack_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_send_slatepack",
+            debug_name: "wallet_issue_invoice",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1137,14 +1283,14 @@ fn wire__crate__api__wallet_send_slatepack_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_to = <String>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             let api_amount_nano = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::wallet_send_slatepack(api_to, api_amount_nano).await?;
+                            crate::api::wallet_issue_invoice(api_handle, api_amount_nano).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1153,7 +1299,7 @@ fn wire__crate__api__wallet_send_slatepack_impl(
         },
     )
 }
-fn wire__crate__api__wallet_set_active_account_impl(
+fn wire__crate__api__wallet_list_accounts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1161,7 +1307,7 @@ fn wire__crate__api__wallet_set_active_account_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_set_active_account",
+            debug_name: "wallet_list_accounts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1175,12 +1321,12 @@ fn wire__crate__api__wallet_set_active_account_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_label = <String>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_set_active_account(api_label).await?;
+                        let output_ok = crate::api::wallet_list_accounts(api_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1189,7 +1335,7 @@ fn wire__crate__api__wallet_set_active_account_impl(
         },
     )
 }
-fn wire__crate__api__wallet_sync_impl(
+fn wire__crate__api__wallet_list_outputs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1197,7 +1343,7 @@ fn wire__crate__api__wallet_sync_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_sync",
+            debug_name: "wallet_list_outputs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1211,11 +1357,19 @@ fn wire__crate__api__wallet_sync_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_include_spent = <bool>::sse_decode(&mut deserializer);
+            let api_refresh_from_node = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_sync().await?;
+                        let output_ok = crate::api::wallet_list_outputs(
+                            api_handle,
+                            api_include_spent,
+                            api_refresh_from_node,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1224,7 +1378,7 @@ fn wire__crate__api__wallet_sync_impl(
         },
     )
 }
-fn wire__crate__api__wallet_transaction_slatepack_impl(
+fn wire__crate__api__wallet_list_transactions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1232,7 +1386,7 @@ fn wire__crate__api__wallet_transaction_slatepack_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_transaction_slatepack",
+            debug_name: "wallet_list_transactions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1246,12 +1400,15 @@ fn wire__crate__api__wallet_transaction_slatepack_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tx_id = <u32>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_refresh_from_node = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
-                        let output_ok = crate::api::wallet_transaction_slatepack(api_tx_id).await?;
+                        let output_ok =
+                            crate::api::wallet_list_transactions(api_handle, api_refresh_from_node)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1260,7 +1417,7 @@ fn wire__crate__api__wallet_transaction_slatepack_impl(
         },
     )
 }
-fn wire__crate__api__wallet_verify_payment_proof_impl(
+fn wire__crate__api__wallet_payment_proof_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1268,7 +1425,7 @@ fn wire__crate__api__wallet_verify_payment_proof_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wallet_verify_payment_proof",
+            debug_name: "wallet_payment_proof",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1282,13 +1439,14 @@ fn wire__crate__api__wallet_verify_payment_proof_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_payload = <String>::sse_decode(&mut deserializer);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_tx_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::error::BridgeError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::wallet_verify_payment_proof(api_payload).await?;
+                            crate::api::wallet_payment_proof(api_handle, api_tx_id).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1297,196 +1455,4050 @@ fn wire__crate__api__wallet_verify_payment_proof_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__wallet_process_invoice_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_process_invoice",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
+            let api_output_file =
+                <Option<crate::models::SlatepackFileTarget>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_process_invoice(
+                            api_handle,
+                            api_message,
+                            api_options,
+                            api_output_file,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
+fn wire__crate__api__wallet_process_slatepack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_process_slatepack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::models::ProcessSlatepackOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_process_slatepack(
+                            api_handle,
+                            api_message,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_receive_slatepack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_receive_slatepack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_output_file =
+                <Option<crate::models::SlatepackFileTarget>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_receive_slatepack(
+                            api_handle,
+                            api_message,
+                            api_output_file,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_reject_slatepack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_reject_slatepack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_slate_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_reject_slatepack(api_handle, api_slate_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_repost_tx_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_repost_tx",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_tx_id = <u32>::sse_decode(&mut deserializer);
+            let api_fluff = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_repost_tx(api_handle, api_tx_id, api_fluff).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_restore_from_seed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_restore_from_seed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_phrase = <String>::sse_decode(&mut deserializer);
+            let api_chain_type = <crate::wallet::WalletChainType>::sse_decode(&mut deserializer);
+            let api_cancel_token = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_restore_from_seed(
+                            api_data_dir,
+                            api_passphrase,
+                            api_phrase,
+                            api_chain_type,
+                            api_cancel_token,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_resume_scan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_resume_scan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_cancel_token = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_resume_scan(api_handle, api_cancel_token).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_review_slatepack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_review_slatepack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_expectation = <crate::models::SlateExpectation>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_review_slatepack(
+                            api_handle,
+                            api_message,
+                            api_expectation,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_scan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_scan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_delete_unconfirmed = <bool>::sse_decode(&mut deserializer);
+            let api_start_height = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_backwards_from_tip = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_cancel_token = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_scan(
+                            api_handle,
+                            api_delete_unconfirmed,
+                            api_start_height,
+                            api_backwards_from_tip,
+                            api_cancel_token,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_scan_resume_height_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_scan_resume_height",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_scan_resume_height(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_seed_phrase_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_seed_phrase",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_dir = <String>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_seed_phrase(api_data_dir, api_passphrase).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_send_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_send_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_items = <Vec<crate::models::PayoutItem>>::sse_decode(&mut deserializer);
+            let api_via_tor = <bool>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_send_batch(
+                            api_handle,
+                            api_items,
+                            api_via_tor,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_send_slatepack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_send_slatepack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            let api_amount_nano = <u64>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
+            let api_output_file =
+                <Option<crate::models::SlatepackFileTarget>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_send_slatepack(
+                            api_handle,
+                            api_to,
+                            api_amount_nano,
+                            api_options,
+                            api_output_file,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_send_via_http_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_send_via_http",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_amount_nano = <u64>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_send_via_http(
+                            api_handle,
+                            api_url,
+                            api_amount_nano,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_send_via_tor_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_send_via_tor",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_address = <String>::sse_decode(&mut deserializer);
+            let api_amount_nano = <u64>::sse_decode(&mut deserializer);
+            let api_options = <crate::models::SendOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_send_via_tor(
+                            api_handle,
+                            api_address,
+                            api_amount_nano,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_set_active_account_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_set_active_account",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_label = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_set_active_account(api_handle, api_label).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_set_auto_lock_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_set_auto_lock",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_timeout_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_set_auto_lock(api_handle, api_timeout_secs).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_set_default_ttl_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_set_default_ttl",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_ttl_blocks = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_set_default_ttl(api_handle, api_ttl_blocks).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_set_require_review_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_set_require_review",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_required = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_set_require_review(api_handle, api_required).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_set_tx_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_set_tx_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_parent_key_id = <String>::sse_decode(&mut deserializer);
+            let api_tx_id = <u32>::sse_decode(&mut deserializer);
+            let api_metadata = <crate::models::TxMetadataDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_set_tx_metadata(
+                            api_handle,
+                            api_parent_key_id,
+                            api_tx_id,
+                            api_metadata,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_start_updater_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_start_updater",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_interval_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_start_updater(api_handle, api_interval_secs).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_stop_updater_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_stop_updater",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_stop_updater(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_sync_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_sync",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_sync(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_transaction_slatepack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_transaction_slatepack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_tx_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_transaction_slatepack(api_handle, api_tx_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_tx_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_tx_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_parent_key_id = <String>::sse_decode(&mut deserializer);
+            let api_tx_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_tx_metadata(
+                            api_handle,
+                            api_parent_key_id,
+                            api_tx_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_updater_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_updater_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::wallet_updater_status(api_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wallet_verify_payment_proof_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wallet_verify_payment_proof",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_payload = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::wallet_verify_payment_proof(api_handle, api_payload)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__write_slatepack_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "write_slatepack_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_binary = <bool>::sse_decode(&mut deserializer);
+            let api_overwrite = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::BridgeError>(
+                    (move || async move {
+                        let output_ok = crate::api::write_slatepack_file(
+                            api_message,
+                            api_path,
+                            api_binary,
+                            api_overwrite,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::events::WalletEventDto, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::models::AccountDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_isActive = <bool>::sse_decode(deserializer);
+        return crate::models::AccountDto {
+            label: var_label,
+            path: var_path,
+            is_active: var_isActive,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::error::BridgeError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::NotInitialized { detail: var_detail };
+            }
+            1 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::WalletNotOpen { detail: var_detail };
+            }
+            2 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::WalletAlreadyOpen { detail: var_detail };
+            }
+            3 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::WrongPassword { detail: var_detail };
+            }
+            4 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::InsufficientFunds { detail: var_detail };
+            }
+            5 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::NodeUnreachable { detail: var_detail };
+            }
+            6 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::InvalidSlatepack { detail: var_detail };
+            }
+            7 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::InvalidAddress { detail: var_detail };
+            }
+            8 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::InvalidInput { detail: var_detail };
+            }
+            9 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::AccountError { detail: var_detail };
+            }
+            10 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::TransactionError { detail: var_detail };
+            }
+            11 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::PaymentProofError { detail: var_detail };
+            }
+            12 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::TorFailed { detail: var_detail };
+            }
+            13 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::ListenerFailed { detail: var_detail };
+            }
+            14 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::Cancelled { detail: var_detail };
+            }
+            15 => {
+                let mut var_detail = <String>::sse_decode(deserializer);
+                return crate::error::BridgeError::Internal { detail: var_detail };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::i18n::BridgeLocale {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::i18n::BridgeLocale::En,
+            1 => crate::i18n::BridgeLocale::De,
+            _ => unreachable!("Invalid variant for BridgeLocale: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::CreatedWallet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handle = <u64>::sse_decode(deserializer);
+        let mut var_mnemonic = <String>::sse_decode(deserializer);
+        return crate::models::CreatedWallet {
+            handle: var_handle,
+            mnemonic: var_mnemonic,
+        };
+    }
+}
+
+impl SseDecode for crate::models::DirectSendResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txSlateId = <String>::sse_decode(deserializer);
+        let mut var_delivered = <bool>::sse_decode(deserializer);
+        let mut var_slatepack = <Option<String>>::sse_decode(deserializer);
+        let mut var_fallbackReason = <Option<String>>::sse_decode(deserializer);
+        return crate::models::DirectSendResultDto {
+            tx_slate_id: var_txSlateId,
+            delivered: var_delivered,
+            slatepack: var_slatepack,
+            fallback_reason: var_fallbackReason,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::AccountDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::AccountDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::OutputDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::OutputDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::PayoutItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::PayoutItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::PayoutResultDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::PayoutResultDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
             ans_.push(<u8>::sse_decode(deserializer));
         }
-        return ans_;
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::TransactionDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::TransactionDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::SlatepackFileTarget> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::models::SlatepackFileTarget>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::TxMetadataDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::models::TxMetadataDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::UpdaterMessageDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::models::UpdaterMessageDto>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::wallet::WalletChainType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::wallet::WalletChainType>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::models::OutputDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_commitment = <String>::sse_decode(deserializer);
+        let mut var_value = <u64>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_height = <u64>::sse_decode(deserializer);
+        let mut var_lockHeight = <u64>::sse_decode(deserializer);
+        let mut var_isCoinbase = <bool>::sse_decode(deserializer);
+        let mut var_mmrIndex = <Option<u64>>::sse_decode(deserializer);
+        let mut var_txLogId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_confirmations = <u64>::sse_decode(deserializer);
+        let mut var_spendable = <bool>::sse_decode(deserializer);
+        return crate::models::OutputDto {
+            commitment: var_commitment,
+            value: var_value,
+            status: var_status,
+            height: var_height,
+            lock_height: var_lockHeight,
+            is_coinbase: var_isCoinbase,
+            mmr_index: var_mmrIndex,
+            tx_log_id: var_txLogId,
+            confirmations: var_confirmations,
+            spendable: var_spendable,
+        };
+    }
+}
+
+impl SseDecode for crate::models::OwnerListenerStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_listenAddr = <String>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        return crate::models::OwnerListenerStatusDto {
+            running: var_running,
+            listen_addr: var_listenAddr,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::models::PaymentProofDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txId = <u32>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_excess = <String>::sse_decode(deserializer);
+        let mut var_recipientAddress = <String>::sse_decode(deserializer);
+        let mut var_recipientSig = <String>::sse_decode(deserializer);
+        let mut var_senderAddress = <String>::sse_decode(deserializer);
+        let mut var_senderSig = <String>::sse_decode(deserializer);
+        let mut var_proofJson = <String>::sse_decode(deserializer);
+        return crate::models::PaymentProofDto {
+            tx_id: var_txId,
+            amount: var_amount,
+            excess: var_excess,
+            recipient_address: var_recipientAddress,
+            recipient_sig: var_recipientSig,
+            sender_address: var_senderAddress,
+            sender_sig: var_senderSig,
+            proof_json: var_proofJson,
+        };
+    }
+}
+
+impl SseDecode for crate::models::PaymentProofState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::PaymentProofState::None,
+            1 => crate::models::PaymentProofState::Requested,
+            2 => crate::models::PaymentProofState::Received,
+            _ => unreachable!("Invalid variant for PaymentProofState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::PaymentProofVerificationDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_isSender = <bool>::sse_decode(deserializer);
+        let mut var_isRecipient = <bool>::sse_decode(deserializer);
+        return crate::models::PaymentProofVerificationDto {
+            is_sender: var_isSender,
+            is_recipient: var_isRecipient,
+        };
+    }
+}
+
+impl SseDecode for crate::models::PayoutItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_memo = <Option<String>>::sse_decode(deserializer);
+        return crate::models::PayoutItem {
+            address: var_address,
+            amount: var_amount,
+            memo: var_memo,
+        };
+    }
+}
+
+impl SseDecode for crate::models::PayoutReportDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_items = <Vec<crate::models::PayoutResultDto>>::sse_decode(deserializer);
+        let mut var_delivered = <u32>::sse_decode(deserializer);
+        let mut var_manual = <u32>::sse_decode(deserializer);
+        let mut var_skipped = <u32>::sse_decode(deserializer);
+        let mut var_failed = <u32>::sse_decode(deserializer);
+        let mut var_totalCommitted = <u64>::sse_decode(deserializer);
+        return crate::models::PayoutReportDto {
+            items: var_items,
+            delivered: var_delivered,
+            manual: var_manual,
+            skipped: var_skipped,
+            failed: var_failed,
+            total_committed: var_totalCommitted,
+        };
+    }
+}
+
+impl SseDecode for crate::models::PayoutResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_memo = <Option<String>>::sse_decode(deserializer);
+        let mut var_status = <crate::models::PayoutStatus>::sse_decode(deserializer);
+        let mut var_txSlateId = <Option<String>>::sse_decode(deserializer);
+        let mut var_fee = <Option<u64>>::sse_decode(deserializer);
+        let mut var_slatepack = <Option<String>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::models::PayoutResultDto {
+            address: var_address,
+            amount: var_amount,
+            memo: var_memo,
+            status: var_status,
+            tx_slate_id: var_txSlateId,
+            fee: var_fee,
+            slatepack: var_slatepack,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::models::PayoutStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::PayoutStatus::Delivered,
+            1 => crate::models::PayoutStatus::Manual,
+            2 => crate::models::PayoutStatus::Skipped,
+            3 => crate::models::PayoutStatus::Failed,
+            _ => unreachable!("Invalid variant for PayoutStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::ProcessSlatepackOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_send = <crate::models::SendOptions>::sse_decode(deserializer);
+        let mut var_postTx = <Option<bool>>::sse_decode(deserializer);
+        let mut var_fluff = <Option<bool>>::sse_decode(deserializer);
+        return crate::models::ProcessSlatepackOptions {
+            send: var_send,
+            post_tx: var_postTx,
+            fluff: var_fluff,
+        };
+    }
+}
+
+impl SseDecode for crate::models::ProcessedSlatepackDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_action = <crate::models::SlatepackAction>::sse_decode(deserializer);
+        let mut var_slateId = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::models::SlateCode>::sse_decode(deserializer);
+        let mut var_posted = <bool>::sse_decode(deserializer);
+        let mut var_response = <String>::sse_decode(deserializer);
+        return crate::models::ProcessedSlatepackDto {
+            action: var_action,
+            slate_id: var_slateId,
+            state: var_state,
+            posted: var_posted,
+            response: var_response,
+        };
+    }
+}
+
+impl SseDecode for crate::models::QrScanProgressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_received = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        return crate::models::QrScanProgressDto {
+            received: var_received,
+            total: var_total,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::models::RestoreResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handle = <u64>::sse_decode(deserializer);
+        let mut var_completed = <bool>::sse_decode(deserializer);
+        let mut var_resumeHeight = <Option<u64>>::sse_decode(deserializer);
+        return crate::models::RestoreResultDto {
+            handle: var_handle,
+            completed: var_completed,
+            resume_height: var_resumeHeight,
+        };
+    }
+}
+
+impl SseDecode for crate::models::ScanProgressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startHeight = <u64>::sse_decode(deserializer);
+        let mut var_currentHeight = <u64>::sse_decode(deserializer);
+        let mut var_tipHeight = <u64>::sse_decode(deserializer);
+        let mut var_percent = <u8>::sse_decode(deserializer);
+        return crate::models::ScanProgressDto {
+            start_height: var_startHeight,
+            current_height: var_currentHeight,
+            tip_height: var_tipHeight,
+            percent: var_percent,
+        };
+    }
+}
+
+impl SseDecode for crate::models::ScanResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_deleteUnconfirmed = <bool>::sse_decode(deserializer);
+        let mut var_startHeight = <Option<u64>>::sse_decode(deserializer);
+        let mut var_backwardsFromTip = <Option<u64>>::sse_decode(deserializer);
+        let mut var_performedAtEpochSecs = <u64>::sse_decode(deserializer);
+        return crate::models::ScanResultDto {
+            delete_unconfirmed: var_deleteUnconfirmed,
+            start_height: var_startHeight,
+            backwards_from_tip: var_backwardsFromTip,
+            performed_at_epoch_secs: var_performedAtEpochSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::models::SendEstimateDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fee = <u64>::sse_decode(deserializer);
+        let mut var_numInputs = <usize>::sse_decode(deserializer);
+        let mut var_totalInputs = <u64>::sse_decode(deserializer);
+        let mut var_change = <u64>::sse_decode(deserializer);
+        let mut var_sufficient = <bool>::sse_decode(deserializer);
+        return crate::models::SendEstimateDto {
+            amount: var_amount,
+            fee: var_fee,
+            num_inputs: var_numInputs,
+            total_inputs: var_totalInputs,
+            change: var_change,
+            sufficient: var_sufficient,
+        };
+    }
+}
+
+impl SseDecode for crate::models::SendOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minimumConfirmations = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxOutputs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_numChangeOutputs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_selectionStrategyIsUseAll = <Option<bool>>::sse_decode(deserializer);
+        let mut var_amountIncludesFee = <Option<bool>>::sse_decode(deserializer);
+        let mut var_sendAll = <Option<bool>>::sse_decode(deserializer);
+        let mut var_srcAcctName = <Option<String>>::sse_decode(deserializer);
+        let mut var_requestPaymentProof = <Option<bool>>::sse_decode(deserializer);
+        let mut var_ttlBlocks = <Option<u64>>::sse_decode(deserializer);
+        let mut var_lateLock = <Option<bool>>::sse_decode(deserializer);
+        let mut var_fluff = <Option<bool>>::sse_decode(deserializer);
+        return crate::models::SendOptions {
+            minimum_confirmations: var_minimumConfirmations,
+            max_outputs: var_maxOutputs,
+            num_change_outputs: var_numChangeOutputs,
+            selection_strategy_is_use_all: var_selectionStrategyIsUseAll,
+            amount_includes_fee: var_amountIncludesFee,
+            send_all: var_sendAll,
+            src_acct_name: var_srcAcctName,
+            request_payment_proof: var_requestPaymentProof,
+            ttl_blocks: var_ttlBlocks,
+            late_lock: var_lateLock,
+            fluff: var_fluff,
+        };
+    }
+}
+
+impl SseDecode for crate::models::SlateCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::SlateCode::S1,
+            1 => crate::models::SlateCode::S2,
+            2 => crate::models::SlateCode::S3,
+            3 => crate::models::SlateCode::I1,
+            4 => crate::models::SlateCode::I2,
+            5 => crate::models::SlateCode::I3,
+            6 => crate::models::SlateCode::Unknown,
+            _ => unreachable!("Invalid variant for SlateCode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::SlateExpectation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxFee = <Option<u64>>::sse_decode(deserializer);
+        return crate::models::SlateExpectation {
+            amount: var_amount,
+            max_fee: var_maxFee,
+        };
+    }
+}
+
+impl SseDecode for crate::models::SlateInspectionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code = <crate::models::SlateCode>::sse_decode(deserializer);
+        let mut var_slateId = <String>::sse_decode(deserializer);
+        let mut var_state = <String>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fee = <u64>::sse_decode(deserializer);
+        let mut var_numParticipants = <u16>::sse_decode(deserializer);
+        let mut var_kernelExcess = <Option<String>>::sse_decode(deserializer);
+        return crate::models::SlateInspectionDto {
+            code: var_code,
+            slate_id: var_slateId,
+            state: var_state,
+            amount: var_amount,
+            fee: var_fee,
+            num_participants: var_numParticipants,
+            kernel_excess: var_kernelExcess,
+        };
+    }
+}
+
+impl SseDecode for crate::models::SlateReviewDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_slateId = <String>::sse_decode(deserializer);
+        let mut var_state = <crate::models::SlateCode>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fee = <u64>::sse_decode(deserializer);
+        let mut var_senderAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_encryptedToUs = <bool>::sse_decode(deserializer);
+        let mut var_ttlCutoffHeight = <Option<u64>>::sse_decode(deserializer);
+        let mut var_paymentProofRequested = <bool>::sse_decode(deserializer);
+        return crate::models::SlateReviewDto {
+            slate_id: var_slateId,
+            state: var_state,
+            amount: var_amount,
+            fee: var_fee,
+            sender_address: var_senderAddress,
+            encrypted_to_us: var_encryptedToUs,
+            ttl_cutoff_height: var_ttlCutoffHeight,
+            payment_proof_requested: var_paymentProofRequested,
+        };
+    }
+}
+
+impl SseDecode for crate::models::SlatepackAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::SlatepackAction::Received,
+            1 => crate::models::SlatepackAction::PaidInvoice,
+            2 => crate::models::SlatepackAction::Finalized,
+            _ => unreachable!("Invalid variant for SlatepackAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::SlatepackFileDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_binary = <bool>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        return crate::models::SlatepackFileDto {
+            path: var_path,
+            binary: var_binary,
+            size_bytes: var_sizeBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::models::SlatepackFileTarget {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_binary = <bool>::sse_decode(deserializer);
+        let mut var_overwrite = <bool>::sse_decode(deserializer);
+        return crate::models::SlatepackFileTarget {
+            path: var_path,
+            binary: var_binary,
+            overwrite: var_overwrite,
+        };
+    }
+}
+
+impl SseDecode for crate::models::TorStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_onionAddress = <Option<String>>::sse_decode(deserializer);
+        let mut var_slatepackAddress = <Option<String>>::sse_decode(deserializer);
+        return crate::models::TorStatusDto {
+            running: var_running,
+            onion_address: var_onionAddress,
+            slatepack_address: var_slatepackAddress,
+        };
+    }
+}
+
+impl SseDecode for crate::models::TransactionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_parentKeyId = <String>::sse_decode(deserializer);
+        let mut var_txSlateId = <Option<String>>::sse_decode(deserializer);
+        let mut var_txType = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_direction = <String>::sse_decode(deserializer);
+        let mut var_creationTs = <String>::sse_decode(deserializer);
+        let mut var_confirmationTs = <Option<String>>::sse_decode(deserializer);
+        let mut var_confirmed = <bool>::sse_decode(deserializer);
+        let mut var_amount = <u64>::sse_decode(deserializer);
+        let mut var_fee = <Option<u64>>::sse_decode(deserializer);
+        let mut var_numInputs = <usize>::sse_decode(deserializer);
+        let mut var_numOutputs = <usize>::sse_decode(deserializer);
+        let mut var_hasProof = <bool>::sse_decode(deserializer);
+        let mut var_paymentProofState =
+            <crate::models::PaymentProofState>::sse_decode(deserializer);
+        let mut var_kernelExcess = <Option<String>>::sse_decode(deserializer);
+        let mut var_ttlCutoffHeight = <Option<u64>>::sse_decode(deserializer);
+        let mut var_revertedAfterSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_confirmations = <u64>::sse_decode(deserializer);
+        let mut var_metadata = <Option<crate::models::TxMetadataDto>>::sse_decode(deserializer);
+        return crate::models::TransactionDto {
+            id: var_id,
+            parent_key_id: var_parentKeyId,
+            tx_slate_id: var_txSlateId,
+            tx_type: var_txType,
+            status: var_status,
+            direction: var_direction,
+            creation_ts: var_creationTs,
+            confirmation_ts: var_confirmationTs,
+            confirmed: var_confirmed,
+            amount: var_amount,
+            fee: var_fee,
+            num_inputs: var_numInputs,
+            num_outputs: var_numOutputs,
+            has_proof: var_hasProof,
+            payment_proof_state: var_paymentProofState,
+            kernel_excess: var_kernelExcess,
+            ttl_cutoff_height: var_ttlCutoffHeight,
+            reverted_after_secs: var_revertedAfterSecs,
+            confirmations: var_confirmations,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::models::TxMetadataDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_category = <Option<String>>::sse_decode(deserializer);
+        let mut var_counterparty = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <Option<String>>::sse_decode(deserializer);
+        return crate::models::TxMetadataDto {
+            note: var_note,
+            category: var_category,
+            counterparty: var_counterparty,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::models::UpdaterMessageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::models::UpdaterMessageDto::UpdatingOutputs {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::models::UpdaterMessageDto::UpdatingTransactions {
+                    message: var_message,
+                };
+            }
+            2 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::models::UpdaterMessageDto::FullScanWarn {
+                    message: var_message,
+                };
+            }
+            3 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                let mut var_percent = <u8>::sse_decode(deserializer);
+                return crate::models::UpdaterMessageDto::Scanning {
+                    message: var_message,
+                    percent: var_percent,
+                };
+            }
+            4 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::models::UpdaterMessageDto::ScanningComplete {
+                    message: var_message,
+                };
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::models::UpdaterMessageDto::UpdateWarning {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::models::UpdaterStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_intervalSecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_lastConfirmedHeight = <u64>::sse_decode(deserializer);
+        let mut var_lastMessage =
+            <Option<crate::models::UpdaterMessageDto>>::sse_decode(deserializer);
+        return crate::models::UpdaterStatusDto {
+            running: var_running,
+            interval_secs: var_intervalSecs,
+            last_confirmed_height: var_lastConfirmedHeight,
+            last_message: var_lastMessage,
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::wallet::WalletChainType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::wallet::WalletChainType::Mainnet,
+            1 => crate::wallet::WalletChainType::Testnet,
+            2 => crate::wallet::WalletChainType::AutomatedTesting,
+            _ => unreachable!("Invalid variant for WalletChainType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::WalletCloseReportDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handle = <u64>::sse_decode(deserializer);
+        let mut var_foreignListenerStopped = <bool>::sse_decode(deserializer);
+        let mut var_ownerListenerStopped = <bool>::sse_decode(deserializer);
+        let mut var_torStopped = <bool>::sse_decode(deserializer);
+        let mut var_updaterStopped = <bool>::sse_decode(deserializer);
+        let mut var_keychainCleared = <bool>::sse_decode(deserializer);
+        return crate::models::WalletCloseReportDto {
+            handle: var_handle,
+            foreign_listener_stopped: var_foreignListenerStopped,
+            owner_listener_stopped: var_ownerListenerStopped,
+            tor_stopped: var_torStopped,
+            updater_stopped: var_updaterStopped,
+            keychain_cleared: var_keychainCleared,
+        };
+    }
+}
+
+impl SseDecode for crate::events::WalletEventDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_idleSecs = <u64>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::AutoLocked {
+                    handle: var_handle,
+                    idle_secs: var_idleSecs,
+                };
+            }
+            1 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_tx = <crate::models::TransactionDto>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::IncomingSlate {
+                    handle: var_handle,
+                    tx: var_tx,
+                };
+            }
+            2 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_txId = <u32>::sse_decode(deserializer);
+                let mut var_confirmations = <u64>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::ConfirmationsChanged {
+                    handle: var_handle,
+                    tx_id: var_txId,
+                    confirmations: var_confirmations,
+                };
+            }
+            3 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_balance = <crate::models::WalletInfoDto>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::BalanceChanged {
+                    handle: var_handle,
+                    balance: var_balance,
+                };
+            }
+            4 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_txId = <u32>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::TxReverted {
+                    handle: var_handle,
+                    tx_id: var_txId,
+                };
+            }
+            5 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_txId = <u32>::sse_decode(deserializer);
+                let mut var_txSlateId = <Option<String>>::sse_decode(deserializer);
+                let mut var_unlockedAmount = <u64>::sse_decode(deserializer);
+                let mut var_unlockedInputs = <usize>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::TxExpired {
+                    handle: var_handle,
+                    tx_id: var_txId,
+                    tx_slate_id: var_txSlateId,
+                    unlocked_amount: var_unlockedAmount,
+                    unlocked_inputs: var_unlockedInputs,
+                };
+            }
+            6 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_progress = <crate::models::ScanProgressDto>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::ScanProgress {
+                    handle: var_handle,
+                    progress: var_progress,
+                };
+            }
+            7 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_result = <crate::models::ScanResultDto>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::ScanCompleted {
+                    handle: var_handle,
+                    result: var_result,
+                };
+            }
+            8 => {
+                let mut var_handle = <u64>::sse_decode(deserializer);
+                let mut var_message = <crate::models::UpdaterMessageDto>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::UpdaterProgress {
+                    handle: var_handle,
+                    message: var_message,
+                };
+            }
+            9 => {
+                let mut var_reachable = <bool>::sse_decode(deserializer);
+                let mut var_tipHeight = <Option<u64>>::sse_decode(deserializer);
+                return crate::events::WalletEventDto::NodeConnectivityChanged {
+                    reachable: var_reachable,
+                    tip_height: var_tipHeight,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::models::WalletInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_refreshedFromNode = <bool>::sse_decode(deserializer);
+        let mut var_activeAccount = <String>::sse_decode(deserializer);
+        let mut var_lastConfirmedHeight = <u64>::sse_decode(deserializer);
+        let mut var_minimumConfirmations = <u64>::sse_decode(deserializer);
+        let mut var_total = <u64>::sse_decode(deserializer);
+        let mut var_amountAwaitingFinalization = <u64>::sse_decode(deserializer);
+        let mut var_amountAwaitingConfirmation = <u64>::sse_decode(deserializer);
+        let mut var_amountImmature = <u64>::sse_decode(deserializer);
+        let mut var_amountCurrentlySpendable = <u64>::sse_decode(deserializer);
+        let mut var_amountLocked = <u64>::sse_decode(deserializer);
+        let mut var_amountReverted = <u64>::sse_decode(deserializer);
+        return crate::models::WalletInfoDto {
+            refreshed_from_node: var_refreshedFromNode,
+            active_account: var_activeAccount,
+            last_confirmed_height: var_lastConfirmedHeight,
+            minimum_confirmations: var_minimumConfirmations,
+            total: var_total,
+            amount_awaiting_finalization: var_amountAwaitingFinalization,
+            amount_awaiting_confirmation: var_amountAwaitingConfirmation,
+            amount_immature: var_amountImmature,
+            amount_currently_spendable: var_amountCurrentlySpendable,
+            amount_locked: var_amountLocked,
+            amount_reverted: var_amountReverted,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__cancel_operation_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__create_cancel_token_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__foreign_listener_addr_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__get_locale_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__get_node_tip_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__get_node_url_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__owner_listener_start_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__owner_listener_status_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__qr_add_frame_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__qr_create_scan_session_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__qr_encode_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__qr_release_scan_session_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__read_slatepack_file_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__set_locale_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__set_node_url_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__tor_start_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__tor_status_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__tor_stop_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__wallet_active_account_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__wallet_approve_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__wallet_cancel_tx_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__wallet_change_password_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__wallet_close_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__wallet_create_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__wallet_create_account_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__wallet_estimate_send_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__wallet_events_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__wallet_finalize_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__wallet_get_address_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__wallet_get_balance_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__wallet_info_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__wallet_init_or_open_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__wallet_inspect_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__wallet_issue_invoice_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__wallet_list_accounts_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__wallet_list_outputs_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__wallet_list_transactions_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__wallet_payment_proof_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__wallet_process_invoice_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__wallet_process_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__wallet_receive_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__wallet_reject_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__wallet_repost_tx_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__wallet_restore_from_seed_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__wallet_resume_scan_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__wallet_review_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__wallet_scan_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__wallet_scan_resume_height_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__wallet_seed_phrase_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__wallet_send_batch_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__wallet_send_slatepack_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__wallet_send_via_http_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__wallet_send_via_tor_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__wallet_set_active_account_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__wallet_set_auto_lock_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__wallet_set_default_ttl_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__wallet_set_require_review_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__wallet_set_tx_metadata_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__wallet_start_updater_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__wallet_stop_updater_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__wallet_sync_impl(port, ptr, rust_vec_len, data_len),
+        62 => {
+            wire__crate__api__wallet_transaction_slatepack_impl(port, ptr, rust_vec_len, data_len)
+        }
+        63 => wire__crate__api__wallet_tx_metadata_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__wallet_updater_status_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__wallet_verify_payment_proof_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__write_slatepack_file_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::AccountDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.is_active.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::AccountDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::AccountDto> for crate::models::AccountDto {
+    fn into_into_dart(self) -> crate::models::AccountDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::BridgeError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::error::BridgeError::NotInitialized { detail } => {
+                [0.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::WalletNotOpen { detail } => {
+                [1.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::WalletAlreadyOpen { detail } => {
+                [2.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::WrongPassword { detail } => {
+                [3.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::InsufficientFunds { detail } => {
+                [4.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::NodeUnreachable { detail } => {
+                [5.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::InvalidSlatepack { detail } => {
+                [6.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::InvalidAddress { detail } => {
+                [7.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::InvalidInput { detail } => {
+                [8.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::AccountError { detail } => {
+                [9.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::TransactionError { detail } => {
+                [10.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::PaymentProofError { detail } => {
+                [11.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::TorFailed { detail } => {
+                [12.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::ListenerFailed { detail } => {
+                [13.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::Cancelled { detail } => {
+                [14.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::BridgeError::Internal { detail } => {
+                [15.into_dart(), detail.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::BridgeError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::BridgeError> for crate::error::BridgeError {
+    fn into_into_dart(self) -> crate::error::BridgeError {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::i18n::BridgeLocale {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::En => 0.into_dart(),
+            Self::De => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::i18n::BridgeLocale {}
+impl flutter_rust_bridge::IntoIntoDart<crate::i18n::BridgeLocale> for crate::i18n::BridgeLocale {
+    fn into_into_dart(self) -> crate::i18n::BridgeLocale {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::CreatedWallet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.handle.into_into_dart().into_dart(),
+            self.mnemonic.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::CreatedWallet {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::CreatedWallet>
+    for crate::models::CreatedWallet
+{
+    fn into_into_dart(self) -> crate::models::CreatedWallet {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::DirectSendResultDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tx_slate_id.into_into_dart().into_dart(),
+            self.delivered.into_into_dart().into_dart(),
+            self.slatepack.into_into_dart().into_dart(),
+            self.fallback_reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::DirectSendResultDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::DirectSendResultDto>
+    for crate::models::DirectSendResultDto
+{
+    fn into_into_dart(self) -> crate::models::DirectSendResultDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::OutputDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.commitment.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.lock_height.into_into_dart().into_dart(),
+            self.is_coinbase.into_into_dart().into_dart(),
+            self.mmr_index.into_into_dart().into_dart(),
+            self.tx_log_id.into_into_dart().into_dart(),
+            self.confirmations.into_into_dart().into_dart(),
+            self.spendable.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::OutputDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::OutputDto> for crate::models::OutputDto {
+    fn into_into_dart(self) -> crate::models::OutputDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::OwnerListenerStatusDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.running.into_into_dart().into_dart(),
+            self.listen_addr.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::OwnerListenerStatusDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::OwnerListenerStatusDto>
+    for crate::models::OwnerListenerStatusDto
+{
+    fn into_into_dart(self) -> crate::models::OwnerListenerStatusDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::PaymentProofDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tx_id.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.excess.into_into_dart().into_dart(),
+            self.recipient_address.into_into_dart().into_dart(),
+            self.recipient_sig.into_into_dart().into_dart(),
+            self.sender_address.into_into_dart().into_dart(),
+            self.sender_sig.into_into_dart().into_dart(),
+            self.proof_json.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::PaymentProofDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::PaymentProofDto>
+    for crate::models::PaymentProofDto
+{
+    fn into_into_dart(self) -> crate::models::PaymentProofDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::PaymentProofState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Requested => 1.into_dart(),
+            Self::Received => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::PaymentProofState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::PaymentProofState>
+    for crate::models::PaymentProofState
+{
+    fn into_into_dart(self) -> crate::models::PaymentProofState {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::PaymentProofVerificationDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.is_sender.into_into_dart().into_dart(),
+            self.is_recipient.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::PaymentProofVerificationDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::PaymentProofVerificationDto>
+    for crate::models::PaymentProofVerificationDto
+{
+    fn into_into_dart(self) -> crate::models::PaymentProofVerificationDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::PayoutItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.memo.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::PayoutItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::PayoutItem> for crate::models::PayoutItem {
+    fn into_into_dart(self) -> crate::models::PayoutItem {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::PayoutReportDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.items.into_into_dart().into_dart(),
+            self.delivered.into_into_dart().into_dart(),
+            self.manual.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+            self.total_committed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::PayoutReportDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::PayoutReportDto>
+    for crate::models::PayoutReportDto
+{
+    fn into_into_dart(self) -> crate::models::PayoutReportDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::PayoutResultDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.memo.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.tx_slate_id.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.slatepack.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::PayoutResultDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::PayoutResultDto>
+    for crate::models::PayoutResultDto
+{
+    fn into_into_dart(self) -> crate::models::PayoutResultDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::PayoutStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Delivered => 0.into_dart(),
+            Self::Manual => 1.into_dart(),
+            Self::Skipped => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::PayoutStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::PayoutStatus>
+    for crate::models::PayoutStatus
+{
+    fn into_into_dart(self) -> crate::models::PayoutStatus {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::ProcessSlatepackOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.send.into_into_dart().into_dart(),
+            self.post_tx.into_into_dart().into_dart(),
+            self.fluff.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::ProcessSlatepackOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::ProcessSlatepackOptions>
+    for crate::models::ProcessSlatepackOptions
+{
+    fn into_into_dart(self) -> crate::models::ProcessSlatepackOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::ProcessedSlatepackDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.action.into_into_dart().into_dart(),
+            self.slate_id.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.posted.into_into_dart().into_dart(),
+            self.response.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::ProcessedSlatepackDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::ProcessedSlatepackDto>
+    for crate::models::ProcessedSlatepackDto
+{
+    fn into_into_dart(self) -> crate::models::ProcessedSlatepackDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::QrScanProgressDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.received.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::QrScanProgressDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::QrScanProgressDto>
+    for crate::models::QrScanProgressDto
+{
+    fn into_into_dart(self) -> crate::models::QrScanProgressDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::RestoreResultDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.handle.into_into_dart().into_dart(),
+            self.completed.into_into_dart().into_dart(),
+            self.resume_height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::RestoreResultDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::RestoreResultDto>
+    for crate::models::RestoreResultDto
+{
+    fn into_into_dart(self) -> crate::models::RestoreResultDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::ScanProgressDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_height.into_into_dart().into_dart(),
+            self.current_height.into_into_dart().into_dart(),
+            self.tip_height.into_into_dart().into_dart(),
+            self.percent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::ScanProgressDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::ScanProgressDto>
+    for crate::models::ScanProgressDto
+{
+    fn into_into_dart(self) -> crate::models::ScanProgressDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::ScanResultDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.delete_unconfirmed.into_into_dart().into_dart(),
+            self.start_height.into_into_dart().into_dart(),
+            self.backwards_from_tip.into_into_dart().into_dart(),
+            self.performed_at_epoch_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::ScanResultDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::ScanResultDto>
+    for crate::models::ScanResultDto
+{
+    fn into_into_dart(self) -> crate::models::ScanResultDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SendEstimateDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.amount.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.num_inputs.into_into_dart().into_dart(),
+            self.total_inputs.into_into_dart().into_dart(),
+            self.change.into_into_dart().into_dart(),
+            self.sufficient.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::SendEstimateDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SendEstimateDto>
+    for crate::models::SendEstimateDto
+{
+    fn into_into_dart(self) -> crate::models::SendEstimateDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SendOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.minimum_confirmations.into_into_dart().into_dart(),
+            self.max_outputs.into_into_dart().into_dart(),
+            self.num_change_outputs.into_into_dart().into_dart(),
+            self.selection_strategy_is_use_all
+                .into_into_dart()
+                .into_dart(),
+            self.amount_includes_fee.into_into_dart().into_dart(),
+            self.send_all.into_into_dart().into_dart(),
+            self.src_acct_name.into_into_dart().into_dart(),
+            self.request_payment_proof.into_into_dart().into_dart(),
+            self.ttl_blocks.into_into_dart().into_dart(),
+            self.late_lock.into_into_dart().into_dart(),
+            self.fluff.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::SendOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SendOptions> for crate::models::SendOptions {
+    fn into_into_dart(self) -> crate::models::SendOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SlateCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::S1 => 0.into_dart(),
+            Self::S2 => 1.into_dart(),
+            Self::S3 => 2.into_dart(),
+            Self::I1 => 3.into_dart(),
+            Self::I2 => 4.into_dart(),
+            Self::I3 => 5.into_dart(),
+            Self::Unknown => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::SlateCode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SlateCode> for crate::models::SlateCode {
+    fn into_into_dart(self) -> crate::models::SlateCode {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SlateExpectation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.amount.into_into_dart().into_dart(),
+            self.max_fee.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::SlateExpectation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SlateExpectation>
+    for crate::models::SlateExpectation
+{
+    fn into_into_dart(self) -> crate::models::SlateExpectation {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SlateInspectionDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.code.into_into_dart().into_dart(),
+            self.slate_id.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.num_participants.into_into_dart().into_dart(),
+            self.kernel_excess.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::SlateInspectionDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SlateInspectionDto>
+    for crate::models::SlateInspectionDto
+{
+    fn into_into_dart(self) -> crate::models::SlateInspectionDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SlateReviewDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.slate_id.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.sender_address.into_into_dart().into_dart(),
+            self.encrypted_to_us.into_into_dart().into_dart(),
+            self.ttl_cutoff_height.into_into_dart().into_dart(),
+            self.payment_proof_requested.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::SlateReviewDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SlateReviewDto>
+    for crate::models::SlateReviewDto
+{
+    fn into_into_dart(self) -> crate::models::SlateReviewDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SlatepackAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Received => 0.into_dart(),
+            Self::PaidInvoice => 1.into_dart(),
+            Self::Finalized => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::SlatepackAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SlatepackAction>
+    for crate::models::SlatepackAction
+{
+    fn into_into_dart(self) -> crate::models::SlatepackAction {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SlatepackFileDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.binary.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::SlatepackFileDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SlatepackFileDto>
+    for crate::models::SlatepackFileDto
+{
+    fn into_into_dart(self) -> crate::models::SlatepackFileDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::SlatepackFileTarget {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.binary.into_into_dart().into_dart(),
+            self.overwrite.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::SlatepackFileTarget
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::SlatepackFileTarget>
+    for crate::models::SlatepackFileTarget
+{
+    fn into_into_dart(self) -> crate::models::SlatepackFileTarget {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::TorStatusDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.running.into_into_dart().into_dart(),
+            self.onion_address.into_into_dart().into_dart(),
+            self.slatepack_address.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::TorStatusDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::TorStatusDto>
+    for crate::models::TorStatusDto
+{
+    fn into_into_dart(self) -> crate::models::TorStatusDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::TransactionDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.parent_key_id.into_into_dart().into_dart(),
+            self.tx_slate_id.into_into_dart().into_dart(),
+            self.tx_type.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.creation_ts.into_into_dart().into_dart(),
+            self.confirmation_ts.into_into_dart().into_dart(),
+            self.confirmed.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+            self.fee.into_into_dart().into_dart(),
+            self.num_inputs.into_into_dart().into_dart(),
+            self.num_outputs.into_into_dart().into_dart(),
+            self.has_proof.into_into_dart().into_dart(),
+            self.payment_proof_state.into_into_dart().into_dart(),
+            self.kernel_excess.into_into_dart().into_dart(),
+            self.ttl_cutoff_height.into_into_dart().into_dart(),
+            self.reverted_after_secs.into_into_dart().into_dart(),
+            self.confirmations.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::TransactionDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::TransactionDto>
+    for crate::models::TransactionDto
+{
+    fn into_into_dart(self) -> crate::models::TransactionDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::TxMetadataDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.note.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.counterparty.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::TxMetadataDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::TxMetadataDto>
+    for crate::models::TxMetadataDto
+{
+    fn into_into_dart(self) -> crate::models::TxMetadataDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::UpdaterMessageDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::models::UpdaterMessageDto::UpdatingOutputs { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::UpdaterMessageDto::UpdatingTransactions { message } => {
+                [1.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::UpdaterMessageDto::FullScanWarn { message } => {
+                [2.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::UpdaterMessageDto::Scanning { message, percent } => [
+                3.into_dart(),
+                message.into_into_dart().into_dart(),
+                percent.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::models::UpdaterMessageDto::ScanningComplete { message } => {
+                [4.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::UpdaterMessageDto::UpdateWarning { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::UpdaterMessageDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::UpdaterMessageDto>
+    for crate::models::UpdaterMessageDto
+{
+    fn into_into_dart(self) -> crate::models::UpdaterMessageDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::UpdaterStatusDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.running.into_into_dart().into_dart(),
+            self.interval_secs.into_into_dart().into_dart(),
+            self.last_confirmed_height.into_into_dart().into_dart(),
+            self.last_message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::UpdaterStatusDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::UpdaterStatusDto>
+    for crate::models::UpdaterStatusDto
+{
+    fn into_into_dart(self) -> crate::models::UpdaterStatusDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::wallet::WalletChainType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mainnet => 0.into_dart(),
+            Self::Testnet => 1.into_dart(),
+            Self::AutomatedTesting => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::wallet::WalletChainType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::wallet::WalletChainType>
+    for crate::wallet::WalletChainType
+{
+    fn into_into_dart(self) -> crate::wallet::WalletChainType {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::WalletCloseReportDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.handle.into_into_dart().into_dart(),
+            self.foreign_listener_stopped.into_into_dart().into_dart(),
+            self.owner_listener_stopped.into_into_dart().into_dart(),
+            self.tor_stopped.into_into_dart().into_dart(),
+            self.updater_stopped.into_into_dart().into_dart(),
+            self.keychain_cleared.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::WalletCloseReportDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::WalletCloseReportDto>
+    for crate::models::WalletCloseReportDto
+{
+    fn into_into_dart(self) -> crate::models::WalletCloseReportDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::events::WalletEventDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::events::WalletEventDto::AutoLocked { handle, idle_secs } => [
+                0.into_dart(),
+                handle.into_into_dart().into_dart(),
+                idle_secs.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::IncomingSlate { handle, tx } => [
+                1.into_dart(),
+                handle.into_into_dart().into_dart(),
+                tx.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::ConfirmationsChanged {
+                handle,
+                tx_id,
+                confirmations,
+            } => [
+                2.into_dart(),
+                handle.into_into_dart().into_dart(),
+                tx_id.into_into_dart().into_dart(),
+                confirmations.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::BalanceChanged { handle, balance } => [
+                3.into_dart(),
+                handle.into_into_dart().into_dart(),
+                balance.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::TxReverted { handle, tx_id } => [
+                4.into_dart(),
+                handle.into_into_dart().into_dart(),
+                tx_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::TxExpired {
+                handle,
+                tx_id,
+                tx_slate_id,
+                unlocked_amount,
+                unlocked_inputs,
+            } => [
+                5.into_dart(),
+                handle.into_into_dart().into_dart(),
+                tx_id.into_into_dart().into_dart(),
+                tx_slate_id.into_into_dart().into_dart(),
+                unlocked_amount.into_into_dart().into_dart(),
+                unlocked_inputs.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::ScanProgress { handle, progress } => [
+                6.into_dart(),
+                handle.into_into_dart().into_dart(),
+                progress.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::ScanCompleted { handle, result } => [
+                7.into_dart(),
+                handle.into_into_dart().into_dart(),
+                result.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::UpdaterProgress { handle, message } => [
+                8.into_dart(),
+                handle.into_into_dart().into_dart(),
+                message.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::events::WalletEventDto::NodeConnectivityChanged {
+                reachable,
+                tip_height,
+            } => [
+                9.into_dart(),
+                reachable.into_into_dart().into_dart(),
+                tip_height.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::events::WalletEventDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::events::WalletEventDto>
+    for crate::events::WalletEventDto
+{
+    fn into_into_dart(self) -> crate::events::WalletEventDto {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::WalletInfoDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.refreshed_from_node.into_into_dart().into_dart(),
+            self.active_account.into_into_dart().into_dart(),
+            self.last_confirmed_height.into_into_dart().into_dart(),
+            self.minimum_confirmations.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.amount_awaiting_finalization
+                .into_into_dart()
+                .into_dart(),
+            self.amount_awaiting_confirmation
+                .into_into_dart()
+                .into_dart(),
+            self.amount_immature.into_into_dart().into_dart(),
+            self.amount_currently_spendable.into_into_dart().into_dart(),
+            self.amount_locked.into_into_dart().into_dart(),
+            self.amount_reverted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::models::WalletInfoDto {}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::WalletInfoDto>
+    for crate::models::WalletInfoDto
+{
+    fn into_into_dart(self) -> crate::models::WalletInfoDto {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::events::WalletEventDto, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::models::AccountDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.is_active, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::error::BridgeError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::error::BridgeError::NotInitialized { detail } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::WalletNotOpen { detail } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::WalletAlreadyOpen { detail } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::WrongPassword { detail } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::InsufficientFunds { detail } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::NodeUnreachable { detail } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::InvalidSlatepack { detail } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::InvalidAddress { detail } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::InvalidInput { detail } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::AccountError { detail } => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::TransactionError { detail } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::PaymentProofError { detail } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::TorFailed { detail } => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::ListenerFailed { detail } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::Cancelled { detail } => {
+                <i32>::sse_encode(14, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            crate::error::BridgeError::Internal { detail } => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(detail, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::i18n::BridgeLocale {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::i18n::BridgeLocale::En => 0,
+                crate::i18n::BridgeLocale::De => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::CreatedWallet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.handle, serializer);
+        <String>::sse_encode(self.mnemonic, serializer);
+    }
+}
+
+impl SseEncode for crate::models::DirectSendResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.tx_slate_id, serializer);
+        <bool>::sse_encode(self.delivered, serializer);
+        <Option<String>>::sse_encode(self.slatepack, serializer);
+        <Option<String>>::sse_encode(self.fallback_reason, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::models::AccountDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::AccountDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::models::OutputDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::OutputDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::models::PayoutItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::PayoutItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::models::PayoutResultDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::PayoutResultDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::models::TransactionDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::TransactionDto>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Option<crate::models::SlatepackFileTarget> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::SlatepackFileTarget>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<crate::models::TxMetadataDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::TxMetadataDto>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<crate::models::UpdaterMessageDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::UpdaterMessageDto>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__get_node_tip_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__get_node_url_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__owner_listener_start_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__owner_listener_status_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__set_node_url_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__tor_start_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__tor_status_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__tor_stop_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__wallet_active_account_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__wallet_cancel_tx_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__wallet_create_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__wallet_create_account_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__wallet_finalize_slatepack_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__wallet_get_address_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__wallet_get_balance_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__wallet_info_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__wallet_init_or_open_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__wallet_inspect_slatepack_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__wallet_issue_invoice_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__wallet_list_accounts_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__wallet_list_outputs_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__wallet_list_transactions_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__wallet_payment_proof_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__wallet_process_invoice_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__wallet_receive_slatepack_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__wallet_repost_tx_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__wallet_restore_from_seed_impl(port, ptr, rust_vec_len, data_len),
-        28 => wire__crate__api__wallet_scan_impl(port, ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__wallet_seed_phrase_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__wallet_send_slatepack_impl(port, ptr, rust_vec_len, data_len),
-        31 => wire__crate__api__wallet_set_active_account_impl(port, ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__wallet_sync_impl(port, ptr, rust_vec_len, data_len),
-        33 => {
-            wire__crate__api__wallet_transaction_slatepack_impl(port, ptr, rust_vec_len, data_len)
+impl SseEncode for Option<crate::wallet::WalletChainType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::wallet::WalletChainType>::sse_encode(value, serializer);
         }
-        34 => wire__crate__api__wallet_verify_payment_proof_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for crate::models::OutputDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.commitment, serializer);
+        <u64>::sse_encode(self.value, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <u64>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.lock_height, serializer);
+        <bool>::sse_encode(self.is_coinbase, serializer);
+        <Option<u64>>::sse_encode(self.mmr_index, serializer);
+        <Option<u32>>::sse_encode(self.tx_log_id, serializer);
+        <u64>::sse_encode(self.confirmations, serializer);
+        <bool>::sse_encode(self.spendable, serializer);
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::models::OwnerListenerStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.running, serializer);
+        <String>::sse_encode(self.listen_addr, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+    }
+}
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::models::PaymentProofDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <u32>::sse_encode(self.tx_id, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <String>::sse_encode(self.excess, serializer);
+        <String>::sse_encode(self.recipient_address, serializer);
+        <String>::sse_encode(self.recipient_sig, serializer);
+        <String>::sse_encode(self.sender_address, serializer);
+        <String>::sse_encode(self.sender_sig, serializer);
+        <String>::sse_encode(self.proof_json, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::models::PaymentProofState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::models::PaymentProofState::None => 0,
+                crate::models::PaymentProofState::Requested => 1,
+                crate::models::PaymentProofState::Received => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::models::PaymentProofVerificationDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_sender, serializer);
+        <bool>::sse_encode(self.is_recipient, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::models::PayoutItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.address, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <Option<String>>::sse_encode(self.memo, serializer);
     }
 }
 
-impl SseEncode for Option<u64> {
+impl SseEncode for crate::models::PayoutReportDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
-        }
+        <Vec<crate::models::PayoutResultDto>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.delivered, serializer);
+        <u32>::sse_encode(self.manual, serializer);
+        <u32>::sse_encode(self.skipped, serializer);
+        <u32>::sse_encode(self.failed, serializer);
+        <u64>::sse_encode(self.total_committed, serializer);
+    }
+}
+
+impl SseEncode for crate::models::PayoutResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <Option<String>>::sse_encode(self.memo, serializer);
+        <crate::models::PayoutStatus>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.tx_slate_id, serializer);
+        <Option<u64>>::sse_encode(self.fee, serializer);
+        <Option<String>>::sse_encode(self.slatepack, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::models::PayoutStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::PayoutStatus::Delivered => 0,
+                crate::models::PayoutStatus::Manual => 1,
+                crate::models::PayoutStatus::Skipped => 2,
+                crate::models::PayoutStatus::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::ProcessSlatepackOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::SendOptions>::sse_encode(self.send, serializer);
+        <Option<bool>>::sse_encode(self.post_tx, serializer);
+        <Option<bool>>::sse_encode(self.fluff, serializer);
+    }
+}
+
+impl SseEncode for crate::models::ProcessedSlatepackDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::SlatepackAction>::sse_encode(self.action, serializer);
+        <String>::sse_encode(self.slate_id, serializer);
+        <crate::models::SlateCode>::sse_encode(self.state, serializer);
+        <bool>::sse_encode(self.posted, serializer);
+        <String>::sse_encode(self.response, serializer);
+    }
+}
+
+impl SseEncode for crate::models::QrScanProgressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.received, serializer);
+        <u32>::sse_encode(self.total, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::models::RestoreResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.handle, serializer);
+        <bool>::sse_encode(self.completed, serializer);
+        <Option<u64>>::sse_encode(self.resume_height, serializer);
+    }
+}
+
+impl SseEncode for crate::models::ScanProgressDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.start_height, serializer);
+        <u64>::sse_encode(self.current_height, serializer);
+        <u64>::sse_encode(self.tip_height, serializer);
+        <u8>::sse_encode(self.percent, serializer);
+    }
+}
+
+impl SseEncode for crate::models::ScanResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.delete_unconfirmed, serializer);
+        <Option<u64>>::sse_encode(self.start_height, serializer);
+        <Option<u64>>::sse_encode(self.backwards_from_tip, serializer);
+        <u64>::sse_encode(self.performed_at_epoch_secs, serializer);
+    }
+}
+
+impl SseEncode for crate::models::SendEstimateDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.fee, serializer);
+        <usize>::sse_encode(self.num_inputs, serializer);
+        <u64>::sse_encode(self.total_inputs, serializer);
+        <u64>::sse_encode(self.change, serializer);
+        <bool>::sse_encode(self.sufficient, serializer);
+    }
+}
+
+impl SseEncode for crate::models::SendOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.minimum_confirmations, serializer);
+        <Option<u32>>::sse_encode(self.max_outputs, serializer);
+        <Option<u32>>::sse_encode(self.num_change_outputs, serializer);
+        <Option<bool>>::sse_encode(self.selection_strategy_is_use_all, serializer);
+        <Option<bool>>::sse_encode(self.amount_includes_fee, serializer);
+        <Option<bool>>::sse_encode(self.send_all, serializer);
+        <Option<String>>::sse_encode(self.src_acct_name, serializer);
+        <Option<bool>>::sse_encode(self.request_payment_proof, serializer);
+        <Option<u64>>::sse_encode(self.ttl_blocks, serializer);
+        <Option<bool>>::sse_encode(self.late_lock, serializer);
+        <Option<bool>>::sse_encode(self.fluff, serializer);
+    }
+}
+
+impl SseEncode for crate::models::SlateCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::SlateCode::S1 => 0,
+                crate::models::SlateCode::S2 => 1,
+                crate::models::SlateCode::S3 => 2,
+                crate::models::SlateCode::I1 => 3,
+                crate::models::SlateCode::I2 => 4,
+                crate::models::SlateCode::I3 => 5,
+                crate::models::SlateCode::Unknown => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::SlateExpectation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.amount, serializer);
+        <Option<u64>>::sse_encode(self.max_fee, serializer);
+    }
+}
+
+impl SseEncode for crate::models::SlateInspectionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::SlateCode>::sse_encode(self.code, serializer);
+        <String>::sse_encode(self.slate_id, serializer);
+        <String>::sse_encode(self.state, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.fee, serializer);
+        <u16>::sse_encode(self.num_participants, serializer);
+        <Option<String>>::sse_encode(self.kernel_excess, serializer);
+    }
+}
+
+impl SseEncode for crate::models::SlateReviewDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.slate_id, serializer);
+        <crate::models::SlateCode>::sse_encode(self.state, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <u64>::sse_encode(self.fee, serializer);
+        <Option<String>>::sse_encode(self.sender_address, serializer);
+        <bool>::sse_encode(self.encrypted_to_us, serializer);
+        <Option<u64>>::sse_encode(self.ttl_cutoff_height, serializer);
+        <bool>::sse_encode(self.payment_proof_requested, serializer);
+    }
+}
+
+impl SseEncode for crate::models::SlatepackAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::SlatepackAction::Received => 0,
+                crate::models::SlatepackAction::PaidInvoice => 1,
+                crate::models::SlatepackAction::Finalized => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::SlatepackFileDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.binary, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::models::SlatepackFileTarget {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <bool>::sse_encode(self.binary, serializer);
+        <bool>::sse_encode(self.overwrite, serializer);
+    }
+}
+
+impl SseEncode for crate::models::TorStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.running, serializer);
+        <Option<String>>::sse_encode(self.onion_address, serializer);
+        <Option<String>>::sse_encode(self.slatepack_address, serializer);
+    }
+}
+
+impl SseEncode for crate::models::TransactionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.parent_key_id, serializer);
+        <Option<String>>::sse_encode(self.tx_slate_id, serializer);
+        <String>::sse_encode(self.tx_type, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <String>::sse_encode(self.direction, serializer);
+        <String>::sse_encode(self.creation_ts, serializer);
+        <Option<String>>::sse_encode(self.confirmation_ts, serializer);
+        <bool>::sse_encode(self.confirmed, serializer);
+        <u64>::sse_encode(self.amount, serializer);
+        <Option<u64>>::sse_encode(self.fee, serializer);
+        <usize>::sse_encode(self.num_inputs, serializer);
+        <usize>::sse_encode(self.num_outputs, serializer);
+        <bool>::sse_encode(self.has_proof, serializer);
+        <crate::models::PaymentProofState>::sse_encode(self.payment_proof_state, serializer);
+        <Option<String>>::sse_encode(self.kernel_excess, serializer);
+        <Option<u64>>::sse_encode(self.ttl_cutoff_height, serializer);
+        <Option<u64>>::sse_encode(self.reverted_after_secs, serializer);
+        <u64>::sse_encode(self.confirmations, serializer);
+        <Option<crate::models::TxMetadataDto>>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::models::TxMetadataDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.note, serializer);
+        <Option<String>>::sse_encode(self.category, serializer);
+        <Option<String>>::sse_encode(self.counterparty, serializer);
+        <Option<String>>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -1516,6 +5528,52 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::models::UpdaterMessageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::models::UpdaterMessageDto::UpdatingOutputs { message } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::models::UpdaterMessageDto::UpdatingTransactions { message } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::models::UpdaterMessageDto::FullScanWarn { message } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::models::UpdaterMessageDto::Scanning { message, percent } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(message, serializer);
+                <u8>::sse_encode(percent, serializer);
+            }
+            crate::models::UpdaterMessageDto::ScanningComplete { message } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::models::UpdaterMessageDto::UpdateWarning { message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::models::UpdaterStatusDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.running, serializer);
+        <Option<u64>>::sse_encode(self.interval_secs, serializer);
+        <u64>::sse_encode(self.last_confirmed_height, serializer);
+        <Option<crate::models::UpdaterMessageDto>>::sse_encode(self.last_message, serializer);
+    }
+}
+
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1526,6 +5584,130 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::wallet::WalletChainType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::wallet::WalletChainType::Mainnet => 0,
+                crate::wallet::WalletChainType::Testnet => 1,
+                crate::wallet::WalletChainType::AutomatedTesting => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::WalletCloseReportDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.handle, serializer);
+        <bool>::sse_encode(self.foreign_listener_stopped, serializer);
+        <bool>::sse_encode(self.owner_listener_stopped, serializer);
+        <bool>::sse_encode(self.tor_stopped, serializer);
+        <bool>::sse_encode(self.updater_stopped, serializer);
+        <bool>::sse_encode(self.keychain_cleared, serializer);
+    }
+}
+
+impl SseEncode for crate::events::WalletEventDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::events::WalletEventDto::AutoLocked { handle, idle_secs } => {
+                <i32>::sse_encode(0, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <u64>::sse_encode(idle_secs, serializer);
+            }
+            crate::events::WalletEventDto::IncomingSlate { handle, tx } => {
+                <i32>::sse_encode(1, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <crate::models::TransactionDto>::sse_encode(tx, serializer);
+            }
+            crate::events::WalletEventDto::ConfirmationsChanged {
+                handle,
+                tx_id,
+                confirmations,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <u32>::sse_encode(tx_id, serializer);
+                <u64>::sse_encode(confirmations, serializer);
+            }
+            crate::events::WalletEventDto::BalanceChanged { handle, balance } => {
+                <i32>::sse_encode(3, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <crate::models::WalletInfoDto>::sse_encode(balance, serializer);
+            }
+            crate::events::WalletEventDto::TxReverted { handle, tx_id } => {
+                <i32>::sse_encode(4, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <u32>::sse_encode(tx_id, serializer);
+            }
+            crate::events::WalletEventDto::TxExpired {
+                handle,
+                tx_id,
+                tx_slate_id,
+                unlocked_amount,
+                unlocked_inputs,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <u32>::sse_encode(tx_id, serializer);
+                <Option<String>>::sse_encode(tx_slate_id, serializer);
+                <u64>::sse_encode(unlocked_amount, serializer);
+                <usize>::sse_encode(unlocked_inputs, serializer);
+            }
+            crate::events::WalletEventDto::ScanProgress { handle, progress } => {
+                <i32>::sse_encode(6, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <crate::models::ScanProgressDto>::sse_encode(progress, serializer);
+            }
+            crate::events::WalletEventDto::ScanCompleted { handle, result } => {
+                <i32>::sse_encode(7, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <crate::models::ScanResultDto>::sse_encode(result, serializer);
+            }
+            crate::events::WalletEventDto::UpdaterProgress { handle, message } => {
+                <i32>::sse_encode(8, serializer);
+                <u64>::sse_encode(handle, serializer);
+                <crate::models::UpdaterMessageDto>::sse_encode(message, serializer);
+            }
+            crate::events::WalletEventDto::NodeConnectivityChanged {
+                reachable,
+                tip_height,
+            } => {
+                <i32>::sse_encode(9, serializer);
+                <bool>::sse_encode(reachable, serializer);
+                <Option<u64>>::sse_encode(tip_height, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::models::WalletInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.refreshed_from_node, serializer);
+        <String>::sse_encode(self.active_account, serializer);
+        <u64>::sse_encode(self.last_confirmed_height, serializer);
+        <u64>::sse_encode(self.minimum_confirmations, serializer);
+        <u64>::sse_encode(self.total, serializer);
+        <u64>::sse_encode(self.amount_awaiting_finalization, serializer);
+        <u64>::sse_encode(self.amount_awaiting_confirmation, serializer);
+        <u64>::sse_encode(self.amount_immature, serializer);
+        <u64>::sse_encode(self.amount_currently_spendable, serializer);
+        <u64>::sse_encode(self.amount_locked, serializer);
+        <u64>::sse_encode(self.amount_reverted, serializer);
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
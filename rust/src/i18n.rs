@@ -0,0 +1,437 @@
+//! Meldungskatalog fuer alles, was die Bridge an Texten nach Dart liefert. Die Sprache
+//! stellt die App ueber `set_locale` passend zu ihrem `LocaleStore` ein; Standard ist Englisch.
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use crate::wallet::WalletHandle;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BridgeLocale {
+    En,
+    De,
+}
+
+static LOCALE: Lazy<Mutex<BridgeLocale>> = Lazy::new(|| Mutex::new(BridgeLocale::En));
+
+pub fn set_locale(locale: BridgeLocale) {
+    if let Ok(mut guard) = LOCALE.lock() {
+        *guard = locale;
+    }
+}
+
+pub fn current_locale() -> BridgeLocale {
+    LOCALE.lock().map(|l| *l).unwrap_or(BridgeLocale::En)
+}
+
+/// Schluessel des Katalogs. Parameter werden erst beim Rendern in die Sprache eingesetzt.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Msg<'a> {
+    RegistryLock,
+    RegistryRead,
+    WalletLock,
+    WalletNotOpen { handle: WalletHandle },
+    WalletAlreadyOpen { dir: &'a str, handle: WalletHandle },
+    NotInitialized { dir: &'a str },
+    NodeUrlEmpty,
+    NodeUrlScheme,
+    NodeUrlWrite,
+    NodeUrlRead,
+    NodeClientCreate,
+    NodeTip,
+    ChainTypeMismatch { stored: &'a str, requested: &'a str },
+    LcProvider,
+    TopLevelDirSet,
+    TopLevelDirRead,
+    WorkingDir,
+    DataDirCreate { dir: &'a str },
+    WalletConfigCreate,
+    WalletBackendCreate,
+    WalletCreate,
+    WalletInit,
+    WalletExistsCheck,
+    WalletOpen,
+    WalletClose,
+    WalletInstance,
+    SeedRead,
+    SeedEmpty,
+    SeedInvalid,
+    WalletRestore,
+    PasswordChangedReopen,
+    WrongPassword,
+    OldPasswordWrong,
+    PasswordChange,
+    AccountSet { label: &'a str },
+    AccountNameEmpty,
+    UnknownAccount { label: &'a str },
+    AmountZero,
+    InvalidSlatepackAddress { address: &'a str },
+    NoStoredSlate { tx_id: u32 },
+    PaymentProofParse,
+    DefaultTtlWrite,
+    InvoiceTxMissing { slate_id: &'a str },
+    PaymentProofSerialize,
+    ForeignRoute,
+    OwnerRoute,
+    ListenerAddrInvalid { addr: &'a str },
+    ListenerStart { addr: &'a str },
+    NoFreeListenPort { from: u16, to: u16 },
+    OwnerListenerRunning { addr: &'a str },
+    OwnerListenerStopped,
+    OwnerSecretCreate,
+    OwnerSecretRead,
+    OwnerSecretEmpty,
+    Keychain,
+    AddressKey,
+    OnionDerive,
+    SlatepackAddressDerive,
+    SlatepackAddressSerialize,
+    TorConfig,
+    TorProcess,
+    BackgroundTask,
+    UpdaterIntervalZero,
+    ScanCancelled { resume_height: u64 },
+    NoScanToResume,
+    UnknownCancelToken { token: u64 },
+    NothingToSend,
+    PaymentProofNeedsAddress,
+    LateLockFunds,
+    LateLockFee,
+    TorRecipientUnreachable,
+    EndpointUnreachable,
+    InvalidEndpointUrl { url: &'a str },
+    DirectSendTimeout { secs: u64 },
+    DirectSendPost,
+    ForeignClientCreate,
+    ForeignCall { method: &'a str },
+    SlateVersionUnsupported,
+    TorSocksConfig,
+    PayoutEmpty,
+    PayoutSendAll,
+    PayoutOverBudget { spendable: u64, needed: u64 },
+    SlatepackFileRead { path: &'a str },
+    SlatepackFileWrite { path: &'a str },
+    SlatepackFileTooLarge { path: &'a str, max_bytes: u64 },
+    SlatepackFileExists { path: &'a str },
+    QrMessageEmpty,
+    QrFrameInvalid,
+    QrChecksumMismatch,
+    QrSessionLock,
+    UnknownQrSession { session: u64 },
+    SlatepackNothingToDo { state: &'a str },
+    ReviewRequired,
+    ReviewNotSignable { state: &'a str },
+    NoPendingReview { slate_id: &'a str },
+    ReviewAmountMismatch { expected: u64, actual: u64 },
+    ReviewFeeTooHigh { max_fee: u64, fee: u64 },
+    UnknownTx { tx_id: u32 },
+    TxMetadataRead,
+    TxMetadataWrite,
+}
+
+/// Rendert eine Meldung in der aktuell eingestellten Sprache.
+pub(crate) fn tr(msg: Msg) -> String {
+    match current_locale() {
+        BridgeLocale::En => en(msg),
+        BridgeLocale::De => de(msg),
+    }
+}
+
+fn en(msg: Msg) -> String {
+    match msg {
+        Msg::RegistryLock => "Could not lock the wallet registry".into(),
+        Msg::RegistryRead => "Could not read the wallet registry".into(),
+        Msg::WalletLock => "Could not acquire the wallet lock".into(),
+        Msg::WalletNotOpen { handle } => {
+            format!("Wallet {handle} is not open. Call wallet_init_or_open first.")
+        }
+        Msg::WalletAlreadyOpen { dir, handle } => {
+            format!("Wallet in {dir} is already open as handle {handle}")
+        }
+        Msg::NotInitialized { dir } => format!("Wallet in {dir} has not been initialized yet"),
+        Msg::NodeUrlEmpty => "Node URL must not be empty".into(),
+        Msg::NodeUrlScheme => "Node URL must start with http:// or https://".into(),
+        Msg::NodeUrlWrite => "Could not set the node URL".into(),
+        Msg::NodeUrlRead => "Could not read the node URL".into(),
+        Msg::NodeClientCreate => "Could not create the node client".into(),
+        Msg::NodeTip => "Could not fetch the node tip".into(),
+        Msg::ChainTypeMismatch { stored, requested } => {
+            format!("Wallet was created for {stored} and cannot be opened as {requested}")
+        }
+        Msg::LcProvider => "Wallet lifecycle provider failed".into(),
+        Msg::TopLevelDirSet => "Could not set the wallet directory".into(),
+        Msg::TopLevelDirRead => "Could not read the wallet directory".into(),
+        Msg::WorkingDir => "Could not determine the working directory".into(),
+        Msg::DataDirCreate { dir } => format!("Could not create wallet directory: {dir}"),
+        Msg::WalletConfigCreate => "Could not create the wallet configuration".into(),
+        Msg::WalletBackendCreate => "Could not create the wallet backend".into(),
+        Msg::WalletCreate => "Could not create the wallet".into(),
+        Msg::WalletInit => "Wallet initialization failed".into(),
+        Msg::WalletExistsCheck => "Could not check whether the wallet exists".into(),
+        Msg::WalletOpen => "Could not open the wallet".into(),
+        Msg::WalletClose => "Could not close the wallet".into(),
+        Msg::WalletInstance => "Could not load the wallet instance".into(),
+        Msg::SeedRead => "Could not read the seed phrase".into(),
+        Msg::SeedEmpty => "Seed phrase must not be empty".into(),
+        Msg::SeedInvalid => "Invalid seed phrase".into(),
+        Msg::WalletRestore => "Could not restore the wallet from the seed".into(),
+        Msg::PasswordChangedReopen => {
+            "Password changed, but the wallet could not be reopened; open it with the new password"
+                .into()
+        }
+        Msg::WrongPassword => "Wrong password".into(),
+        Msg::OldPasswordWrong => "The old password is wrong".into(),
+        Msg::PasswordChange => "Could not change the password".into(),
+        Msg::AccountSet { label } => format!("Could not select account '{label}'"),
+        Msg::AccountNameEmpty => "Account name must not be empty".into(),
+        Msg::UnknownAccount { label } => format!("There is no account named '{label}'"),
+        Msg::AmountZero => "Amount must be greater than 0".into(),
+        Msg::InvalidSlatepackAddress { address } => {
+            format!("Invalid slatepack address: {address}")
+        }
+        Msg::NoStoredSlate { tx_id } => format!("No slatepack data found for tx {tx_id}"),
+        Msg::PaymentProofParse => "Could not read the payment proof".into(),
+        Msg::DefaultTtlWrite => "Could not save the default TTL".into(),
+        Msg::InvoiceTxMissing { slate_id } => {
+            format!("No transaction found for invoice {slate_id}")
+        }
+        Msg::PaymentProofSerialize => "Could not serialize the payment proof".into(),
+        Msg::ForeignRoute => "Could not register the foreign API route".into(),
+        Msg::OwnerRoute => "Could not register the owner API route".into(),
+        Msg::ListenerAddrInvalid { addr } => format!("Invalid listener address {addr}"),
+        Msg::ListenerStart { addr } => format!("Could not start the listener on {addr}"),
+        Msg::NoFreeListenPort { from, to } => {
+            format!("No free listener port between {from} and {to}")
+        }
+        Msg::OwnerListenerRunning { addr } => format!("Owner API is listening on {addr}"),
+        Msg::OwnerListenerStopped => "Owner API is not running".into(),
+        Msg::OwnerSecretCreate => "Could not create the owner API secret".into(),
+        Msg::OwnerSecretRead => "Could not read the owner API secret".into(),
+        Msg::OwnerSecretEmpty => "Owner API secret is empty".into(),
+        Msg::Keychain => "Could not load the keychain".into(),
+        Msg::AddressKey => "Could not derive the address key".into(),
+        Msg::OnionDerive => "Could not derive the onion v3 address".into(),
+        Msg::SlatepackAddressDerive => "Could not derive the slatepack address".into(),
+        Msg::SlatepackAddressSerialize => "Could not serialize the slatepack address".into(),
+        Msg::TorConfig => "Tor configuration failed".into(),
+        Msg::TorProcess => "Could not start the Tor process".into(),
+        Msg::BackgroundTask => "Background task failed".into(),
+        Msg::UpdaterIntervalZero => "Updater interval must be greater than 0".into(),
+        Msg::ScanCancelled { resume_height } => {
+            format!("Scan cancelled, it can be resumed at height {resume_height}")
+        }
+        Msg::NoScanToResume => "There is no interrupted scan to resume".into(),
+        Msg::UnknownCancelToken { token } => format!("Unknown cancel token {token}"),
+        Msg::NothingToSend => "There is no spendable balance to send".into(),
+        Msg::PaymentProofNeedsAddress => {
+            "A payment proof requires the recipient's slatepack address".into()
+        }
+        Msg::LateLockFunds => {
+            "The balance changed since the slatepack was created and no longer covers it".into()
+        }
+        Msg::LateLockFee => {
+            "The inputs available now would change the fee agreed with the recipient".into()
+        }
+        Msg::TorRecipientUnreachable => "Recipient not reachable over Tor".into(),
+        Msg::EndpointUnreachable => "Recipient endpoint not reachable".into(),
+        Msg::InvalidEndpointUrl { url } => format!("Not an http(s) URL: {url}"),
+        Msg::DirectSendTimeout { secs } => format!("No answer from recipient within {secs}s"),
+        Msg::ForeignClientCreate => "Could not set up the connection to the recipient".into(),
+        Msg::ForeignCall { method } => format!("Call '{method}' to the recipient failed"),
+        Msg::SlateVersionUnsupported => "Recipient does not accept slate version V4".into(),
+        Msg::TorSocksConfig => "Could not enable the Tor SOCKS port".into(),
+        Msg::PayoutEmpty => "The payout list is empty".into(),
+        Msg::PayoutSendAll => "Send-all is not possible in a batch payout".into(),
+        Msg::PayoutOverBudget { spendable, needed } => {
+            format!("Needs {needed} nanogrin including fee, only {spendable} left to spend")
+        }
+        Msg::SlatepackFileRead { path } => format!("Could not read slatepack file {path}"),
+        Msg::SlatepackFileWrite { path } => format!("Could not write slatepack file {path}"),
+        Msg::SlatepackFileTooLarge { path, max_bytes } => {
+            format!("{path} is larger than {max_bytes} bytes and not a slatepack")
+        }
+        Msg::SlatepackFileExists { path } => format!("{path} already exists"),
+        Msg::QrMessageEmpty => "Nothing to encode as QR code".into(),
+        Msg::QrFrameInvalid => "Not a slatepack QR frame".into(),
+        Msg::QrChecksumMismatch => "Checksum of the scanned slatepack does not match".into(),
+        Msg::QrSessionLock => "QR scan sessions could not be locked".into(),
+        Msg::UnknownQrSession { session } => format!("Unknown QR scan session {session}"),
+        Msg::SlatepackNothingToDo { state } => {
+            format!("Slatepack in state {state} needs no further processing")
+        }
+        Msg::ReviewRequired => "Incoming slatepacks must be reviewed and approved first".into(),
+        Msg::ReviewNotSignable { state } => {
+            format!("Only S1 and I1 slatepacks can be reviewed, not {state}")
+        }
+        Msg::NoPendingReview { slate_id } => format!("No reviewed slatepack with id {slate_id}"),
+        Msg::ReviewAmountMismatch { expected, actual } => {
+            format!("Amount is {actual} nanogrin, expected {expected}")
+        }
+        Msg::ReviewFeeTooHigh { max_fee, fee } => {
+            format!("Fee is {fee} nanogrin, more than the accepted {max_fee}")
+        }
+        Msg::UnknownTx { tx_id } => format!("There is no transaction with id {tx_id}"),
+        Msg::TxMetadataRead => "Could not read transaction notes".into(),
+        Msg::TxMetadataWrite => "Could not save transaction notes".into(),
+        Msg::DirectSendPost => {
+            "Transaction was finalized but could not be posted; repost it later".into()
+        }
+    }
+}
+
+fn de(msg: Msg) -> String {
+    match msg {
+        Msg::RegistryLock => "Wallet-Registry konnte nicht gesperrt werden".into(),
+        Msg::RegistryRead => "Wallet-Registry konnte nicht gelesen werden".into(),
+        Msg::WalletLock => "Wallet-Lock konnte nicht bezogen werden".into(),
+        Msg::WalletNotOpen { handle } => format!(
+            "Wallet {handle} ist nicht geoeffnet. Bitte wallet_init_or_open zuerst aufrufen."
+        ),
+        Msg::WalletAlreadyOpen { dir, handle } => {
+            format!("Wallet in {dir} ist bereits als Handle {handle} geoeffnet")
+        }
+        Msg::NotInitialized { dir } => format!("Wallet in {dir} ist noch nicht initialisiert"),
+        Msg::NodeUrlEmpty => "Node-URL darf nicht leer sein".into(),
+        Msg::NodeUrlScheme => "Node-URL muss mit http:// oder https:// beginnen".into(),
+        Msg::NodeUrlWrite => "Node-URL konnte nicht gesetzt werden".into(),
+        Msg::NodeUrlRead => "Node-URL konnte nicht gelesen werden".into(),
+        Msg::NodeClientCreate => "NodeClient konnte nicht erstellt werden".into(),
+        Msg::NodeTip => "Node Tip Fehler".into(),
+        Msg::ChainTypeMismatch { stored, requested } => format!(
+            "Wallet wurde fuer {stored} angelegt und kann nicht als {requested} geoeffnet werden"
+        ),
+        Msg::LcProvider => "LC-Provider fehlgeschlagen".into(),
+        Msg::TopLevelDirSet => "Top-Level-Verzeichnis konnte nicht gesetzt werden".into(),
+        Msg::TopLevelDirRead => "Top-Level-Verzeichnis konnte nicht gelesen werden".into(),
+        Msg::WorkingDir => "Arbeitsverzeichnis konnte nicht bestimmt werden".into(),
+        Msg::DataDirCreate { dir } => format!("Konnte Wallet-Verzeichnis nicht anlegen: {dir}"),
+        Msg::WalletConfigCreate => "Wallet-Konfiguration konnte nicht erstellt werden".into(),
+        Msg::WalletBackendCreate => "Wallet-Backend konnte nicht erstellt werden".into(),
+        Msg::WalletCreate => "Wallet konnte nicht erstellt werden".into(),
+        Msg::WalletInit => "Wallet-Initialisierung fehlgeschlagen".into(),
+        Msg::WalletExistsCheck => "Wallet-Existenz konnte nicht geprueft werden".into(),
+        Msg::WalletOpen => "Wallet konnte nicht geoeffnet werden".into(),
+        Msg::WalletClose => "Wallet konnte nicht geschlossen werden".into(),
+        Msg::WalletInstance => "Wallet-Instanz konnte nicht geladen werden".into(),
+        Msg::SeedRead => "Seedphrase konnte nicht gelesen werden".into(),
+        Msg::SeedEmpty => "Seedphrase darf nicht leer sein".into(),
+        Msg::SeedInvalid => "Seedphrase ungueltig".into(),
+        Msg::WalletRestore => "Wallet konnte nicht aus Seed wiederhergestellt werden".into(),
+        Msg::PasswordChangedReopen => {
+            "Passwort geaendert, aber Neuoeffnen fehlgeschlagen; bitte mit neuem Passwort oeffnen"
+                .into()
+        }
+        Msg::WrongPassword => "Falsches Passwort".into(),
+        Msg::OldPasswordWrong => "Altes Passwort ist falsch".into(),
+        Msg::PasswordChange => "Passwort konnte nicht geaendert werden".into(),
+        Msg::AccountSet { label } => format!("Account '{label}' konnte nicht gesetzt werden"),
+        Msg::AccountNameEmpty => "Account-Name darf nicht leer sein".into(),
+        Msg::UnknownAccount { label } => format!("Es gibt keinen Account '{label}'"),
+        Msg::AmountZero => "Betrag muss groesser als 0 sein".into(),
+        Msg::InvalidSlatepackAddress { address } => {
+            format!("Ungueltige Slatepack-Adresse: {address}")
+        }
+        Msg::NoStoredSlate { tx_id } => format!("Keine Slatepack-Daten fuer Tx {tx_id} gefunden"),
+        Msg::PaymentProofParse => "Payment Proof konnte nicht gelesen werden".into(),
+        Msg::DefaultTtlWrite => "Standard-TTL konnte nicht gespeichert werden".into(),
+        Msg::InvoiceTxMissing { slate_id } => format!("Keine Tx zur Rechnung {slate_id} gefunden"),
+        Msg::PaymentProofSerialize => "Payment Proof konnte nicht serialisiert werden".into(),
+        Msg::ForeignRoute => "Foreign-API-Route konnte nicht angelegt werden".into(),
+        Msg::OwnerRoute => "Owner-API-Route konnte nicht angelegt werden".into(),
+        Msg::ListenerAddrInvalid { addr } => format!("Ungueltige Listener-Adresse {addr}"),
+        Msg::ListenerStart { addr } => {
+            format!("Listener auf {addr} konnte nicht gestartet werden")
+        }
+        Msg::NoFreeListenPort { from, to } => {
+            format!("Kein freier Listener-Port zwischen {from} und {to}")
+        }
+        Msg::OwnerListenerRunning { addr } => format!("Owner-API lauscht auf {addr}"),
+        Msg::OwnerListenerStopped => "Owner-API laeuft nicht".into(),
+        Msg::OwnerSecretCreate => "Owner API Secret konnte nicht erstellt werden".into(),
+        Msg::OwnerSecretRead => "Owner API Secret konnte nicht gelesen werden".into(),
+        Msg::OwnerSecretEmpty => "Owner API Secret ist leer".into(),
+        Msg::Keychain => "Keychain konnte nicht geladen werden".into(),
+        Msg::AddressKey => "Address key konnte nicht abgeleitet werden".into(),
+        Msg::OnionDerive => "Onion v3 konnte nicht erzeugt werden".into(),
+        Msg::SlatepackAddressDerive => "Slatepack-Adresse konnte nicht erzeugt werden".into(),
+        Msg::SlatepackAddressSerialize => {
+            "Slatepack-Adresse konnte nicht serialisiert werden".into()
+        }
+        Msg::TorConfig => "Tor-Konfiguration fehlgeschlagen".into(),
+        Msg::TorProcess => "Tor-Prozessstart fehlgeschlagen".into(),
+        Msg::BackgroundTask => "Hintergrund-Task fehlgeschlagen".into(),
+        Msg::UpdaterIntervalZero => "Updater-Intervall muss groesser als 0 sein".into(),
+        Msg::ScanCancelled { resume_height } => {
+            format!("Scan abgebrochen, Fortsetzung ab Hoehe {resume_height} moeglich")
+        }
+        Msg::NoScanToResume => "Es gibt keinen unterbrochenen Scan zum Fortsetzen".into(),
+        Msg::UnknownCancelToken { token } => format!("Unbekanntes Abbruch-Token {token}"),
+        Msg::NothingToSend => "Es ist kein ausgebbares Guthaben vorhanden".into(),
+        Msg::PaymentProofNeedsAddress => {
+            "Ein Payment Proof braucht die Slatepack-Adresse des Empfaengers".into()
+        }
+        Msg::LateLockFunds => {
+            "Das Guthaben hat sich seit dem Slatepack geaendert und reicht nicht mehr".into()
+        }
+        Msg::LateLockFee => {
+            "Mit den jetzt verfuegbaren Inputs wuerde sich die vereinbarte Gebuehr aendern".into()
+        }
+        Msg::TorRecipientUnreachable => "Empfaenger ueber Tor nicht erreichbar".into(),
+        Msg::EndpointUnreachable => "Endpunkt des Empfaengers nicht erreichbar".into(),
+        Msg::InvalidEndpointUrl { url } => format!("Keine http(s)-URL: {url}"),
+        Msg::DirectSendTimeout { secs } => {
+            format!("Keine Antwort vom Empfaenger innerhalb von {secs}s")
+        }
+        Msg::ForeignClientCreate => {
+            "Verbindung zum Empfaenger konnte nicht eingerichtet werden".into()
+        }
+        Msg::ForeignCall { method } => format!("Aufruf '{method}' beim Empfaenger fehlgeschlagen"),
+        Msg::SlateVersionUnsupported => "Empfaenger akzeptiert Slate-Version V4 nicht".into(),
+        Msg::TorSocksConfig => "Tor-SOCKS-Port konnte nicht eingerichtet werden".into(),
+        Msg::PayoutEmpty => "Die Auszahlungsliste ist leer".into(),
+        Msg::PayoutSendAll => "Send-all ist bei Sammelauszahlungen nicht moeglich".into(),
+        Msg::PayoutOverBudget { spendable, needed } => {
+            format!("Benoetigt {needed} Nanogrin inklusive Fee, nur noch {spendable} verfuegbar")
+        }
+        Msg::SlatepackFileRead { path } => {
+            format!("Slatepack-Datei {path} konnte nicht gelesen werden")
+        }
+        Msg::SlatepackFileWrite { path } => {
+            format!("Slatepack-Datei {path} konnte nicht geschrieben werden")
+        }
+        Msg::SlatepackFileTooLarge { path, max_bytes } => {
+            format!("{path} ist groesser als {max_bytes} Bytes und kein Slatepack")
+        }
+        Msg::SlatepackFileExists { path } => format!("{path} existiert bereits"),
+        Msg::QrMessageEmpty => "Nichts zum Kodieren als QR-Code".into(),
+        Msg::QrFrameInvalid => "Kein Slatepack-QR-Frame".into(),
+        Msg::QrChecksumMismatch => "Pruefsumme des gescannten Slatepacks stimmt nicht".into(),
+        Msg::QrSessionLock => "QR-Scan-Sessions konnten nicht gesperrt werden".into(),
+        Msg::UnknownQrSession { session } => format!("Unbekannte QR-Scan-Session {session}"),
+        Msg::SlatepackNothingToDo { state } => {
+            format!("Slatepack im Status {state} muss nicht weiter verarbeitet werden")
+        }
+        Msg::ReviewRequired => {
+            "Eingehende Slatepacks muessen zuerst geprueft und freigegeben werden".into()
+        }
+        Msg::ReviewNotSignable { state } => {
+            format!("Nur S1- und I1-Slatepacks koennen geprueft werden, nicht {state}")
+        }
+        Msg::NoPendingReview { slate_id } => {
+            format!("Kein geprueftes Slatepack mit ID {slate_id}")
+        }
+        Msg::ReviewAmountMismatch { expected, actual } => {
+            format!("Betrag ist {actual} Nanogrin, erwartet waren {expected}")
+        }
+        Msg::ReviewFeeTooHigh { max_fee, fee } => {
+            format!("Gebuehr ist {fee} Nanogrin, mehr als die akzeptierten {max_fee}")
+        }
+        Msg::UnknownTx { tx_id } => format!("Es gibt keine Transaktion mit ID {tx_id}"),
+        Msg::TxMetadataRead => "Transaktionsnotizen konnten nicht gelesen werden".into(),
+        Msg::TxMetadataWrite => "Transaktionsnotizen konnten nicht gespeichert werden".into(),
+        Msg::DirectSendPost => {
+            "Tx wurde finalisiert, aber nicht gepostet; bitte spaeter erneut posten".into()
+        }
+    }
+}
@@ -1,5 +1,16 @@
 #![allow(unexpected_cfgs)]
 
 mod api;
+mod cancel;
+mod error;
+mod events;
+mod foreign_client;
 mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
+mod i18n;
+mod listener;
+mod models;
+mod node_client;
+mod qr;
+mod slatepack_file;
+mod tx_meta;
 mod wallet;
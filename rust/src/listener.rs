@@ -0,0 +1,75 @@
+//! Huelle um die Handler von Foreign- und Owner-Listener. Der `ApiServer` fuehrt Requests auf
+//! eigenen Tokio-Threads aus, die keine Wallet kennen; ohne die Huelle liefen sie mit dem
+//! globalen Chain-Typ, also dem der zuletzt geoeffneten Wallet.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use grin_api::{Handler, HandlerObj, ResponseFuture};
+use grin_core::global::{self, ChainTypes};
+use hyper::{Body, Request};
+
+/// Laeuft nach jedem beantworteten Request, z.B. um Events fuer eingegangene Slates zu melden.
+/// Wird im Poll der Response aufgerufen und muss deshalb sofort zurueckkehren.
+pub(crate) type RequestHook = Arc<dyn Fn() + Send + Sync>;
+
+pub(crate) struct ChainScopedHandler {
+    inner: HandlerObj,
+    chain_type: ChainTypes,
+    after: Option<RequestHook>,
+}
+
+impl ChainScopedHandler {
+    pub(crate) fn wrap(
+        inner: HandlerObj,
+        chain_type: ChainTypes,
+        after: Option<RequestHook>,
+    ) -> HandlerObj {
+        Arc::new(ChainScopedHandler {
+            inner,
+            chain_type,
+            after,
+        })
+    }
+}
+
+impl Handler for ChainScopedHandler {
+    fn call(
+        &self,
+        req: Request<Body>,
+        handlers: Box<dyn Iterator<Item = HandlerObj>>,
+    ) -> ResponseFuture {
+        global::set_local_chain_type(self.chain_type);
+        let inner = self.inner.call(req, handlers);
+        Box::pin(ChainScoped {
+            inner,
+            chain_type: self.chain_type,
+            after: self.after.clone(),
+        })
+    }
+}
+
+/// Setzt den Chain-Typ vor jedem Poll, weil Tokio die Future zwischen Threads verschieben kann.
+struct ChainScoped {
+    inner: ResponseFuture,
+    chain_type: ChainTypes,
+    after: Option<RequestHook>,
+}
+
+impl Future for ChainScoped {
+    type Output = <ResponseFuture as Future>::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        global::set_local_chain_type(this.chain_type);
+        let poll = this.inner.as_mut().poll(cx);
+        if poll.is_ready() {
+            if let Some(after) = this.after.take() {
+                after();
+            }
+        }
+        poll
+    }
+}
@@ -0,0 +1,646 @@
+//! Rueckgabetypen der Bridge. Werden von flutter_rust_bridge 1:1 nach Dart gespiegelt,
+//! Aenderungen an Feldern fallen dort also schon beim Kompilieren auf.
+
+use grin_util::ToHex;
+use grin_wallet_libwallet::{
+    InitTxArgs, OutputCommitMapping, PaymentProof, SlateState, StatusMessage, TxLogEntry,
+    TxLogEntryType, WalletInfo,
+};
+
+use crate::wallet::WalletHandle;
+
+/// Ergebnis von `create_wallet`: Handle der neuen Wallet plus Seedphrase zur Sicherung.
+#[derive(Clone, Debug)]
+pub struct CreatedWallet {
+    pub handle: WalletHandle,
+    pub mnemonic: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct WalletInfoDto {
+    pub refreshed_from_node: bool,
+    pub active_account: String,
+    pub last_confirmed_height: u64,
+    pub minimum_confirmations: u64,
+    pub total: u64,
+    pub amount_awaiting_finalization: u64,
+    pub amount_awaiting_confirmation: u64,
+    pub amount_immature: u64,
+    pub amount_currently_spendable: u64,
+    pub amount_locked: u64,
+    pub amount_reverted: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionDto {
+    pub id: u32,
+    /// Parent-Key (hex) des Accounts; `id` gilt nur innerhalb dieses Accounts.
+    pub parent_key_id: String,
+    pub tx_slate_id: Option<String>,
+    pub tx_type: String,
+    pub status: String,
+    pub direction: String,
+    pub creation_ts: String,
+    pub confirmation_ts: Option<String>,
+    pub confirmed: bool,
+    pub amount: u64,
+    pub fee: Option<u64>,
+    pub num_inputs: usize,
+    pub num_outputs: usize,
+    pub has_proof: bool,
+    pub payment_proof_state: PaymentProofState,
+    pub kernel_excess: Option<String>,
+    pub ttl_cutoff_height: Option<u64>,
+    pub reverted_after_secs: Option<u64>,
+    pub confirmations: u64,
+    /// Notizen aus dem Metadaten-Store der Bridge, falls vorhanden.
+    pub metadata: Option<TxMetadataDto>,
+}
+
+/// Stand des Zahlungsnachweises einer Tx.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaymentProofState {
+    None,
+    /// Angefordert, der Empfaenger hat aber noch nicht signiert.
+    Requested,
+    Received,
+}
+
+/// Vom Nutzer gepflegte Angaben zu einer Tx. `updated_at` setzt die Bridge beim Speichern.
+#[derive(Clone, Debug, Default)]
+pub struct TxMetadataDto {
+    pub note: Option<String>,
+    pub category: Option<String>,
+    pub counterparty: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct OutputDto {
+    pub commitment: String,
+    pub value: u64,
+    pub status: String,
+    pub height: u64,
+    pub lock_height: u64,
+    pub is_coinbase: bool,
+    pub mmr_index: Option<u64>,
+    pub tx_log_id: Option<u32>,
+    pub confirmations: u64,
+    pub spendable: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct AccountDto {
+    pub label: String,
+    pub path: String,
+    pub is_active: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct ScanResultDto {
+    pub delete_unconfirmed: bool,
+    pub start_height: Option<u64>,
+    pub backwards_from_tip: Option<u64>,
+    pub performed_at_epoch_secs: u64,
+}
+
+/// Ergebnis von `restore_wallet_from_seed`. `completed == false` heisst: Scan wurde abgebrochen
+/// und laesst sich ab `resume_height` fortsetzen.
+#[derive(Clone, Debug)]
+pub struct RestoreResultDto {
+    pub handle: WalletHandle,
+    pub completed: bool,
+    pub resume_height: Option<u64>,
+}
+
+/// Fortschritt eines Scans nach einem abgeschlossenen Hoehenfenster.
+#[derive(Clone, Debug)]
+pub struct ScanProgressDto {
+    pub start_height: u64,
+    pub current_height: u64,
+    pub tip_height: u64,
+    pub percent: u8,
+}
+
+/// Optionen fuer Senden und Gebuehrenschaetzung. `None` nimmt den bisherigen Standardwert.
+#[derive(Clone, Debug, Default)]
+pub struct SendOptions {
+    pub minimum_confirmations: Option<u64>,
+    pub max_outputs: Option<u32>,
+    pub num_change_outputs: Option<u32>,
+    pub selection_strategy_is_use_all: Option<bool>,
+    /// Gebuehr geht vom Betrag ab statt zusaetzlich aus dem Guthaben.
+    pub amount_includes_fee: Option<bool>,
+    /// Sendet das gesamte verfuegbare Guthaben abzueglich Gebuehr; der uebergebene Betrag
+    /// wird ignoriert.
+    pub send_all: Option<bool>,
+    /// Account, aus dem bezahlt wird; `None` nimmt den aktiven Account, ohne ihn zu aendern.
+    pub src_acct_name: Option<String>,
+    /// Payment Proof vom Empfaenger verlangen. `None` verlangt einen, sobald eine
+    /// Slatepack-Adresse angegeben ist.
+    pub request_payment_proof: Option<bool>,
+    /// Gueltigkeit in Bloecken; `None` nimmt den Standard der Wallet, 0 setzt keinen TTL.
+    pub ttl_blocks: Option<u64>,
+    /// Inputs erst beim Finalisieren auswaehlen und sperren statt schon beim Erzeugen von S1.
+    pub late_lock: Option<bool>,
+    /// Direkt gesendete Txs per Fluff statt Dandelion posten; Standard nein.
+    pub fluff: Option<bool>,
+}
+
+/// Ergebnis von `estimate_send`. `amount` ist, was beim Empfaenger ankommt. Bei
+/// `sufficient == false` ist `fee` die Gebuehr, die fuer den Betrag noetig waere, und
+/// `total_inputs` das verfuegbare Guthaben. `num_inputs` ist nachgerechnet, weil libwallet nur
+/// die Summe meldet; es bleibt eine Schaetzung, bis die Tx wirklich erzeugt wird.
+#[derive(Clone, Debug)]
+pub struct SendEstimateDto {
+    pub amount: u64,
+    pub fee: u64,
+    pub num_inputs: usize,
+    pub total_inputs: u64,
+    pub change: u64,
+    pub sufficient: bool,
+}
+
+/// Payment Proof in lesbaren Feldern; `proof_json` ist das Original fuer
+/// `wallet_verify_payment_proof`.
+#[derive(Clone, Debug)]
+pub struct PaymentProofDto {
+    pub tx_id: u32,
+    pub amount: u64,
+    pub excess: String,
+    pub recipient_address: String,
+    pub recipient_sig: String,
+    pub sender_address: String,
+    pub sender_sig: String,
+    pub proof_json: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct PaymentProofVerificationDto {
+    pub is_sender: bool,
+    pub is_recipient: bool,
+}
+
+/// Erwartung an ein eingehendes Slate; `review_slatepack` lehnt Abweichungen ab.
+#[derive(Clone, Debug, Default)]
+pub struct SlateExpectation {
+    pub amount: Option<u64>,
+    pub max_fee: Option<u64>,
+}
+
+/// Slate-Status in der Kurzform von grin-wallet (`S1` .. `I3`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlateCode {
+    S1,
+    S2,
+    S3,
+    I1,
+    I2,
+    I3,
+    Unknown,
+}
+
+/// Zusammenfassung vor dem Signieren. Bei I1 ist `fee` die Gebuehr, die wir beim Bezahlen
+/// tragen wuerden (geschaetzt mit den Optionen des Aufrufs), bei S1 die des Senders.
+#[derive(Clone, Debug)]
+pub struct SlateReviewDto {
+    pub slate_id: String,
+    /// `S1` (Empfang) oder `I1` (Rechnung).
+    pub state: SlateCode,
+    pub amount: u64,
+    pub fee: u64,
+    pub sender_address: Option<String>,
+    pub encrypted_to_us: bool,
+    pub ttl_cutoff_height: Option<u64>,
+    pub payment_proof_requested: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct SlateInspectionDto {
+    pub code: SlateCode,
+    pub slate_id: String,
+    pub state: String,
+    pub amount: u64,
+    pub fee: u64,
+    pub num_participants: u16,
+    pub kernel_excess: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TorStatusDto {
+    pub running: bool,
+    pub onion_address: Option<String>,
+    pub slatepack_address: Option<String>,
+}
+
+/// Ergebnis von `send_via_tor` und `send_via_http`. Ohne `delivered` muss Dart das
+/// `slatepack` wie bei einem normalen Send selbst weitergeben.
+#[derive(Clone, Debug)]
+pub struct DirectSendResultDto {
+    pub tx_slate_id: String,
+    pub delivered: bool,
+    pub slatepack: Option<String>,
+    pub fallback_reason: Option<String>,
+}
+
+/// Optionen fuer `process_slatepack`. `send` gilt nur, wenn eine Rechnung bezahlt wird.
+#[derive(Clone, Debug, Default)]
+pub struct ProcessSlatepackOptions {
+    pub send: SendOptions,
+    /// Finalisierte Tx gleich posten; Standard ja.
+    pub post_tx: Option<bool>,
+    pub fluff: Option<bool>,
+}
+
+/// Was `process_slatepack` mit einem Slatepack gemacht hat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlatepackAction {
+    Received,
+    PaidInvoice,
+    Finalized,
+}
+
+/// Ergebnis von `process_slatepack`; `response` geht nach `Received` und `PaidInvoice` an die
+/// Gegenseite zurueck.
+#[derive(Clone, Debug)]
+pub struct ProcessedSlatepackDto {
+    pub action: SlatepackAction,
+    pub slate_id: String,
+    /// Status des eingegangenen Slates (`S1`, `I1`, `S2`, `I2`).
+    pub state: SlateCode,
+    pub posted: bool,
+    pub response: String,
+}
+
+/// Stand einer QR-Scan-Session; `message` ist gesetzt, sobald alle Frames da sind.
+#[derive(Clone, Debug)]
+pub struct QrScanProgressDto {
+    pub received: u32,
+    pub total: u32,
+    pub message: Option<String>,
+}
+
+/// Ergebnis von `write_slatepack_file`; `path` inklusive ergaenzter Endung.
+#[derive(Clone, Debug)]
+pub struct SlatepackFileDto {
+    pub path: String,
+    pub binary: bool,
+    pub size_bytes: u64,
+}
+
+/// Datei, in die Senden, Empfangen, Rechnung bezahlen und Finalisieren ihr Slatepack
+/// zusaetzlich schreiben; Pfad und Flags wie bei `write_slatepack_file`.
+#[derive(Clone, Debug)]
+pub struct SlatepackFileTarget {
+    pub path: String,
+    pub binary: bool,
+    pub overwrite: bool,
+}
+
+/// Eine Zahlung eines Batch-Payouts. Leere `address` erzeugt ein unverschluesseltes Slatepack.
+#[derive(Clone, Debug)]
+pub struct PayoutItem {
+    pub address: String,
+    pub amount: u64,
+    pub memo: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoutStatus {
+    /// Direkt abgeschlossen.
+    Delivered,
+    /// Slatepack muss weitergegeben werden.
+    Manual,
+    /// Guthaben reicht samt Fee nicht mehr.
+    Skipped,
+    Failed,
+}
+
+#[derive(Clone, Debug)]
+pub struct PayoutResultDto {
+    pub address: String,
+    pub amount: u64,
+    pub memo: Option<String>,
+    pub status: PayoutStatus,
+    pub tx_slate_id: Option<String>,
+    pub fee: Option<u64>,
+    pub slatepack: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PayoutReportDto {
+    pub items: Vec<PayoutResultDto>,
+    pub delivered: u32,
+    pub manual: u32,
+    pub skipped: u32,
+    pub failed: u32,
+    /// Betrag plus Gebuehr aller gesendeten oder als Slatepack vorbereiteten Zahlungen.
+    pub total_committed: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct OwnerListenerStatusDto {
+    pub running: bool,
+    pub listen_addr: String,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct WalletCloseReportDto {
+    pub handle: WalletHandle,
+    pub foreign_listener_stopped: bool,
+    pub owner_listener_stopped: bool,
+    pub tor_stopped: bool,
+    pub updater_stopped: bool,
+    pub keychain_cleared: bool,
+}
+
+/// Statusmeldung des grin-wallet Updaters bzw. eines Scans.
+#[derive(Clone, Debug)]
+pub enum UpdaterMessageDto {
+    UpdatingOutputs { message: String },
+    UpdatingTransactions { message: String },
+    FullScanWarn { message: String },
+    Scanning { message: String, percent: u8 },
+    ScanningComplete { message: String },
+    UpdateWarning { message: String },
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdaterStatusDto {
+    pub running: bool,
+    pub interval_secs: Option<u64>,
+    pub last_confirmed_height: u64,
+    pub last_message: Option<UpdaterMessageDto>,
+}
+
+impl SendOptions {
+    pub(crate) const DEFAULT_MINIMUM_CONFIRMATIONS: u64 = 10;
+
+    pub(crate) fn minimum_confirmations(&self) -> u64 {
+        self.minimum_confirmations
+            .unwrap_or(Self::DEFAULT_MINIMUM_CONFIRMATIONS)
+    }
+
+    pub(crate) fn send_all(&self) -> bool {
+        self.send_all.unwrap_or(false)
+    }
+
+    /// Send-all braucht alle Outputs und zieht die Gebuehr vom Betrag ab.
+    pub(crate) fn use_all(&self) -> bool {
+        self.send_all() || self.selection_strategy_is_use_all.unwrap_or(false)
+    }
+
+    pub(crate) fn amount_includes_fee(&self) -> bool {
+        self.send_all() || self.amount_includes_fee.unwrap_or(false)
+    }
+
+    /// Weiche Obergrenze fuer Inputs; libwallet-Standard ist 500.
+    pub(crate) fn max_outputs(&self) -> u32 {
+        self.max_outputs.unwrap_or(500)
+    }
+
+    pub(crate) fn late_lock(&self) -> bool {
+        self.late_lock.unwrap_or(false)
+    }
+
+    pub(crate) fn fluff(&self) -> bool {
+        self.fluff.unwrap_or(false)
+    }
+
+    pub(crate) fn ttl_blocks(&self, wallet_default: Option<u64>) -> Option<u64> {
+        match self.ttl_blocks {
+            Some(0) => None,
+            Some(blocks) => Some(blocks),
+            None => wallet_default,
+        }
+    }
+
+    pub(crate) fn source_account(&self) -> Option<&str> {
+        self.src_acct_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+    }
+
+    pub(crate) fn init_tx_args(&self, amount: u64) -> InitTxArgs {
+        InitTxArgs {
+            src_acct_name: self.source_account().map(str::to_string),
+            amount,
+            amount_includes_fee: Some(self.amount_includes_fee()),
+            minimum_confirmations: self.minimum_confirmations(),
+            max_outputs: self.max_outputs(),
+            num_change_outputs: self.num_change_outputs.unwrap_or(1),
+            selection_strategy_is_use_all: self.use_all(),
+            ..Default::default()
+        }
+    }
+}
+
+impl WalletInfoDto {
+    pub(crate) fn from_info(
+        refreshed_from_node: bool,
+        info: WalletInfo,
+        active_account: String,
+    ) -> Self {
+        WalletInfoDto {
+            refreshed_from_node,
+            active_account,
+            last_confirmed_height: info.last_confirmed_height,
+            minimum_confirmations: info.minimum_confirmations,
+            total: info.total,
+            amount_awaiting_finalization: info.amount_awaiting_finalization,
+            amount_awaiting_confirmation: info.amount_awaiting_confirmation,
+            amount_immature: info.amount_immature,
+            amount_currently_spendable: info.amount_currently_spendable,
+            amount_locked: info.amount_locked,
+            amount_reverted: info.amount_reverted,
+        }
+    }
+
+    /// Vergleicht nur die Betraege; `refreshed_from_node` wechselt mit der Node-Verbindung.
+    pub(crate) fn same_balance(&self, other: &WalletInfoDto) -> bool {
+        self.last_confirmed_height == other.last_confirmed_height
+            && self.total == other.total
+            && self.amount_awaiting_finalization == other.amount_awaiting_finalization
+            && self.amount_awaiting_confirmation == other.amount_awaiting_confirmation
+            && self.amount_immature == other.amount_immature
+            && self.amount_currently_spendable == other.amount_currently_spendable
+            && self.amount_locked == other.amount_locked
+            && self.amount_reverted == other.amount_reverted
+            && self.active_account == other.active_account
+    }
+}
+
+impl SlateCode {
+    pub(crate) fn from_state(state: &SlateState) -> Self {
+        match state {
+            SlateState::Standard1 => SlateCode::S1,
+            SlateState::Standard2 => SlateCode::S2,
+            SlateState::Standard3 => SlateCode::S3,
+            SlateState::Invoice1 => SlateCode::I1,
+            SlateState::Invoice2 => SlateCode::I2,
+            SlateState::Invoice3 => SlateCode::I3,
+            SlateState::Unknown => SlateCode::Unknown,
+        }
+    }
+}
+
+impl TransactionDto {
+    pub(crate) fn from_entry(entry: TxLogEntry, confirmations: u64) -> Self {
+        let direction = tx_direction(&entry.tx_type).to_string();
+        let status = tx_status(&entry).to_string();
+        let amount = match direction.as_str() {
+            "sent" => entry
+                .amount_debited
+                .saturating_sub(entry.amount_credited)
+                .max(0),
+            _ => entry
+                .amount_credited
+                .saturating_sub(entry.amount_debited)
+                .max(0),
+        };
+        TransactionDto {
+            id: entry.id,
+            parent_key_id: entry.parent_key_id.to_hex(),
+            tx_slate_id: entry.tx_slate_id.map(|id| id.to_string()),
+            tx_type: format!("{:?}", entry.tx_type),
+            status,
+            direction,
+            creation_ts: entry.creation_ts.to_rfc3339(),
+            confirmation_ts: entry.confirmation_ts.map(|ts| ts.to_rfc3339()),
+            confirmed: entry.confirmed,
+            amount,
+            fee: entry.fee.as_ref().map(|f| f.fee()),
+            num_inputs: entry.num_inputs,
+            num_outputs: entry.num_outputs,
+            has_proof: entry.payment_proof.is_some(),
+            payment_proof_state: payment_proof_state(&entry),
+            kernel_excess: entry.kernel_excess.as_ref().map(|c| c.to_hex()),
+            ttl_cutoff_height: entry.ttl_cutoff_height,
+            reverted_after_secs: entry.reverted_after.map(|d| d.as_secs()),
+            confirmations,
+            metadata: None,
+        }
+    }
+}
+
+impl OutputDto {
+    pub(crate) fn from_mapping(mapping: OutputCommitMapping, current_height: u64) -> Self {
+        let output = mapping.output;
+        let confirmations = output.num_confirmations(current_height);
+        let spendable = output.eligible_to_spend(current_height, 10);
+        OutputDto {
+            commitment: mapping.commit.to_hex(),
+            value: output.value,
+            status: format!("{:?}", output.status),
+            height: output.height,
+            lock_height: output.lock_height,
+            is_coinbase: output.is_coinbase,
+            mmr_index: output.mmr_index,
+            tx_log_id: output.tx_log_entry,
+            confirmations,
+            spendable,
+        }
+    }
+}
+
+impl PaymentProofDto {
+    pub(crate) fn from_proof(tx_id: u32, proof: PaymentProof) -> Result<Self, serde_json::Error> {
+        Ok(PaymentProofDto {
+            tx_id,
+            amount: proof.amount,
+            excess: proof.excess.to_hex(),
+            recipient_address: proof.recipient_address.to_string(),
+            recipient_sig: proof.recipient_sig.to_bytes().to_hex(),
+            sender_address: proof.sender_address.to_string(),
+            sender_sig: proof.sender_sig.to_bytes().to_hex(),
+            proof_json: serde_json::to_string(&proof)?,
+        })
+    }
+}
+
+impl PayoutResultDto {
+    pub(crate) fn for_item(item: &PayoutItem, status: PayoutStatus) -> Self {
+        PayoutResultDto {
+            address: item.address.clone(),
+            amount: item.amount,
+            memo: item.memo.clone(),
+            status,
+            tx_slate_id: None,
+            fee: None,
+            slatepack: None,
+            error: None,
+        }
+    }
+}
+
+impl PayoutReportDto {
+    pub(crate) fn push(&mut self, result: PayoutResultDto) {
+        match result.status {
+            PayoutStatus::Delivered => self.delivered += 1,
+            PayoutStatus::Manual => self.manual += 1,
+            PayoutStatus::Skipped => self.skipped += 1,
+            PayoutStatus::Failed => self.failed += 1,
+        }
+        if let Some(fee) = result.fee {
+            self.total_committed = self
+                .total_committed
+                .saturating_add(result.amount)
+                .saturating_add(fee);
+        }
+        self.items.push(result);
+    }
+}
+
+impl UpdaterMessageDto {
+    pub(crate) fn from_status(status: StatusMessage) -> Self {
+        match status {
+            StatusMessage::UpdatingOutputs(message) => {
+                UpdaterMessageDto::UpdatingOutputs { message }
+            }
+            StatusMessage::UpdatingTransactions(message) => {
+                UpdaterMessageDto::UpdatingTransactions { message }
+            }
+            StatusMessage::FullScanWarn(message) => UpdaterMessageDto::FullScanWarn { message },
+            StatusMessage::Scanning(message, percent) => {
+                UpdaterMessageDto::Scanning { message, percent }
+            }
+            StatusMessage::ScanningComplete(message) => {
+                UpdaterMessageDto::ScanningComplete { message }
+            }
+            StatusMessage::UpdateWarning(message) => UpdaterMessageDto::UpdateWarning { message },
+        }
+    }
+}
+
+fn tx_direction(tx_type: &TxLogEntryType) -> &'static str {
+    match tx_type {
+        TxLogEntryType::TxSent | TxLogEntryType::TxSentCancelled => "sent",
+        TxLogEntryType::TxReverted => "reverted",
+        _ => "received",
+    }
+}
+
+fn payment_proof_state(entry: &TxLogEntry) -> PaymentProofState {
+    match &entry.payment_proof {
+        None => PaymentProofState::None,
+        Some(proof) if proof.receiver_signature.is_some() => PaymentProofState::Received,
+        Some(_) => PaymentProofState::Requested,
+    }
+}
+
+fn tx_status(entry: &TxLogEntry) -> &'static str {
+    match entry.tx_type {
+        TxLogEntryType::TxReceivedCancelled | TxLogEntryType::TxSentCancelled => "cancelled",
+        TxLogEntryType::TxReverted => "reverted",
+        _ => {
+            if entry.confirmed {
+                "confirmed"
+            } else {
+                "pending"
+            }
+        }
+    }
+}
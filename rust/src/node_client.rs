@@ -0,0 +1,159 @@
+//! `NodeClient` der Bridge: reicht alles an `HTTPNodeClient` durch, kann aber einen laufenden
+//! Scan abbrechen und dessen Hoehenbereich begrenzen. grin-wallet selbst bietet dafuer keinen
+//! Haken, der Scan fragt die Outputs aber stapelweise ueber diesen Client ab.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+use grin_core::core::{Transaction, TxKernel};
+use grin_util::secp::pedersen::{Commitment, RangeProof};
+use grin_wallet_impls::HTTPNodeClient;
+use grin_wallet_libwallet::{Error, HeaderInfo, NodeClient, NodeVersionInfo};
+
+/// Gemeinsamer Zustand zwischen Runtime und Node-Client fuer den gerade laufenden Scan. Der
+/// Client ist mit Updater und Listenern geteilt, deshalb gilt der Zustand nur fuer Aufrufe vom
+/// Thread, der den Scan angemeldet hat; libwallet scannt synchron auf diesem Thread.
+#[derive(Default)]
+pub(crate) struct ScanControl {
+    active: Mutex<Option<ActiveScan>>,
+}
+
+struct ActiveScan {
+    thread: ThreadId,
+    cancel: Option<Arc<AtomicBool>>,
+    end_height: Option<u64>,
+}
+
+impl ScanControl {
+    /// Meldet einen Scan auf dem aktuellen Thread an; `end_height` begrenzt ihn auf ein
+    /// Hoehenfenster.
+    pub(crate) fn begin(&self, cancel: Option<Arc<AtomicBool>>, end_height: Option<u64>) {
+        if let Ok(mut guard) = self.active.lock() {
+            *guard = Some(ActiveScan {
+                thread: thread::current().id(),
+                cancel,
+                end_height,
+            });
+        }
+    }
+
+    pub(crate) fn finish(&self) {
+        if let Ok(mut guard) = self.active.lock() {
+            *guard = None;
+        }
+    }
+
+    /// Wendet `f` auf den Scan an, falls er vom aufrufenden Thread stammt.
+    fn for_current_thread<R>(&self, f: impl FnOnce(&ActiveScan) -> R) -> Option<R> {
+        let guard = self.active.lock().ok()?;
+        guard
+            .as_ref()
+            .filter(|scan| scan.thread == thread::current().id())
+            .map(f)
+    }
+
+    fn cancelled(&self) -> bool {
+        self.for_current_thread(|scan| {
+            scan.cancel
+                .as_ref()
+                .map(|flag| flag.load(Ordering::SeqCst))
+                .unwrap_or(false)
+        })
+        .unwrap_or(false)
+    }
+
+    fn end_height(&self) -> Option<u64> {
+        self.for_current_thread(|scan| scan.end_height).flatten()
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct BridgeNodeClient {
+    inner: HTTPNodeClient,
+    scan: Arc<ScanControl>,
+}
+
+impl BridgeNodeClient {
+    pub(crate) fn new(inner: HTTPNodeClient, scan: Arc<ScanControl>) -> Self {
+        BridgeNodeClient { inner, scan }
+    }
+}
+
+impl NodeClient for BridgeNodeClient {
+    fn node_url(&self) -> &str {
+        self.inner.node_url()
+    }
+
+    fn set_node_url(&mut self, node_url: &str) {
+        self.inner.set_node_url(node_url)
+    }
+
+    fn node_api_secret(&self) -> Option<String> {
+        self.inner.node_api_secret()
+    }
+
+    fn set_node_api_secret(&mut self, node_api_secret: Option<String>) {
+        self.inner.set_node_api_secret(node_api_secret)
+    }
+
+    fn post_tx(&self, tx: &Transaction, fluff: bool) -> Result<(), Error> {
+        self.inner.post_tx(tx, fluff)
+    }
+
+    fn get_version_info(&mut self) -> Option<NodeVersionInfo> {
+        self.inner.get_version_info()
+    }
+
+    fn get_chain_tip(&self) -> Result<(u64, String), Error> {
+        self.inner.get_chain_tip()
+    }
+
+    fn get_header_info(&self, height: u64) -> Result<HeaderInfo, Error> {
+        self.inner.get_header_info(height)
+    }
+
+    fn get_kernel(
+        &mut self,
+        excess: &Commitment,
+        min_height: Option<u64>,
+        max_height: Option<u64>,
+    ) -> Result<Option<(TxKernel, u64, u64)>, Error> {
+        self.inner.get_kernel(excess, min_height, max_height)
+    }
+
+    fn get_outputs_from_node(
+        &self,
+        wallet_outputs: Vec<Commitment>,
+    ) -> Result<HashMap<Commitment, (String, u64, u64)>, Error> {
+        self.inner.get_outputs_from_node(wallet_outputs)
+    }
+
+    /// Wird vom Scan pro Stapel aufgerufen; hier greift der Abbruch.
+    fn get_outputs_by_pmmr_index(
+        &self,
+        start_index: u64,
+        end_index: Option<u64>,
+        max_outputs: u64,
+    ) -> Result<(u64, u64, Vec<(Commitment, RangeProof, bool, u64, u64)>), Error> {
+        if self.scan.cancelled() {
+            return Err(Error::ClientCallback("Scan cancelled".to_string()));
+        }
+        self.inner
+            .get_outputs_by_pmmr_index(start_index, end_index, max_outputs)
+    }
+
+    fn height_range_to_pmmr_indices(
+        &self,
+        start_height: u64,
+        end_height: Option<u64>,
+    ) -> Result<(u64, u64), Error> {
+        // Das Scan-Fenster begrenzt auch explizite Endhoehen von libwallet.
+        let end_height = end_height
+            .map(|h| self.scan.end_height().map_or(h, |w| h.min(w)))
+            .or(self.scan.end_height());
+        self.inner
+            .height_range_to_pmmr_indices(start_height, end_height)
+    }
+}
@@ -0,0 +1,269 @@
+//! Mehrteilige QR-Codes fuer Slatepacks. Ein Slatepack wird in nummerierte Text-Frames
+//! `GQR1:<frame-crc32>:<nr>/<anzahl>:<crc32>:<teil>` zerlegt, die Dart als animierten QR-Code
+//! zeigt. Die Gegenseite sammelt die Frames in beliebiger Reihenfolge ueber eine Scan-Session;
+//! die CRC32 ueber die ganze Nachricht erkennt den Wechsel auf ein anderes Slatepack, die
+//! Frame-CRC32 ueber den Rest des Frames verwirft falsch gelesene Frames einzeln.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+
+use crate::error::BridgeError;
+use crate::i18n::{tr, Msg};
+use crate::models::QrScanProgressDto;
+use crate::slatepack_file::MAX_SLATEPACK_FILE_BYTES;
+
+pub type QrSession = u64;
+
+const FRAME_PREFIX: &str = "GQR1";
+/// Passt bei mittlerer Fehlerkorrektur noch gut lesbar in einen QR-Code.
+const DEFAULT_FRAME_CHARS: usize = 400;
+const MIN_FRAME_CHARS: usize = 32;
+/// Mehr Frames kann ein Slatepack in Dateigroesse nicht haben; begrenzt die Vorab-Allokation.
+const MAX_FRAMES: usize = MAX_SLATEPACK_FILE_BYTES as usize / MIN_FRAME_CHARS;
+
+struct Assembly {
+    crc: u32,
+    parts: Vec<Option<String>>,
+}
+
+static SESSIONS: Lazy<Mutex<HashMap<QrSession, Option<Assembly>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_SESSION: AtomicU64 = AtomicU64::new(1);
+
+/// Zerlegt ein armored Slatepack in Frames mit hoechstens `max_chars` Zeichen Nutzlast.
+pub fn encode(message: &str, max_chars: Option<u32>) -> Result<Vec<String>> {
+    let message = message.trim();
+    if message.is_empty() {
+        return Err(invalid_frame(Msg::QrMessageEmpty));
+    }
+    let max_chars = max_chars
+        .map(|chars| chars as usize)
+        .unwrap_or(DEFAULT_FRAME_CHARS)
+        .max(MIN_FRAME_CHARS);
+    let chars: Vec<char> = message.chars().collect();
+    let chunks: Vec<String> = chars
+        .chunks(max_chars)
+        .map(|chunk| chunk.iter().collect())
+        .collect();
+    let total = chunks.len();
+    let crc = crc32(message.as_bytes());
+    Ok(chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let body = format!("{}/{total}:{crc:08x}:{chunk}", index + 1);
+            format!("{FRAME_PREFIX}:{:08x}:{body}", crc32(body.as_bytes()))
+        })
+        .collect())
+}
+
+pub fn create_session() -> QrSession {
+    let session = NEXT_SESSION.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut sessions) = SESSIONS.lock() {
+        sessions.insert(session, None);
+    }
+    session
+}
+
+/// Nimmt einen gescannten Frame an; doppelte Frames werden ignoriert. Ein kaputter Frame wird
+/// abgelehnt, die bisher gesammelten bleiben erhalten. Ein Frame eines anderen Slatepacks
+/// startet die Session neu, etwa wenn die Gegenseite inzwischen ein neues zeigt. Sobald alle
+/// Teile da sind und die Pruefsumme stimmt, steht die Nachricht in `message` und die Session
+/// ist leer.
+pub fn add_frame(session: QrSession, frame: &str) -> Result<QrScanProgressDto> {
+    let (index, total, crc, chunk) = parse_frame(frame.trim())?;
+    let mut sessions = SESSIONS
+        .lock()
+        .map_err(|_| anyhow!(tr(Msg::QrSessionLock)))?;
+    let slot = sessions
+        .get_mut(&session)
+        .ok_or_else(|| invalid_frame(Msg::UnknownQrSession { session }))?;
+    if slot
+        .as_ref()
+        .is_some_and(|assembly| assembly.crc != crc || assembly.parts.len() != total)
+    {
+        *slot = None;
+    }
+    let assembly = slot.get_or_insert_with(|| Assembly {
+        crc,
+        parts: vec![None; total],
+    });
+    if assembly.parts[index].is_none() {
+        assembly.parts[index] = Some(chunk.to_string());
+    }
+    let received = assembly.parts.iter().filter(|part| part.is_some()).count();
+    if received < total {
+        return Ok(QrScanProgressDto {
+            received: received as u32,
+            total: total as u32,
+            message: None,
+        });
+    }
+    let message: String = assembly
+        .parts
+        .iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    // Danach beginnt die Session fuer das naechste Slatepack von vorn.
+    *slot = None;
+    if crc32(message.as_bytes()) != crc {
+        return Err(BridgeError::InvalidSlatepack {
+            detail: tr(Msg::QrChecksumMismatch),
+        }
+        .into());
+    }
+    Ok(QrScanProgressDto {
+        received: received as u32,
+        total: total as u32,
+        message: Some(message),
+    })
+}
+
+pub fn release_session(session: QrSession) {
+    if let Ok(mut sessions) = SESSIONS.lock() {
+        sessions.remove(&session);
+    }
+}
+
+/// Liefert Index (ab 0), Anzahl, CRC der Nachricht und Nutzlast.
+fn parse_frame(frame: &str) -> Result<(usize, usize, u32, &str)> {
+    let fields: Vec<&str> = frame.splitn(3, ':').collect();
+    let [prefix, frame_crc, body] = fields[..] else {
+        return Err(invalid_frame(Msg::QrFrameInvalid));
+    };
+    let frame_crc =
+        u32::from_str_radix(frame_crc, 16).map_err(|_| invalid_frame(Msg::QrFrameInvalid))?;
+    if prefix != FRAME_PREFIX || crc32(body.as_bytes()) != frame_crc {
+        return Err(invalid_frame(Msg::QrFrameInvalid));
+    }
+    let fields: Vec<&str> = body.splitn(3, ':').collect();
+    let [position, crc, chunk] = fields[..] else {
+        return Err(invalid_frame(Msg::QrFrameInvalid));
+    };
+    if chunk.is_empty() {
+        return Err(invalid_frame(Msg::QrFrameInvalid));
+    }
+    let (number, total) = position
+        .split_once('/')
+        .and_then(|(number, total)| Some((number.parse::<usize>().ok()?, total.parse().ok()?)))
+        .ok_or_else(|| invalid_frame(Msg::QrFrameInvalid))?;
+    if number == 0 || number > total || total > MAX_FRAMES {
+        return Err(invalid_frame(Msg::QrFrameInvalid));
+    }
+    let crc = u32::from_str_radix(crc, 16).map_err(|_| invalid_frame(Msg::QrFrameInvalid))?;
+    Ok((number - 1, total, crc, chunk))
+}
+
+fn invalid_frame(msg: Msg) -> anyhow::Error {
+    BridgeError::InvalidInput { detail: tr(msg) }.into()
+}
+
+/// CRC-32 (IEEE), wie bei zlib; fuer ein paar KB reicht die bitweise Variante.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSAGE: &str = "BEGINSLATEPACK. 4H1qx1wHe668tFW yC2gfL8PPd8kSgv pcXQhyRkHbyKHZg \
+        GN75o7uWoT3dkib R2tj1fFGN2FoRLY GWmtgsneoXf7N4D uVWuyZSamPhfF1u AHRaYWvhF7jQvKx \
+        wNJAc7qmVm9JVcm NJLEw4k5BU7jY6S eb. ENDSLATEPACK.";
+
+    fn scan(session: QrSession, frames: &[String]) -> Result<QrScanProgressDto> {
+        let mut progress = None;
+        for frame in frames {
+            progress = Some(add_frame(session, frame)?);
+        }
+        Ok(progress.expect("at least one frame"))
+    }
+
+    #[test]
+    fn round_trip() {
+        let frames = encode(MESSAGE, Some(40)).unwrap();
+        assert!(frames.len() > 1);
+        let session = create_session();
+        let progress = scan(session, &frames).unwrap();
+        assert_eq!(progress.message.as_deref(), Some(MESSAGE));
+        assert_eq!(progress.received as usize, frames.len());
+        release_session(session);
+    }
+
+    #[test]
+    fn out_of_order_and_duplicate_frames() {
+        let mut frames = encode(MESSAGE, Some(40)).unwrap();
+        frames.reverse();
+        let session = create_session();
+        let first = add_frame(session, &frames[0]).unwrap();
+        let again = add_frame(session, &frames[0]).unwrap();
+        assert_eq!(first.received, 1);
+        assert_eq!(again.received, 1);
+        assert!(again.message.is_none());
+        let progress = scan(session, &frames[1..]).unwrap();
+        assert_eq!(progress.message.as_deref(), Some(MESSAGE));
+        release_session(session);
+    }
+
+    #[test]
+    fn frame_of_other_slatepack_restarts_assembly() {
+        let frames = encode(MESSAGE, Some(40)).unwrap();
+        let other_message = MESSAGE.replace("4H1qx", "5H1qx");
+        let other = encode(&other_message, Some(40)).unwrap();
+        let session = create_session();
+        scan(session, &frames[..2]).unwrap();
+        let restarted = add_frame(session, &other[1]).unwrap();
+        assert_eq!(restarted.received, 1);
+        assert_eq!(restarted.total as usize, other.len());
+        let progress = scan(session, &other).unwrap();
+        assert_eq!(progress.message.as_deref(), Some(other_message.as_str()));
+        release_session(session);
+    }
+
+    #[test]
+    fn corrupted_frame_is_rejected_alone() {
+        let frames = encode(MESSAGE, Some(40)).unwrap();
+        let corrupted = frames[1].replacen("/", "/1", 1);
+        let extended = format!("{}x", frames[1]);
+        let session = create_session();
+        add_frame(session, &frames[0]).unwrap();
+        assert!(add_frame(session, &corrupted).is_err());
+        assert!(add_frame(session, &extended).is_err());
+        let progress = scan(session, &frames[1..]).unwrap();
+        assert_eq!(progress.message.as_deref(), Some(MESSAGE));
+        release_session(session);
+    }
+
+    #[test]
+    fn bad_input() {
+        assert!(encode("   ", None).is_err());
+        let session = create_session();
+        for frame in [
+            "",
+            "GQR1",
+            "hello world",
+            "GQR1:zz:1/1:00000000:abc",
+            "GQR0:00000000:1/1:00000000:abc",
+        ] {
+            assert!(add_frame(session, frame).is_err(), "{frame}");
+        }
+        let body = format!("1/{}:00000000:abc", MAX_FRAMES + 1);
+        let oversized = format!("{FRAME_PREFIX}:{:08x}:{body}", crc32(body.as_bytes()));
+        assert!(add_frame(session, &oversized).is_err());
+        release_session(session);
+        assert!(add_frame(session, &encode(MESSAGE, None).unwrap()[0]).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! Slatepacks als Datei, fuer den Austausch per USB-Stick oder Filesharing. Gelesen wird
+//! armored Text wie auch die binaere Kodierung; nach aussen gibt die Bridge immer den armored
+//! String weiter, damit Senden, Empfangen, Rechnungen und Finalisieren unveraendert bleiben.
+
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use grin_wallet_libwallet::{SlatepackArmor, Slatepacker, SlatepackerArgs};
+
+use crate::error::BridgeError;
+use crate::i18n::{tr, Msg};
+use crate::models::{SlatepackFileDto, SlatepackFileTarget};
+
+/// Echte Slatepacks sind wenige KB gross; schuetzt vor versehentlich gewaehlten Riesendateien.
+pub(crate) const MAX_SLATEPACK_FILE_BYTES: u64 = 1024 * 1024;
+const SLATEPACK_EXTENSION: &str = "slatepack";
+
+/// Liest eine Slatepack-Datei (armored oder binaer) und gibt sie armored zurueck. Der
+/// Payload bleibt verschluesselt und wird wie bei eingefuegtem Text erst spaeter geoeffnet.
+pub fn read(path: &str) -> Result<String> {
+    let path = Path::new(path.trim());
+    let shown = path.to_string_lossy();
+    let size = fs::metadata(path)
+        .with_context(|| tr(Msg::SlatepackFileRead { path: &shown }))?
+        .len();
+    if size > MAX_SLATEPACK_FILE_BYTES {
+        return Err(BridgeError::InvalidSlatepack {
+            detail: tr(Msg::SlatepackFileTooLarge {
+                path: &shown,
+                max_bytes: MAX_SLATEPACK_FILE_BYTES,
+            }),
+        }
+        .into());
+    }
+    let data = fs::read(path).with_context(|| tr(Msg::SlatepackFileRead { path: &shown }))?;
+    // Texteditoren haengen gern BOM oder Leerzeilen an; binaere Slatepacks bleiben unangetastet.
+    let data = match std::str::from_utf8(&data) {
+        Ok(text) => text
+            .trim_start_matches('\u{feff}')
+            .trim()
+            .as_bytes()
+            .to_vec(),
+        Err(_) => data,
+    };
+    let packer = Slatepacker::new(SlatepackerArgs {
+        sender: None,
+        recipients: vec![],
+        dec_key: None,
+    });
+    let slatepack = packer.deser_slatepack(&data, false)?;
+    Ok(SlatepackArmor::encode(&slatepack)?)
+}
+
+/// Schreibt ein armored Slatepack in eine Datei, mit `binary` in der kompakten
+/// Binaerkodierung. Ein Pfad ohne Endung bekommt `.slatepack`. Eine vorhandene Datei wird nur
+/// mit `overwrite` ersetzt.
+pub fn write(message: &str, path: &str, binary: bool, overwrite: bool) -> Result<SlatepackFileDto> {
+    let message = message.trim();
+    // Dekodieren prueft auch im armored Fall, dass wirklich ein Slatepack geschrieben wird.
+    let bin = SlatepackArmor::decode(message)?;
+    let data = if binary {
+        bin
+    } else {
+        format!("{message}\n").into_bytes()
+    };
+    let path = target_path(path);
+    let shown = path.to_string_lossy().into_owned();
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .create_new(!overwrite)
+        .open(&path);
+    let mut file = match file {
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+            return Err(BridgeError::InvalidInput {
+                detail: tr(Msg::SlatepackFileExists { path: &shown }),
+            }
+            .into());
+        }
+        file => file.with_context(|| tr(Msg::SlatepackFileWrite { path: &shown }))?,
+    };
+    file.write_all(&data)
+        .with_context(|| tr(Msg::SlatepackFileWrite { path: &shown }))?;
+    Ok(SlatepackFileDto {
+        path: shown,
+        binary,
+        size_bytes: data.len() as u64,
+    })
+}
+
+/// Fuehrt `op` aus und schreibt dessen Slatepack zusaetzlich nach `target`. Das Ziel wird
+/// vorher geprueft, damit eine Tx nicht erst nach dem Signieren an einer vorhandenen Datei
+/// scheitert.
+pub(crate) fn with_output<F>(target: Option<SlatepackFileTarget>, op: F) -> Result<String>
+where
+    F: FnOnce() -> Result<String>,
+{
+    if let Some(target) = &target {
+        let path = target_path(&target.path);
+        if !target.overwrite && path.exists() {
+            return Err(BridgeError::InvalidInput {
+                detail: tr(Msg::SlatepackFileExists {
+                    path: &path.to_string_lossy(),
+                }),
+            }
+            .into());
+        }
+    }
+    let message = op()?;
+    if let Some(target) = target {
+        write(&message, &target.path, target.binary, target.overwrite)?;
+    }
+    Ok(message)
+}
+
+/// Ein Pfad ohne Endung bekommt `.slatepack`.
+fn target_path(path: &str) -> PathBuf {
+    let mut path = PathBuf::from(path.trim());
+    if path.extension().is_none() {
+        path.set_extension(SLATEPACK_EXTENSION);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grin_wallet_libwallet::Slate;
+
+    fn armored() -> String {
+        let packer = Slatepacker::new(SlatepackerArgs {
+            sender: None,
+            recipients: vec![],
+            dec_key: None,
+        });
+        let slatepack = packer.create_slatepack(&Slate::blank(2, false)).unwrap();
+        SlatepackArmor::encode(&slatepack).unwrap()
+    }
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("slatepack-file-{}-{name}", std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn round_trip_armored_and_binary() {
+        let message = armored();
+        for (name, binary) in [("armored", false), ("binary", true)] {
+            let written = write(&message, &temp_path(name), binary, true).unwrap();
+            assert!(written.path.ends_with(".slatepack"));
+            assert_eq!(written.binary, binary);
+            let data = fs::read(&written.path).unwrap();
+            assert_eq!(data.len() as u64, written.size_bytes);
+            assert_eq!(data.starts_with(b"BEGINSLATEPACK"), !binary);
+            assert_eq!(read(&written.path).unwrap(), message);
+            fs::remove_file(&written.path).unwrap();
+        }
+    }
+
+    #[test]
+    fn existing_target_is_checked_before_op() {
+        let message = armored();
+        let path = temp_path("existing");
+        let written = write(&message, &path, false, true).unwrap();
+        let target = SlatepackFileTarget {
+            path: path.clone(),
+            binary: false,
+            overwrite: false,
+        };
+        let result = with_output(Some(target.clone()), || panic!("op must not run"));
+        assert!(result.is_err());
+        let target = SlatepackFileTarget {
+            overwrite: true,
+            binary: true,
+            ..target
+        };
+        assert_eq!(
+            with_output(Some(target), || Ok(message.clone())).unwrap(),
+            message
+        );
+        assert!(!fs::read(&written.path)
+            .unwrap()
+            .starts_with(b"BEGINSLATEPACK"));
+        fs::remove_file(&written.path).unwrap();
+    }
+}
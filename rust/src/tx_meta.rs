@@ -0,0 +1,126 @@
+//! Notizen, Kategorien und Gegenparteien zu Transaktionen. grin-wallet hat dafuer keinen
+//! Platz im Tx-Log, die Bridge legt sie deshalb als JSON im Wallet-Verzeichnis ab. Schluessel
+//! ist die Slate-ID, wenn es eine gibt, sonst die Tx-Log-ID. Die zaehlt je Account, daher
+//! gehoert der Parent-Key des Accounts mit in den Schluessel.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use grin_keychain::Identifier;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{tr, Msg};
+use crate::models::TxMetadataDto;
+
+const TX_METADATA_FILE_NAME: &str = "tx_metadata.json";
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct StoredMeta {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    counterparty: Option<String>,
+    updated_at: String,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct StoredFile {
+    version: u32,
+    entries: BTreeMap<String, StoredMeta>,
+}
+
+pub(crate) struct TxMetaStore {
+    path: PathBuf,
+    file: StoredFile,
+}
+
+/// Fehlt die Datei, ist der Store leer.
+pub(crate) fn load(data_dir: &Path) -> Result<TxMetaStore> {
+    let path = data_dir.join(TX_METADATA_FILE_NAME);
+    let file = if path.exists() {
+        let raw = fs::read_to_string(&path).with_context(|| tr(Msg::TxMetadataRead))?;
+        serde_json::from_str(&raw).with_context(|| tr(Msg::TxMetadataRead))?
+    } else {
+        StoredFile {
+            version: 1,
+            ..StoredFile::default()
+        }
+    };
+    Ok(TxMetaStore { path, file })
+}
+
+impl TxMetaStore {
+    /// `tx` ist der Parent-Key des Accounts und die Tx-Log-ID darin.
+    pub(crate) fn get(
+        &self,
+        tx: Option<(&Identifier, u32)>,
+        slate_id: Option<&str>,
+    ) -> Option<TxMetadataDto> {
+        slate_id
+            .and_then(|id| self.file.entries.get(&slate_key(id)))
+            .or_else(|| tx.and_then(|(parent, id)| self.file.entries.get(&tx_key(parent, id))))
+            .map(|meta| TxMetadataDto {
+                note: meta.note.clone(),
+                category: meta.category.clone(),
+                counterparty: meta.counterparty.clone(),
+                updated_at: Some(meta.updated_at.clone()),
+            })
+    }
+
+    /// Ersetzt die Metadaten; leere Felder werden entfernt, ganz leere Eintraege geloescht.
+    /// Ein frueher nur per Tx-ID abgelegter Eintrag wandert zur Slate-ID.
+    pub(crate) fn set(
+        &mut self,
+        tx: Option<(&Identifier, u32)>,
+        slate_id: Option<&str>,
+        meta: TxMetadataDto,
+    ) {
+        let key = match (slate_id, tx) {
+            (Some(id), _) => slate_key(id),
+            (None, Some((parent, id))) => tx_key(parent, id),
+            (None, None) => return,
+        };
+        if let (Some(_), Some((parent, id))) = (slate_id, tx) {
+            self.file.entries.remove(&tx_key(parent, id));
+        }
+        let stored = StoredMeta {
+            note: cleaned(meta.note),
+            category: cleaned(meta.category),
+            counterparty: cleaned(meta.counterparty),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        if stored.note.is_none() && stored.category.is_none() && stored.counterparty.is_none() {
+            self.file.entries.remove(&key);
+        } else {
+            self.file.entries.insert(key, stored);
+        }
+    }
+
+    /// Schreibt ueber eine Temp-Datei, damit ein Absturz die Notizen nicht halb zerstoert.
+    pub(crate) fn save(&self) -> Result<()> {
+        let json =
+            serde_json::to_string_pretty(&self.file).with_context(|| tr(Msg::TxMetadataWrite))?;
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, json).with_context(|| tr(Msg::TxMetadataWrite))?;
+        fs::rename(&tmp, &self.path).with_context(|| tr(Msg::TxMetadataWrite))?;
+        Ok(())
+    }
+}
+
+fn slate_key(slate_id: &str) -> String {
+    format!("slate:{slate_id}")
+}
+
+fn tx_key(parent_key_id: &Identifier, tx_id: u32) -> String {
+    format!("tx:{}:{tx_id}", parent_key_id.to_hex())
+}
+
+fn cleaned(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
@@ -1,45 +1,70 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
+use std::net::{SocketAddr, TcpListener};
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex, Once};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard, Once, TryLockError};
 use std::thread;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Context, Result};
 use chrono::Utc;
+use futures::channel::oneshot;
+use grin_api::{ApiServer, BasicAuthMiddleware, Router};
 use grin_core::global::{self, ChainTypes};
-use grin_keychain::ExtKeychain;
+use grin_core::libtx::tx_fee;
+use grin_keychain::{ExtKeychain, Identifier};
 use grin_util::secp::key::SecretKey;
-use grin_util::{Mutex as GrinMutex, ToHex, ZeroingString};
+use grin_util::{to_base64, Mutex as GrinMutex, ToHex, ZeroingString};
 use grin_wallet_api::{Foreign, Owner};
 use grin_wallet_config::{
     config::{init_api_secret, API_SECRET_FILE_NAME, OWNER_API_SECRET_FILE_NAME},
     types::{TorConfig, WalletConfig},
-    WALLET_CONFIG_FILE_NAME,
+    GlobalWalletConfig, WALLET_CONFIG_FILE_NAME,
+};
+use grin_wallet_controller::command::{self, CancelArgs, RepostArgs};
+use grin_wallet_controller::controller::{
+    ForeignAPIHandlerV2, OwnerAPIHandlerV3, GRIN_OWNER_BASIC_REALM,
 };
-use grin_wallet_controller::command::{self, CancelArgs, CheckArgs, RepostArgs};
-use grin_wallet_controller::controller;
 use grin_wallet_impls::tor::{config as tor_config, process as tor_process};
 use grin_wallet_impls::{DefaultLCProvider, DefaultWalletImpl, HTTPNodeClient};
 use grin_wallet_libwallet::address;
 use grin_wallet_libwallet::{
-    self, api_impl::types::IssueInvoiceTxArgs, InitTxArgs, OutputCommitMapping, PaymentProof,
-    SlateState, SlatepackAddress, TxLogEntry, TxLogEntryType, WalletInfo, WalletInst,
+    self, api_impl::types::IssueInvoiceTxArgs, InitTxArgs, OutputData, PaymentProof, Slate,
+    SlateState, SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, WalletInst,
 };
 use grin_wallet_util::OnionV3Address;
 use once_cell::sync::Lazy;
-use serde::Serialize;
 use serde_json;
+use zeroize::Zeroize;
+
+use crate::cancel::{self, CancelToken};
+use crate::error::BridgeError;
+use crate::events::{self, WalletEventDto};
+use crate::foreign_client::ForeignClient;
+use crate::i18n::{tr, Msg};
+use crate::listener::{ChainScopedHandler, RequestHook};
+use crate::models::{
+    AccountDto, CreatedWallet, DirectSendResultDto, OutputDto, OwnerListenerStatusDto,
+    PaymentProofDto, PaymentProofVerificationDto, PayoutItem, PayoutReportDto, PayoutResultDto,
+    PayoutStatus, ProcessSlatepackOptions, ProcessedSlatepackDto, RestoreResultDto,
+    ScanProgressDto, ScanResultDto, SendEstimateDto, SendOptions, SlateCode, SlateExpectation,
+    SlateInspectionDto, SlateReviewDto, SlatepackAction, TorStatusDto, TransactionDto,
+    TxMetadataDto, UpdaterMessageDto, UpdaterStatusDto, WalletCloseReportDto, WalletInfoDto,
+};
+use crate::node_client::{BridgeNodeClient, ScanControl};
+use crate::tx_meta;
 
 type WalletBackendInstance = Arc<
     GrinMutex<
         Box<
             dyn WalletInst<
                 'static,
-                DefaultLCProvider<'static, HTTPNodeClient, ExtKeychain>,
-                HTTPNodeClient,
+                DefaultLCProvider<'static, BridgeNodeClient, ExtKeychain>,
+                BridgeNodeClient,
                 ExtKeychain,
             >,
         >,
@@ -47,221 +72,284 @@ type WalletBackendInstance = Arc<
 >;
 
 type OwnerApi =
-    Owner<DefaultLCProvider<'static, HTTPNodeClient, ExtKeychain>, HTTPNodeClient, ExtKeychain>;
+    Owner<DefaultLCProvider<'static, BridgeNodeClient, ExtKeychain>, BridgeNodeClient, ExtKeychain>;
+
+/// Keychain-Maske, die sich Foreign- und Owner-Listener mit der Runtime teilen.
+type SharedMask = Arc<GrinMutex<Option<SecretKey>>>;
+
+/// Opakes Handle einer geoeffneten Wallet; wird von open/create/restore vergeben.
+pub type WalletHandle = u64;
 
 struct WalletRuntime {
+    handle: WalletHandle,
     owner: OwnerApi,
     keychain_mask: Option<SecretKey>,
     config: WalletConfig,
     tor_config: TorConfig,
-    _data_dir: PathBuf,
+    data_dir: PathBuf,
     _node_url: String,
+    chain_type: ChainTypes,
     active_account: String,
+    listener_mask: SharedMask,
+    foreign_listener: Option<ListenerServer>,
+    tor: Option<TorRuntime>,
+    owner_listener: Option<ListenerServer>,
+    last_activity: Instant,
+    auto_lock_after: Option<Duration>,
+    /// Gesetzt, sobald `close_runtime` fertig ist; wer noch auf den Lock gewartet hat, bekommt
+    /// dann `WalletNotOpen` statt einer Runtime ohne Keychain.
+    closed: bool,
+    /// Laufender Refresh-Thread, `None` wenn gestoppt.
+    updater: Option<UpdaterThread>,
+    /// Letzte Statusmeldung des Owners; wird vom Status-Pump-Thread geschrieben.
+    updater_last_message: Arc<Mutex<Option<UpdaterMessageDto>>>,
+    /// Empfaenger des Owner-Statuskanals, bis `register_runtime` den Pump-Thread startet.
+    status_rx: Option<Receiver<StatusMessage>>,
+    /// Teilt sich der Node-Client, um laufende Scans abzubrechen oder zu begrenzen.
+    scan_control: Arc<ScanControl>,
+    /// TTL in Bloecken fuer neue Sends und Rechnungen, `None` ohne TTL.
+    default_ttl_blocks: Option<u64>,
+    /// Meldet Aenderungen an Dart; laeuft nach Updater-Refresh und Listener-Requests.
+    events: Arc<EventWatch>,
+    /// Eingehende S1/I1 nur ueber `review_slatepack` und `approve_slatepack` signieren.
+    require_review: bool,
+    /// Geprueft, aber noch nicht freigegeben; Schluessel ist die Slate-ID.
+    pending_reviews: HashMap<String, PendingReview>,
+}
+
+struct PendingReview {
+    message: String,
+    code: SlateCode,
+    amount: u64,
+    expectation: SlateExpectation,
 }
 
-static WALLET_RUNTIME: Lazy<Mutex<Option<WalletRuntime>>> = Lazy::new(|| Mutex::new(None));
-static CHAIN_INIT: Once = Once::new();
-static NODE_URL: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new("https://grincoin.org".to_string()));
-
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct WalletInfoDto {
-    refreshed_from_node: bool,
-    info: WalletInfo,
-    active_account: String,
+/// Chain, an die eine Wallet gebunden ist; steht als `chain_type` in `grin-wallet.toml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalletChainType {
+    Mainnet,
+    Testnet,
+    AutomatedTesting,
 }
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct TransactionDto {
-    id: u32,
-    tx_slate_id: Option<String>,
-    tx_type: String,
-    status: String,
-    direction: String,
-    creation_ts: String,
-    confirmation_ts: Option<String>,
-    confirmed: bool,
-    amount: u64,
-    fee: Option<u64>,
-    num_inputs: usize,
-    num_outputs: usize,
-    has_proof: bool,
-    kernel_excess: Option<String>,
-    ttl_cutoff_height: Option<u64>,
-    reverted_after_secs: Option<u64>,
-    confirmations: u64,
-}
-
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct OutputDto {
-    commitment: String,
-    value: u64,
-    status: String,
-    height: u64,
-    lock_height: u64,
-    is_coinbase: bool,
-    mmr_index: Option<u64>,
-    tx_log_id: Option<u32>,
-    confirmations: u64,
-    spendable: bool,
-}
-
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct AccountDto {
-    label: String,
-    path: String,
-    is_active: bool,
-}
-
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct ScanResultDto {
-    delete_unconfirmed: bool,
-    start_height: Option<u64>,
-    backwards_from_tip: Option<u64>,
-    performed_at_epoch_secs: u64,
+impl From<WalletChainType> for ChainTypes {
+    fn from(value: WalletChainType) -> Self {
+        match value {
+            WalletChainType::Mainnet => ChainTypes::Mainnet,
+            WalletChainType::Testnet => ChainTypes::Testnet,
+            WalletChainType::AutomatedTesting => ChainTypes::AutomatedTesting,
+        }
+    }
 }
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct PaymentProofDto {
-    tx_id: u32,
-    proof: PaymentProof,
-}
+/// Registry-Eintrag; das Verzeichnis liegt ausserhalb des Runtime-Locks, damit die
+/// Doppel-Oeffnen-Pruefung nicht auf laufende Operationen warten muss.
+struct WalletEntry {
+    data_dir: PathBuf,
+    events: Arc<EventWatch>,
+    runtime: Arc<Mutex<WalletRuntime>>,
+}
+
+static WALLETS: Lazy<Mutex<HashMap<WalletHandle, WalletEntry>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+static AUTO_LOCK_WATCHDOG: Once = Once::new();
+const AUTO_LOCK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// So lange wartet der Updater, bis er den Runtime-Lock erneut versucht.
+const UPDATER_LOCK_RETRY: Duration = Duration::from_millis(50);
+/// Scans laufen in Fenstern dieser Groesse, damit ein Abbruch nur das aktuelle Fenster verliert.
+const SCAN_WINDOW_BLOCKS: u64 = 10_000;
+/// Datei im Wallet-Verzeichnis mit der Hoehe, ab der ein abgebrochener Scan weiterlaeuft.
+const SCAN_RESUME_FILE_NAME: &str = "scan_resume_height";
+/// Datei im Wallet-Verzeichnis mit dem Standard-TTL in Bloecken; `0` heisst abgeschaltet.
+const DEFAULT_TTL_FILE_NAME: &str = "default_ttl_blocks";
+/// Zuletzt gemeldete Erreichbarkeit der Node, `None` bis zum ersten Refresh mit Listener.
+static NODE_REACHABLE: Lazy<Mutex<Option<bool>>> = Lazy::new(|| Mutex::new(None));
+/// Ab so vielen Bestaetigungen gilt eine Tx als final; danach keine Confirmation-Events mehr.
+const EVENT_CONFIRMATION_TARGET: u64 = 10;
+/// Standard-TTL, solange fuer die Wallet nichts gespeichert ist, etwa ein Tag.
+const DEFAULT_TTL_BLOCKS: u64 = 1440;
+/// Wartezeit je Aufruf an die Gegenseite bei direkten Sends; Onion-Verbindungen brauchen laenger.
+const HTTP_SEND_TIMEOUT: Duration = Duration::from_secs(60);
+const TOR_SEND_TIMEOUT: Duration = Duration::from_secs(180);
+static FEE_BASE_INIT: Once = Once::new();
+/// Ports, die Listener offener Wallets belegen oder gerade starten.
+static CLAIMED_PORTS: Lazy<Mutex<HashSet<u16>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+/// So viele Ports ab dem konfigurierten werden probiert, bevor das Oeffnen scheitert.
+const LISTEN_PORT_ATTEMPTS: u16 = 20;
+static ACTIVE_CHAIN_TYPE: Lazy<Mutex<ChainTypes>> = Lazy::new(|| Mutex::new(ChainTypes::Mainnet));
+static NODE_URL: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new("https://grincoin.org".to_string()));
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct PaymentProofVerificationDto {
-    is_sender: bool,
-    is_recipient: bool,
+struct TorRuntime {
+    process: Option<tor_process::TorProcess>,
+    onion: String,
+    slatepack: String,
+    /// SOCKS-Port desselben Prozesses; direkte Sends ueber Tor laufen hierueber.
+    socks_addr: String,
+    _socks_port: PortClaim,
 }
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct SlateInspectionDto {
-    code: String,
-    slate_id: String,
-    state: String,
-    amount: u64,
-    fee: u64,
-    num_participants: u16,
-    kernel_excess: Option<String>,
-}
-
-impl TransactionDto {
-    fn from_entry(entry: TxLogEntry, confirmations: u64) -> Self {
-        let direction = tx_direction(&entry.tx_type).to_string();
-        let status = tx_status(&entry).to_string();
-        let amount = match direction.as_str() {
-            "sent" => entry
-                .amount_debited
-                .saturating_sub(entry.amount_credited)
-                .max(0),
-            _ => entry
-                .amount_credited
-                .saturating_sub(entry.amount_debited)
-                .max(0),
-        };
-        TransactionDto {
-            id: entry.id,
-            tx_slate_id: entry.tx_slate_id.map(|id| id.to_string()),
-            tx_type: format!("{:?}", entry.tx_type),
-            status,
-            direction,
-            creation_ts: entry.creation_ts.to_rfc3339(),
-            confirmation_ts: entry.confirmation_ts.map(|ts| ts.to_rfc3339()),
-            confirmed: entry.confirmed,
-            amount,
-            fee: entry.fee.as_ref().map(|f| f.fee()),
-            num_inputs: entry.num_inputs,
-            num_outputs: entry.num_outputs,
-            has_proof: entry.payment_proof.is_some(),
-            kernel_excess: entry.kernel_excess.as_ref().map(|c| c.to_hex()),
-            ttl_cutoff_height: entry.ttl_cutoff_height,
-            reverted_after_secs: entry.reverted_after.map(|d| d.as_secs()),
-            confirmations,
+impl TorRuntime {
+    fn kill(mut self) -> bool {
+        match self.process.as_mut().and_then(|p| p.process.as_mut()) {
+            Some(child) => child.kill().is_ok(),
+            None => false,
         }
     }
 }
 
-impl OutputDto {
-    fn from_mapping(mapping: OutputCommitMapping, current_height: u64) -> Self {
-        let output = mapping.output;
-        let confirmations = output.num_confirmations(current_height);
-        let spendable = output.eligible_to_spend(current_height, 10);
-        OutputDto {
-            commitment: mapping.commit.to_hex(),
-            value: output.value,
-            status: format!("{:?}", output.status),
-            height: output.height,
-            lock_height: output.lock_height,
-            is_coinbase: output.is_coinbase,
-            mmr_index: output.mmr_index,
-            tx_log_id: output.tx_log_entry,
-            confirmations,
-            spendable,
+/// Eigener Refresh-Thread statt `Owner::start_updater`: den Thread von libwallet kann die
+/// Bridge nicht auf den Chain-Typ der Wallet einstellen.
+struct UpdaterThread {
+    interval: Duration,
+    stop: Arc<AtomicBool>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl UpdaterThread {
+    fn stop(mut self) -> bool {
+        self.stop.store(true, Ordering::SeqCst);
+        match self.thread.take() {
+            Some(thread) => thread.join().is_ok(),
+            None => false,
         }
     }
 }
 
-#[derive(Serialize, Clone, Default)]
-#[serde(rename_all = "camelCase")]
-struct TorStatusDto {
-    running: bool,
-    onion_address: Option<String>,
-    slatepack_address: Option<String>,
+/// HTTP-Listener (Foreign oder Owner API) auf eigenem `ApiServer`, damit er beim Schliessen
+/// der Wallet wirklich beendet wird statt mit geklonter Maske weiterzulaufen.
+struct ListenerServer {
+    server: ApiServer,
+    thread: Option<thread::JoinHandle<()>>,
+    listen_addr: String,
+    /// Gibt den Port beim Stoppen fuer andere Wallets frei.
+    _port: PortClaim,
 }
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-struct OwnerListenerStatusDto {
-    running: bool,
-    listen_addr: String,
-    message: Option<String>,
+/// Reservierter Listener-Port; wird beim Verwerfen wieder freigegeben.
+struct PortClaim(u16);
+
+impl Drop for PortClaim {
+    fn drop(&mut self) {
+        if let Ok(mut ports) = CLAIMED_PORTS.lock() {
+            ports.remove(&self.0);
+        }
+    }
 }
 
-struct TorRuntime {
-    process: Option<tor_process::TorProcess>,
-    onion: String,
-    slatepack: String,
+impl ListenerServer {
+    fn stop(mut self) -> bool {
+        let stopped = self.server.stop();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        log_listener_event(&format!("Listener on {} stopped", self.listen_addr));
+        stopped
+    }
 }
 
-static TOR_RUNTIME: Lazy<Mutex<Option<TorRuntime>>> = Lazy::new(|| Mutex::new(None));
+pub fn reset() {
+    let handles: Vec<WalletHandle> = match WALLETS.lock() {
+        Ok(wallets) => wallets.keys().copied().collect(),
+        Err(_) => return,
+    };
+    for handle in handles {
+        let _ = close_runtime(handle);
+    }
+}
 
-struct OwnerListenerRuntime {
-    _handle: thread::JoinHandle<()>,
-    listen_addr: String,
+pub fn close_wallet(handle: WalletHandle) -> Result<WalletCloseReportDto> {
+    close_runtime(handle)
 }
 
-static OWNER_LISTENER: Lazy<Mutex<Option<OwnerListenerRuntime>>> = Lazy::new(|| Mutex::new(None));
+/// Nimmt die Runtime aus der Registry und baut alles ab, was an ihr haengt: Listener,
+/// Tor-Prozess, Keychain im Backend und die Maske. Danach wird der `Owner` verworfen.
+fn close_runtime(handle: WalletHandle) -> Result<WalletCloseReportDto> {
+    let entry = runtime_entry(handle)?;
+    // Wartet, bis eine eventuell laufende Operation fertig ist.
+    let mut runtime = lock_runtime(&entry, handle)?;
+    unregister_runtime(handle)?;
+    Ok(shutdown_runtime(handle, &mut runtime))
+}
 
-pub fn reset() {
-    if let Ok(mut guard) = WALLET_RUNTIME.lock() {
-        *guard = None;
+/// Sperrt die Wallet nur, wenn sie unter dem Runtime-Lock noch immer untaetig ist. Ein Aufruf,
+/// der nach `idle_wallets` begonnen hat, hat `last_activity` inzwischen erneuert.
+fn auto_lock_runtime(handle: WalletHandle) -> Result<Option<Duration>> {
+    let entry = runtime_entry(handle)?;
+    let mut runtime = lock_runtime(&entry, handle)?;
+    let idle = runtime.last_activity.elapsed();
+    match runtime.auto_lock_after {
+        Some(timeout) if idle >= timeout => {}
+        _ => return Ok(None),
     }
-    if let Ok(mut t) = TOR_RUNTIME.lock() {
-        if let Some(mut rt) = t.take() {
-            if let Some(child) = rt.process.as_mut().and_then(|p| p.process.as_mut()) {
-                let _ = child.kill();
-            }
+    unregister_runtime(handle)?;
+    shutdown_runtime(handle, &mut runtime);
+    Ok(Some(idle))
+}
+
+fn unregister_runtime(handle: WalletHandle) -> Result<()> {
+    let mut wallets = WALLETS.lock().map_err(|_| anyhow!(tr(Msg::RegistryLock)))?;
+    wallets
+        .remove(&handle)
+        .map(|_| ())
+        .ok_or_else(|| wallet_not_open(handle))
+}
+
+fn shutdown_runtime(handle: WalletHandle, runtime: &mut WalletRuntime) -> WalletCloseReportDto {
+    runtime.closed = true;
+    let foreign_listener_stopped = runtime
+        .foreign_listener
+        .take()
+        .map(ListenerServer::stop)
+        .unwrap_or(false);
+    let owner_listener_stopped = runtime
+        .owner_listener
+        .take()
+        .map(ListenerServer::stop)
+        .unwrap_or(false);
+    let tor_stopped = runtime.tor.take().map(TorRuntime::kill).unwrap_or(false);
+    let updater_stopped = runtime
+        .updater
+        .take()
+        .map(UpdaterThread::stop)
+        .unwrap_or(false);
+
+    if let Some(mut mask) = runtime.listener_mask.lock().take() {
+        mask.0.zeroize();
+    }
+    let keychain_cleared = {
+        let mut w_lock = runtime.owner.wallet_inst.lock();
+        match w_lock.lc_provider() {
+            Ok(lc) => lc.close_wallet(None).is_ok(),
+            Err(_) => false,
         }
+    };
+    if let Some(mut mask) = runtime.keychain_mask.take() {
+        mask.0.zeroize();
+    }
+    log_listener_event(&format!("Wallet {handle} closed"));
+
+    WalletCloseReportDto {
+        handle,
+        foreign_listener_stopped,
+        owner_listener_stopped,
+        tor_stopped,
+        updater_stopped,
+        keychain_cleared,
     }
 }
 
 pub fn update_node_url(url: &str) -> Result<()> {
     let cleaned = url.trim();
     if cleaned.is_empty() {
-        return Err(anyhow!("Node-URL darf nicht leer sein"));
+        return Err(invalid_input(Msg::NodeUrlEmpty));
     }
     if !(cleaned.starts_with("http://") || cleaned.starts_with("https://")) {
-        return Err(anyhow!("Node-URL muss mit http:// oder https:// beginnen"));
+        return Err(invalid_input(Msg::NodeUrlScheme));
     }
     let mut guard = NODE_URL
         .lock()
-        .map_err(|_| anyhow!("Node-URL konnte nicht gesetzt werden"))?;
+        .map_err(|_| anyhow!(tr(Msg::NodeUrlWrite)))?;
     if *guard != cleaned {
         *guard = cleaned.to_string();
         reset();
@@ -272,57 +360,78 @@ pub fn update_node_url(url: &str) -> Result<()> {
 pub fn current_node_url() -> Result<String> {
     NODE_URL
         .lock()
-        .map_err(|_| anyhow!("Node-URL konnte nicht gelesen werden"))
+        .map_err(|_| anyhow!(tr(Msg::NodeUrlRead)))
         .map(|s| s.clone())
 }
 
 pub fn node_tip() -> Result<u64> {
-    ensure_chain_type();
+    ensure_chain_type(current_chain_type());
     let url = current_node_url()?;
-    let client = HTTPNodeClient::new(&url, None)
-        .map_err(|e| anyhow!("NodeClient konnte nicht erstellt werden: {e}"))?;
+    let client = HTTPNodeClient::new(&url, None).with_context(|| tr(Msg::NodeClientCreate))?;
     let (height, _) = client
         .chain_height()
-        .map_err(|e| anyhow!("Node Tip Fehler: {e}"))?;
+        .map_err(|e| BridgeError::NodeUnreachable {
+            detail: format!("{}: {e}", tr(Msg::NodeTip)),
+        })?;
     Ok(height)
 }
 
-pub fn init_or_open(data_dir: &str, passphrase: &str) -> Result<()> {
-    ensure_chain_type();
+pub fn init_or_open(
+    data_dir: &str,
+    passphrase: &str,
+    chain_type: Option<WalletChainType>,
+) -> Result<WalletHandle> {
     let resolved = resolve_data_dir(data_dir)?;
+    ensure_not_open(&resolved)?;
+    let requested = chain_type.map(ChainTypes::from);
+    let chain_type = match (requested, stored_chain_type(&resolved)) {
+        (Some(requested), Some(stored)) if requested != stored => {
+            return Err(BridgeError::InvalidInput {
+                detail: tr(Msg::ChainTypeMismatch {
+                    stored: &format!("{:?}", stored),
+                    requested: &format!("{:?}", requested),
+                }),
+            }
+            .into());
+        }
+        (Some(requested), _) => requested,
+        (None, Some(stored)) => stored,
+        (None, None) => ChainTypes::Mainnet,
+    };
+    ensure_chain_type(chain_type);
     let node_url = current_node_url()?;
-    let runtime = build_runtime(&resolved, passphrase, &node_url)?;
-    let mut guard = WALLET_RUNTIME
-        .lock()
-        .map_err(|_| anyhow!("Wallet-Lock konnte nicht bezogen werden"))?;
-    *guard = Some(runtime);
-    Ok(())
-}
-
-pub fn create_wallet(data_dir: &str, passphrase: &str, mnemonic_length: usize) -> Result<String> {
-    ensure_chain_type();
+    let runtime = build_runtime(&resolved, passphrase, &node_url, chain_type)?;
+    let handle = register_runtime(runtime)?;
+    spawn_expiry_sweep(handle);
+    Ok(handle)
+}
+
+pub fn create_wallet(
+    data_dir: &str,
+    passphrase: &str,
+    mnemonic_length: usize,
+    chain_type: WalletChainType,
+) -> Result<CreatedWallet> {
+    let chain_type = ChainTypes::from(chain_type);
+    ensure_chain_type(chain_type);
     let resolved = resolve_data_dir(data_dir)?;
+    ensure_not_open(&resolved)?;
     let node_url = current_node_url()?;
-    let mut wallet = build_wallet_backend(&resolved, &node_url)?;
-    let mut wallet_config = WalletConfig::default();
-    wallet_config.chain_type = Some(ChainTypes::Mainnet);
-    wallet_config.check_node_api_http_addr = node_url.clone();
-    wallet_config.data_file_dir = resolved.to_string_lossy().to_string();
+    let mut wallet = build_wallet_backend(&resolved, &node_url, Arc::default())?;
+    let wallet_config = base_wallet_config(&resolved, &node_url, chain_type);
 
     {
-        let lc = wallet
-            .lc_provider()
-            .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
+        let lc = wallet.lc_provider().with_context(|| tr(Msg::LcProvider))?;
         lc.set_top_level_directory(&wallet_config.data_file_dir)
-            .map_err(|e| anyhow!("Top-Level-Verzeichnis konnte nicht gesetzt werden: {e}"))?;
+            .with_context(|| tr(Msg::TopLevelDirSet))?;
         lc.create_config(
-            &ChainTypes::Mainnet,
+            &chain_type,
             WALLET_CONFIG_FILE_NAME,
             Some(wallet_config.clone()),
             None,
             None,
         )
-        .map_err(|e| anyhow!("Wallet-Konfiguration konnte nicht erstellt werden: {e}"))?;
+        .with_context(|| tr(Msg::WalletConfigCreate))?;
         lc.create_wallet(
             None,
             None,
@@ -330,205 +439,1123 @@ pub fn create_wallet(data_dir: &str, passphrase: &str, mnemonic_length: usize) -
             ZeroingString::from(passphrase),
             false,
         )
-        .map_err(|e| anyhow!("Wallet konnte nicht erstellt werden: {e}"))?;
+        .with_context(|| tr(Msg::WalletCreate))?;
         let phrase = lc
             .get_mnemonic(None, ZeroingString::from(passphrase))
-            .map_err(|e| anyhow!("Seedphrase konnte nicht gelesen werden: {e}"))?;
+            .with_context(|| tr(Msg::SeedRead))?;
         // Wallet ist jetzt erstellt, Runtime aufbauen
-        let runtime = build_runtime(&resolved, passphrase, &node_url)?;
-        let mut guard = WALLET_RUNTIME
-            .lock()
-            .map_err(|_| anyhow!("Wallet-Lock konnte nicht bezogen werden"))?;
-        *guard = Some(runtime);
-        return Ok(phrase.to_string());
+        let runtime = build_runtime(&resolved, passphrase, &node_url, chain_type)?;
+        let handle = register_runtime(runtime)?;
+        return Ok(CreatedWallet {
+            handle,
+            mnemonic: phrase.to_string(),
+        });
     }
 }
 
 pub fn seed_phrase(data_dir: &str, passphrase: &str) -> Result<String> {
     let resolved = resolve_data_dir(data_dir)?;
     let node_url = current_node_url()?;
-    let mut wallet = build_wallet_backend(&resolved, &node_url)?;
-    let lc = wallet
-        .lc_provider()
-        .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
+    let mut wallet = build_wallet_backend(&resolved, &node_url, Arc::default())?;
+    let lc = wallet.lc_provider().with_context(|| tr(Msg::LcProvider))?;
     let dir_string = resolved.to_string_lossy().to_string();
     lc.set_top_level_directory(&dir_string)
-        .map_err(|e| anyhow!("Top-Level-Verzeichnis konnte nicht gesetzt werden: {e}"))?;
+        .with_context(|| tr(Msg::TopLevelDirSet))?;
+    let exists = lc
+        .wallet_exists(None)
+        .with_context(|| tr(Msg::WalletExistsCheck))?;
+    if !exists {
+        return Err(BridgeError::NotInitialized {
+            detail: tr(Msg::NotInitialized { dir: &dir_string }),
+        }
+        .into());
+    }
     let mnemonic = lc
         .get_mnemonic(None, ZeroingString::from(passphrase))
-        .map_err(|e| anyhow!("Seedphrase konnte nicht gelesen werden: {e}"))?;
+        .map_err(|e| seed_password_error(e, Msg::WrongPassword))?;
     Ok(mnemonic.to_string())
 }
 
-pub fn restore_wallet_from_seed(data_dir: &str, passphrase: &str, mnemonic: &str) -> Result<()> {
-    ensure_chain_type();
+/// Stellt die Wallet aus der Seedphrase wieder her und scannt die Chain ab Hoehe 1. Bei Abbruch
+/// bleibt die Wallet offen und `resume_scan` setzt spaeter fort; bei anderen Fehlern wird sie
+/// geschlossen, die Fortsetzungshoehe bleibt fuer das naechste Oeffnen erhalten.
+pub fn restore_wallet_from_seed(
+    data_dir: &str,
+    passphrase: &str,
+    mnemonic: &str,
+    chain_type: WalletChainType,
+    cancel_token: Option<CancelToken>,
+) -> Result<RestoreResultDto> {
+    let chain_type = ChainTypes::from(chain_type);
+    ensure_chain_type(chain_type);
     let resolved = resolve_data_dir(data_dir)?;
+    ensure_not_open(&resolved)?;
     let node_url = current_node_url()?;
-    let mut wallet = build_wallet_backend(&resolved, &node_url)?;
-    let mut wallet_config = WalletConfig::default();
-    wallet_config.chain_type = Some(ChainTypes::Mainnet);
-    wallet_config.check_node_api_http_addr = node_url.clone();
-    wallet_config.data_file_dir = resolved.to_string_lossy().to_string();
+    let mut wallet = build_wallet_backend(&resolved, &node_url, Arc::default())?;
+    let wallet_config = base_wallet_config(&resolved, &node_url, chain_type);
 
     std::fs::create_dir_all(&wallet_config.data_file_dir).with_context(|| {
-        format!(
-            "Konnte Wallet-Verzeichnis nicht anlegen: {}",
-            wallet_config.data_file_dir
-        )
+        tr(Msg::DataDirCreate {
+            dir: &wallet_config.data_file_dir,
+        })
     })?;
 
     {
-        let lc = wallet
-            .lc_provider()
-            .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
+        let lc = wallet.lc_provider().with_context(|| tr(Msg::LcProvider))?;
         lc.set_top_level_directory(&wallet_config.data_file_dir)
-            .map_err(|e| anyhow!("Top-Level-Verzeichnis konnte nicht gesetzt werden: {e}"))?;
+            .with_context(|| tr(Msg::TopLevelDirSet))?;
         lc.create_config(
-            &ChainTypes::Mainnet,
+            &chain_type,
             WALLET_CONFIG_FILE_NAME,
             Some(wallet_config.clone()),
             None,
             None,
         )
-        .map_err(|e| anyhow!("Wallet-Konfiguration konnte nicht erstellt werden: {e}"))?;
+        .with_context(|| tr(Msg::WalletConfigCreate))?;
 
         let phrase_clean = mnemonic.trim().to_string();
         if phrase_clean.is_empty() {
-            return Err(anyhow!("Seedphrase darf nicht leer sein"));
+            return Err(invalid_input(Msg::SeedEmpty));
         }
         let phrase = ZeroingString::from(phrase_clean.clone());
         lc.validate_mnemonic(phrase.clone())
-            .map_err(|e| anyhow!("Seedphrase ungueltig: {e}"))?;
+            .map_err(|e| BridgeError::InvalidInput {
+                detail: format!("{}: {e}", tr(Msg::SeedInvalid)),
+            })?;
         lc.recover_from_mnemonic(phrase, ZeroingString::from(passphrase.to_string()))
-            .map_err(|e| anyhow!("Wallet konnte nicht aus Seed wiederhergestellt werden: {e}"))?;
+            .with_context(|| tr(Msg::WalletRestore))?;
     }
 
-    let runtime = build_runtime(&resolved, passphrase, &node_url)?;
-    let mut guard = WALLET_RUNTIME
-        .lock()
-        .map_err(|_| anyhow!("Wallet-Lock konnte nicht bezogen werden"))?;
-    *guard = Some(runtime);
+    let runtime = build_runtime(&resolved, passphrase, &node_url, chain_type)?;
+    let handle = register_runtime(runtime)?;
+    let scanned = with_cancel_flag(cancel_token, |cancel| {
+        with_runtime_mut(handle, |runtime| {
+            let tip = scan_tip(runtime)?;
+            scan_in_windows(handle, runtime, 1, tip, false, cancel.as_ref())
+        })
+    });
+    match scanned {
+        Ok(()) => Ok(RestoreResultDto {
+            handle,
+            completed: true,
+            resume_height: None,
+        }),
+        Err(err) if is_cancelled(&err) => Ok(RestoreResultDto {
+            handle,
+            completed: false,
+            resume_height: scan_resume_height(handle)?,
+        }),
+        Err(err) => {
+            let _ = close_runtime(handle);
+            Err(err)
+        }
+    }
+}
+
+/// Verschluesselt `wallet.seed` mit neuem Passwort. Der LC-Provider legt dabei ein Backup an
+/// und verwirft es erst, wenn sich der neue Seed mit derselben Mnemonic oeffnen laesst.
+/// Ein leeres neues Passwort ist erlaubt, wie beim Anlegen. Scheitert das Neuoeffnen nach der
+/// Aenderung, wird die Wallet geschlossen, weil die Runtime sonst mit ungueltiger Maske
+/// weiterliefe.
+pub fn change_password(handle: WalletHandle, old: &str, new: &str) -> Result<()> {
+    let reopened = with_runtime_mut(handle, |runtime| {
+        let mut w_lock = runtime.owner.wallet_inst.lock();
+        let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+        lc.get_mnemonic(None, ZeroingString::from(old))
+            .map_err(|e| seed_password_error(e, Msg::OldPasswordWrong))?;
+        lc.change_password(None, ZeroingString::from(old), ZeroingString::from(new))
+            .with_context(|| tr(Msg::PasswordChange))?;
+        drop(w_lock);
+        Ok(reopen_after_password_change(runtime, new))
+    })?;
+    if let Err(e) = reopened {
+        let _ = close_runtime(handle);
+        return Err(BridgeError::WalletNotOpen {
+            detail: format!("{}: {e}", tr(Msg::PasswordChangedReopen)),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn reopen_after_password_change(runtime: &mut WalletRuntime, new: &str) -> Result<()> {
+    let mask = {
+        let mut w_lock = runtime.owner.wallet_inst.lock();
+        let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+        lc.close_wallet(None)
+            .with_context(|| tr(Msg::WalletClose))?;
+        let mask = lc
+            .open_wallet(None, ZeroingString::from(new), false, false)
+            .with_context(|| tr(Msg::WalletOpen))?;
+        let wallet_inst = lc.wallet_inst().with_context(|| tr(Msg::WalletInstance))?;
+        wallet_inst
+            .set_parent_key_id_by_name(&runtime.active_account)
+            .with_context(|| {
+                tr(Msg::AccountSet {
+                    label: &runtime.active_account,
+                })
+            })?;
+        mask
+    };
+    if let Some(mut old_mask) = runtime.keychain_mask.take() {
+        old_mask.0.zeroize();
+    }
+    // Listener und Updater lesen die Maske bei jedem Aufruf aus `listener_mask`.
+    *runtime.listener_mask.lock() = mask.clone();
+    runtime.keychain_mask = mask;
     Ok(())
 }
 
-pub fn sync() -> Result<()> {
-    with_owner(|owner, mask| {
-        owner.scan(mask, None, false)?;
+pub fn sync(handle: WalletHandle) -> Result<()> {
+    with_runtime_mut(handle, |runtime| {
+        runtime
+            .owner
+            .scan(runtime.keychain_mask.as_ref(), None, false)?;
+        if let Err(err) = sweep_expired_txs(runtime) {
+            log_listener_event(&format!("TTL sweep for wallet {handle}: {err}"));
+        }
         Ok(())
-    })
+    })?;
+    events::emit(&WalletEventDto::ScanCompleted {
+        handle,
+        result: ScanResultDto {
+            delete_unconfirmed: false,
+            start_height: None,
+            backwards_from_tip: None,
+            performed_at_epoch_secs: epoch_secs(),
+        },
+    });
+    Ok(())
 }
 
-pub fn balance() -> Result<u64> {
-    with_owner(|owner, mask| {
+pub fn balance(handle: WalletHandle) -> Result<u64> {
+    with_owner(handle, |owner, mask| {
         let (_, info) = owner.retrieve_summary_info(mask, true, 10)?;
         Ok(info.amount_currently_spendable)
     })
 }
 
-pub fn slatepack_address() -> Result<String> {
-    with_owner(|owner, mask| {
+pub fn slatepack_address(handle: WalletHandle) -> Result<String> {
+    with_owner(handle, |owner, mask| {
         let addr = owner.get_slatepack_address(mask, 0)?;
         Ok(addr.to_string())
     })
 }
 
-pub fn send_slatepack(to: &str, amount: u64) -> Result<String> {
-    if amount == 0 {
-        return Err(anyhow!("Betrag muss groesser als 0 sein"));
+pub fn send_slatepack(
+    handle: WalletHandle,
+    to: &str,
+    amount: u64,
+    options: SendOptions,
+) -> Result<String> {
+    if amount == 0 && !options.send_all() {
+        return Err(invalid_input(Msg::AmountZero));
     }
     let trimmed = to.trim();
-    let recipients: Vec<SlatepackAddress> = if trimmed.is_empty() {
-        Vec::new()
+    let recipient = if trimmed.is_empty() {
+        None
     } else {
-        vec![SlatepackAddress::try_from(trimmed)
-            .map_err(|_| anyhow!("Ungueltige Slatepack-Adresse: {}", to))?]
+        Some(parse_slatepack_address(trimmed)?)
     };
-    with_owner(move |owner, mask| {
-        let init_args = InitTxArgs {
-            src_acct_name: None,
-            amount,
-            amount_includes_fee: Some(false),
-            minimum_confirmations: 10,
-            max_outputs: 500,
-            num_change_outputs: 1,
-            selection_strategy_is_use_all: false,
-            ..Default::default()
-        };
-        let slate = owner.init_send_tx(mask, init_args)?;
-        let message = owner.create_slatepack_message(mask, &slate, Some(0), recipients)?;
-        owner.tx_lock_outputs(mask, &slate)?;
+    let proof_recipient = payment_proof_recipient(recipient.as_ref(), &options)?;
+    with_runtime_mut(handle, move |runtime| {
+        let mask = runtime.keychain_mask.as_ref();
+        let slate = init_send(runtime, amount, proof_recipient, &options)?;
+        let message = runtime.owner.create_slatepack_message(
+            mask,
+            &slate,
+            Some(0),
+            recipient.into_iter().collect(),
+        )?;
+        lock_send_outputs(runtime, &slate, &options)?;
         Ok(message)
     })
 }
 
-pub fn receive_slatepack(message: &str) -> Result<String> {
-    let msg = message.to_string();
-    with_owner(|owner, mask| {
-        let slate = owner.slate_from_slatepack_message(mask, msg.clone(), vec![0])?;
-        let decoded = owner.decode_slatepack_message(mask, msg.clone(), vec![0])?;
-        let foreign = Foreign::new(owner.wallet_inst.clone(), mask.cloned(), None, false);
-        let received = foreign.receive_tx(&slate, None, None)?;
-        let mut recipients = Vec::new();
-        if let Some(sender) = decoded.sender {
-            recipients.push(sender);
+/// Sendet direkt an den Foreign API des Empfaengers ueber Tor (S1 -> S2), finalisiert und
+/// postet. Ist der Empfaenger nicht erreichbar, kommt stattdessen das S1-Slatepack zurueck,
+/// die Tx bleibt dann wie bei `send_slatepack` offen.
+pub fn send_via_tor(
+    handle: WalletHandle,
+    address: &str,
+    amount: u64,
+    options: SendOptions,
+) -> Result<DirectSendResultDto> {
+    if amount == 0 && !options.send_all() {
+        return Err(invalid_input(Msg::AmountZero));
+    }
+    let recipient = parse_slatepack_address(address.trim())?;
+    let onion = OnionV3Address::try_from(&recipient).map_err(|_| BridgeError::InvalidAddress {
+        detail: tr(Msg::InvalidSlatepackAddress { address }),
+    })?;
+    let proof_recipient = payment_proof_recipient(Some(&recipient), &options)?;
+    let (slate, client) = prepare_direct_send(handle, amount, proof_recipient, &options, |rt| {
+        tor_client(rt, &onion)
+    })?;
+    finish_direct_send(
+        handle,
+        slate,
+        client,
+        vec![recipient],
+        Msg::TorRecipientUnreachable,
+        options.fluff(),
+    )
+}
+
+/// Client ueber den SOCKS-Port des Tor-Prozesses der Wallet; laeuft noch keiner, wird er wie
+/// bei `tor_start` gestartet und bleibt fuer weitere Sends und den Empfang offen.
+fn tor_client(runtime: &mut WalletRuntime, onion: &OnionV3Address) -> Result<ForeignClient> {
+    if runtime.tor.is_none() {
+        start_tor(runtime, "")?;
+    }
+    let socks_addr = runtime
+        .tor
+        .as_ref()
+        .map(|tor| tor.socks_addr.clone())
+        .unwrap_or_default();
+    ForeignClient::tor(&onion.to_http_str(), &socks_addr, TOR_SEND_TIMEOUT)
+}
+
+/// Zahlt mehrere Empfaenger nacheinander aus, mit `via_tor` direkt, sonst per Slatepack.
+/// Jede Zahlung sperrt ihre Inputs sofort, damit keine zwei dieselben Outputs verplanen;
+/// Late-Lock ist deshalb aus, Send-all nicht erlaubt. Ein Fehler beendet den Batch nicht.
+/// Die Wallet ist nur je Zahlung gesperrt, nicht waehrend des Austauschs ueber Tor.
+pub fn send_batch(
+    handle: WalletHandle,
+    items: Vec<PayoutItem>,
+    via_tor: bool,
+    options: SendOptions,
+) -> Result<PayoutReportDto> {
+    if items.is_empty() {
+        return Err(invalid_input(Msg::PayoutEmpty));
+    }
+    if options.send_all() {
+        return Err(invalid_input(Msg::PayoutSendAll));
+    }
+    let options = SendOptions {
+        late_lock: Some(false),
+        ..options
+    };
+    let mut report = PayoutReportDto::default();
+    for item in items {
+        if item.amount == 0 {
+            let mut result = PayoutResultDto::for_item(&item, PayoutStatus::Failed);
+            result.error = Some(tr(Msg::AmountZero));
+            report.push(result);
+            continue;
         }
-        let response = owner.create_slatepack_message(mask, &received, Some(0), recipients)?;
-        Ok(response)
+        let result = run_payout(handle, &item, via_tor, &options)
+            .unwrap_or_else(|err| payout_failure(&item, err));
+        report.push(result);
+    }
+    let stored = with_runtime(handle, |runtime| {
+        store_payout_memos(runtime, &report);
+        Ok(())
+    });
+    if let Err(err) = stored {
+        log_listener_event(&format!("Storing payout memos: {err:#}"));
+    }
+    Ok(report)
+}
+
+/// Reicht das Guthaben samt Fee nicht, meldet libwallet `NotEnoughFunds`; die Zahlung wird
+/// dann uebersprungen. Alle anderen Fehler gelten als fehlgeschlagen.
+fn payout_failure(item: &PayoutItem, err: anyhow::Error) -> PayoutResultDto {
+    let libwallet = err
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<grin_wallet_libwallet::Error>());
+    if let Some(grin_wallet_libwallet::Error::NotEnoughFunds {
+        available, needed, ..
+    }) = libwallet
+    {
+        let mut result = PayoutResultDto::for_item(item, PayoutStatus::Skipped);
+        result.error = Some(tr(Msg::PayoutOverBudget {
+            spendable: *available,
+            needed: *needed,
+        }));
+        return result;
+    }
+    let mut result = PayoutResultDto::for_item(item, PayoutStatus::Failed);
+    result.error = Some(BridgeError::from(err).detail());
+    result
+}
+
+/// Memos landen als Notiz im Metadaten-Store. Scheitert das, bleibt der Report trotzdem gueltig.
+fn store_payout_memos(runtime: &WalletRuntime, report: &PayoutReportDto) {
+    let stored = tx_meta::load(&runtime.data_dir).and_then(|mut meta| {
+        for item in &report.items {
+            if let (Some(memo), Some(slate_id)) = (&item.memo, &item.tx_slate_id) {
+                let metadata = TxMetadataDto {
+                    note: Some(memo.clone()),
+                    ..TxMetadataDto::default()
+                };
+                meta.set(None, Some(slate_id.as_str()), metadata);
+            }
+        }
+        meta.save()
+    });
+    if let Err(err) = stored {
+        log_listener_event(&format!("Storing payout memos: {err:#}"));
+    }
+}
+
+fn run_payout(
+    handle: WalletHandle,
+    item: &PayoutItem,
+    via_tor: bool,
+    options: &SendOptions,
+) -> Result<PayoutResultDto> {
+    let address = item.address.trim();
+    let recipient = if address.is_empty() {
+        None
+    } else {
+        Some(parse_slatepack_address(address)?)
+    };
+    let onion = match recipient.as_ref() {
+        Some(recipient) if via_tor => {
+            Some(
+                OnionV3Address::try_from(recipient).map_err(|_| BridgeError::InvalidAddress {
+                    detail: tr(Msg::InvalidSlatepackAddress { address }),
+                })?,
+            )
+        }
+        _ => None,
+    };
+    let proof_recipient = payment_proof_recipient(recipient.as_ref(), options)?;
+    let recipients: Vec<SlatepackAddress> = recipient.into_iter().collect();
+    let (fee, sent) = match onion {
+        Some(onion) => {
+            let (slate, client) =
+                prepare_direct_send(handle, item.amount, proof_recipient, options, |rt| {
+                    tor_client(rt, &onion)
+                })?;
+            let fee = slate.fee_fields.fee();
+            let sent = finish_direct_send(
+                handle,
+                slate,
+                client,
+                recipients,
+                Msg::TorRecipientUnreachable,
+                options.fluff(),
+            )?;
+            (fee, sent)
+        }
+        None => with_runtime_mut(handle, |runtime| {
+            let slate = init_send(runtime, item.amount, proof_recipient, options)?;
+            lock_send_outputs(runtime, &slate, options)?;
+            let slatepack = runtime.owner.create_slatepack_message(
+                runtime.keychain_mask.as_ref(),
+                &slate,
+                Some(0),
+                recipients,
+            )?;
+            let sent = DirectSendResultDto {
+                tx_slate_id: slate.id.to_string(),
+                delivered: false,
+                slatepack: Some(slatepack),
+                fallback_reason: None,
+            };
+            Ok((slate.fee_fields.fee(), sent))
+        })?,
+    };
+    let status = if sent.delivered {
+        PayoutStatus::Delivered
+    } else {
+        PayoutStatus::Manual
+    };
+    let mut result = PayoutResultDto::for_item(item, status);
+    result.tx_slate_id = Some(sent.tx_slate_id);
+    result.fee = Some(fee);
+    result.slatepack = sent.slatepack;
+    result.error = sent.fallback_reason;
+    Ok(result)
+}
+
+/// Wie `send_via_tor`, aber gegen einen Foreign API unter einer HTTP(S)-URL, etwa bei
+/// Boersen oder Pools. Ohne Slatepack-Adresse gibt es keinen Payment Proof.
+pub fn send_via_http(
+    handle: WalletHandle,
+    url: &str,
+    amount: u64,
+    options: SendOptions,
+) -> Result<DirectSendResultDto> {
+    if amount == 0 && !options.send_all() {
+        return Err(invalid_input(Msg::AmountZero));
+    }
+    let base_url = url.trim().trim_end_matches('/');
+    if !(base_url.starts_with("http://") || base_url.starts_with("https://")) {
+        return Err(BridgeError::InvalidAddress {
+            detail: tr(Msg::InvalidEndpointUrl { url }),
+        }
+        .into());
+    }
+    if reqwest::Url::parse(base_url).is_err() {
+        return Err(BridgeError::InvalidAddress {
+            detail: tr(Msg::InvalidEndpointUrl { url }),
+        }
+        .into());
+    }
+    let client = ForeignClient::http(base_url, HTTP_SEND_TIMEOUT)?;
+    let proof_recipient = payment_proof_recipient(None, &options)?;
+    let (slate, client) =
+        prepare_direct_send(handle, amount, proof_recipient, &options, |_| Ok(client))?;
+    finish_direct_send(
+        handle,
+        slate,
+        client,
+        Vec::new(),
+        Msg::EndpointUnreachable,
+        options.fluff(),
+    )
+}
+
+/// Baut und sperrt S1 unter dem Runtime-Lock und holt den Client fuer die Gegenseite. Ein
+/// Fehler beim Client landet erst in `finish_direct_send` im Slatepack-Fallback.
+fn prepare_direct_send<C>(
+    handle: WalletHandle,
+    amount: u64,
+    proof_recipient: Option<SlatepackAddress>,
+    options: &SendOptions,
+    connect: C,
+) -> Result<(Slate, Result<ForeignClient>)>
+where
+    C: FnOnce(&mut WalletRuntime) -> Result<ForeignClient>,
+{
+    with_runtime_mut(handle, |runtime| {
+        let slate = init_send(runtime, amount, proof_recipient, options)?;
+        lock_send_outputs(runtime, &slate, options)?;
+        Ok((slate, connect(runtime)))
+    })
+}
+
+/// Der Austausch laeuft ohne Runtime-Lock, damit die Wallet bis zum Timeout des Clients
+/// benutzbar bleibt; erst zum Finalisieren wird sie wieder gesperrt.
+fn finish_direct_send(
+    handle: WalletHandle,
+    slate: Slate,
+    client: Result<ForeignClient>,
+    fallback_recipients: Vec<SlatepackAddress>,
+    unreachable: Msg,
+    fluff: bool,
+) -> Result<DirectSendResultDto> {
+    let returned = client.and_then(|client| client.send_tx(&slate));
+    with_runtime_mut(handle, move |runtime| {
+        exchange_and_finalize(
+            runtime,
+            slate,
+            returned,
+            fallback_recipients,
+            unreachable,
+            fluff,
+        )
+    })
+}
+
+/// Finalisiert und postet das S2 der Gegenseite. `send_tx` fragt vorher per `check_version`
+/// die Slate-Version ab; ein zu alter oder nicht erreichbarer Empfaenger landet im Fallback.
+fn exchange_and_finalize(
+    runtime: &WalletRuntime,
+    slate: Slate,
+    returned: Result<Slate>,
+    fallback_recipients: Vec<SlatepackAddress>,
+    unreachable: Msg,
+    fluff: bool,
+) -> Result<DirectSendResultDto> {
+    let mask = runtime.keychain_mask.as_ref();
+    let tx_slate_id = slate.id.to_string();
+    let returned = match returned {
+        Ok(returned) => returned,
+        Err(err) => {
+            let slatepack = runtime.owner.create_slatepack_message(
+                mask,
+                &slate,
+                Some(0),
+                fallback_recipients,
+            )?;
+            return Ok(DirectSendResultDto {
+                tx_slate_id,
+                delivered: false,
+                slatepack: Some(slatepack),
+                fallback_reason: Some(format!("{}: {err:#}", tr(unreachable))),
+            });
+        }
+    };
+
+    let finalized = runtime
+        .owner
+        .finalize_tx(mask, &returned)
+        .map_err(late_lock_error)?;
+    runtime
+        .owner
+        .post_tx(mask, &finalized, fluff)
+        .with_context(|| tr(Msg::DirectSendPost))?;
+    Ok(DirectSendResultDto {
+        tx_slate_id,
+        delivered: true,
+        slatepack: None,
+        fallback_reason: None,
+    })
+}
+
+fn parse_slatepack_address(address: &str) -> Result<SlatepackAddress> {
+    SlatepackAddress::try_from(address).map_err(|_| {
+        BridgeError::InvalidAddress {
+            detail: tr(Msg::InvalidSlatepackAddress { address }),
+        }
+        .into()
+    })
+}
+
+/// Ein Proof braucht die Adresse des Empfaengers, sonst kann er nicht signieren.
+fn payment_proof_recipient(
+    recipient: Option<&SlatepackAddress>,
+    options: &SendOptions,
+) -> Result<Option<SlatepackAddress>> {
+    match (options.request_payment_proof, recipient) {
+        (Some(false), _) => Ok(None),
+        (_, Some(address)) => Ok(Some(address.clone())),
+        (Some(true), None) => Err(invalid_input(Msg::PaymentProofNeedsAddress)),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Erzeugt das S1-Slate mit allen Send-Optionen, ohne Outputs zu sperren.
+fn init_send(
+    runtime: &WalletRuntime,
+    amount: u64,
+    proof_recipient: Option<SlatepackAddress>,
+    options: &SendOptions,
+) -> Result<Slate> {
+    ensure_source_account(runtime, options)?;
+    let amount = resolve_send_amount(runtime, amount, options)?;
+    let mut args = options.init_tx_args(amount);
+    args.payment_proof_recipient_address = proof_recipient;
+    args.ttl_blocks = options.ttl_blocks(runtime.default_ttl_blocks);
+    args.late_lock = Some(options.late_lock());
+    let slate = runtime
+        .owner
+        .init_send_tx(runtime.keychain_mask.as_ref(), args)?;
+    Ok(slate)
+}
+
+/// Bei Late-Lock waehlt und sperrt erst `finalize_tx` die Inputs.
+fn lock_send_outputs(runtime: &WalletRuntime, slate: &Slate, options: &SendOptions) -> Result<()> {
+    if !options.late_lock() {
+        runtime
+            .owner
+            .tx_lock_outputs(runtime.keychain_mask.as_ref(), slate)?;
+    }
+    Ok(())
+}
+
+/// Bei Send-all ist der Betrag die Summe der ausgebbaren Outputs des Quell-Accounts, also
+/// genau das, was die Output-Auswahl von libwallet sieht.
+fn resolve_send_amount(runtime: &WalletRuntime, amount: u64, options: &SendOptions) -> Result<u64> {
+    if !options.send_all() {
+        return Ok(amount);
+    }
+    let values = spendable_values(runtime, options)?;
+    let spendable = send_all_amount(values, options.max_outputs() as usize);
+    if spendable == 0 {
+        return Err(BridgeError::InsufficientFunds {
+            detail: tr(Msg::NothingToSend),
+        }
+        .into());
+    }
+    Ok(spendable)
+}
+
+/// Mehr als `max_outputs` Inputs nimmt libwallet nicht; Send-all schickt dann die Summe der
+/// groessten `max_outputs` Outputs, der Rest bleibt stehen.
+fn send_all_amount(mut values: Vec<u64>, max_outputs: usize) -> u64 {
+    values.sort_unstable_by(|a, b| b.cmp(a));
+    values
+        .into_iter()
+        .take(max_outputs.max(1))
+        .fold(0u64, |acc, value| acc.saturating_add(value))
+}
+
+/// Werte der Outputs, die libwallet fuer einen Send mit diesen Optionen auswaehlen darf.
+fn spendable_values(runtime: &WalletRuntime, options: &SendOptions) -> Result<Vec<u64>> {
+    let mask = runtime.keychain_mask.as_ref();
+    let height = runtime.owner.node_height(mask)?.height;
+    let outputs = match options.source_account() {
+        Some(label) => account_outputs(runtime, label)?,
+        None => runtime
+            .owner
+            .retrieve_outputs(mask, false, false, None)?
+            .1
+            .into_iter()
+            .map(|mapping| mapping.output)
+            .collect(),
+    };
+    Ok(outputs
+        .into_iter()
+        .filter(|output| output.eligible_to_spend(height, options.minimum_confirmations()))
+        .map(|output| output.value)
+        .collect())
+}
+
+/// Outputs eines beliebigen Accounts. `retrieve_outputs` liefert nur die des aktiven
+/// Accounts, und umschalten wollen wir dafuer nicht.
+fn account_outputs(runtime: &WalletRuntime, label: &str) -> Result<Vec<OutputData>> {
+    let path = account_path(runtime, label)?;
+    let mut w_lock = runtime.owner.wallet_inst.lock();
+    let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+    let wallet_inst = lc.wallet_inst().with_context(|| tr(Msg::WalletInstance))?;
+    let outputs = wallet_inst
+        .iter()
+        .filter(|output| output.root_key_id == path)
+        .collect();
+    Ok(outputs)
+}
+
+/// libwallet nimmt bei unbekanntem `src_acct_name` stillschweigend den aktiven Account.
+fn ensure_source_account(runtime: &WalletRuntime, options: &SendOptions) -> Result<()> {
+    if let Some(label) = options.source_account() {
+        account_path(runtime, label)?;
+    }
+    Ok(())
+}
+
+fn account_path(runtime: &WalletRuntime, label: &str) -> Result<Identifier> {
+    let path = runtime
+        .owner
+        .accounts(runtime.keychain_mask.as_ref())?
+        .into_iter()
+        .find(|acct| acct.label == label)
+        .map(|acct| acct.path)
+        .ok_or_else(|| BridgeError::AccountError {
+            detail: tr(Msg::UnknownAccount { label }),
+        })?;
+    Ok(path)
+}
+
+/// Schaetzt Gebuehr, Inputs und Wechselgeld ueber `estimate_only`; es wird nichts gesperrt
+/// und kein Tx-Log-Eintrag angelegt.
+pub fn estimate_send(
+    handle: WalletHandle,
+    amount: u64,
+    options: SendOptions,
+) -> Result<SendEstimateDto> {
+    if amount == 0 && !options.send_all() {
+        return Err(invalid_input(Msg::AmountZero));
+    }
+    with_runtime_mut(handle, |runtime| {
+        estimate_send_with(runtime, amount, &options)
+    })
+}
+
+/// `estimate_send` unter einem schon gehaltenen Runtime-Lock.
+fn estimate_send_with(
+    runtime: &WalletRuntime,
+    amount: u64,
+    options: &SendOptions,
+) -> Result<SendEstimateDto> {
+    let mask = runtime.keychain_mask.as_ref();
+    ensure_source_account(runtime, options)?;
+    let amount = resolve_send_amount(runtime, amount, options)?;
+    let mut args = options.init_tx_args(amount);
+    args.estimate_only = Some(true);
+    let num_outputs = args.num_change_outputs as usize + 1;
+    match runtime.owner.init_send_tx(mask, args) {
+        Ok(slate) => {
+            // Im Schaetzmodus steht in `amount` die Summe der ausgewaehlten Inputs.
+            let total_inputs = slate.amount;
+            let fee = slate.fee_fields.fee();
+            let (received, debited) = split_send_amount(amount, fee, options);
+            let num_inputs = estimated_input_count(runtime, options, debited)?;
+            Ok(SendEstimateDto {
+                amount: received,
+                fee,
+                num_inputs,
+                total_inputs,
+                change: total_inputs.saturating_sub(debited),
+                sufficient: true,
+            })
+        }
+        Err(grin_wallet_libwallet::Error::NotEnoughFunds {
+            available, needed, ..
+        }) => {
+            // Mit enthaltener Gebuehr ist `needed` nur der Betrag; die Gebuehr dann fuer alle
+            // Inputs rechnen, die libwallet hoechstens nehmen wuerde.
+            let fee = if options.amount_includes_fee() {
+                let max_inputs = spendable_values(runtime, options)?
+                    .len()
+                    .min(options.max_outputs() as usize);
+                tx_fee(max_inputs, num_outputs, 1)
+            } else {
+                needed.saturating_sub(amount)
+            };
+            let (received, _) = split_send_amount(amount, fee, options);
+            Ok(SendEstimateDto {
+                amount: received,
+                fee,
+                num_inputs: 0,
+                total_inputs: available,
+                change: 0,
+                sufficient: false,
+            })
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Empfangener und abgebuchter Betrag; mit `amount_includes_fee` geht die Gebuehr vom Betrag ab.
+fn split_send_amount(amount: u64, fee: u64, options: &SendOptions) -> (u64, u64) {
+    if options.amount_includes_fee() {
+        (amount.saturating_sub(fee), amount)
+    } else {
+        (amount, amount.saturating_add(fee))
+    }
+}
+
+/// libwallet liefert nur die Input-Summe. Rechnet deshalb `select_coins` fuer Betrag plus
+/// Gebuehr nach.
+fn estimated_input_count(
+    runtime: &WalletRuntime,
+    options: &SendOptions,
+    amount_with_fee: u64,
+) -> Result<usize> {
+    let values = spendable_values(runtime, options)?;
+    Ok(input_count(
+        values,
+        options.max_outputs() as usize,
+        options.use_all(),
+        amount_with_fee,
+    ))
+}
+
+/// Kleinste Outputs zuerst, `max_outputs` als weiche Grenze ueber ein gleitendes Fenster,
+/// notfalls die groessten `max_outputs`.
+fn input_count(
+    mut values: Vec<u64>,
+    max_outputs: usize,
+    use_all: bool,
+    amount_with_fee: u64,
+) -> usize {
+    values.sort_unstable();
+    let max_outputs = max_outputs.max(1);
+    if values.len() > max_outputs {
+        let windowed = values
+            .windows(max_outputs)
+            .find_map(|window| selected_input_count(window, amount_with_fee, use_all));
+        if let Some(count) =
+            windowed.or_else(|| selected_input_count(&values, amount_with_fee, false))
+        {
+            return count;
+        }
+    } else if let Some(count) = selected_input_count(&values, amount_with_fee, use_all) {
+        return count;
+    }
+    values.len().min(max_outputs)
+}
+
+/// Wie `select_from` in libwallet, auf aufsteigend sortierten Betraegen.
+fn selected_input_count(values: &[u64], amount: u64, select_all: bool) -> Option<usize> {
+    let total = values
+        .iter()
+        .fold(0u64, |acc, value| acc.saturating_add(*value));
+    if total < amount {
+        return None;
+    }
+    if select_all {
+        return Some(values.len());
+    }
+    let mut selected = 0u64;
+    Some(
+        values
+            .iter()
+            .take_while(|value| {
+                let take = selected < amount;
+                selected = selected.saturating_add(**value);
+                take
+            })
+            .count(),
+    )
+}
+
+pub fn receive_slatepack(handle: WalletHandle, message: &str) -> Result<String> {
+    with_runtime_mut(handle, |runtime| {
+        ensure_review_not_required(runtime)?;
+        sign_received(runtime, message)
     })
 }
 
-pub fn issue_invoice(amount: u64) -> Result<String> {
+fn sign_received(runtime: &WalletRuntime, message: &str) -> Result<String> {
+    let owner = &runtime.owner;
+    let mask = runtime.keychain_mask.as_ref();
+    let slate = owner.slate_from_slatepack_message(mask, message.to_string(), vec![0])?;
+    let decoded = owner.decode_slatepack_message(mask, message.to_string(), vec![0])?;
+    let foreign = Foreign::new(owner.wallet_inst.clone(), mask.cloned(), None, false);
+    let received = foreign.receive_tx(&slate, None, None)?;
+    let mut recipients = Vec::new();
+    if let Some(sender) = decoded.sender {
+        recipients.push(sender);
+    }
+    let response = owner.create_slatepack_message(mask, &received, Some(0), recipients)?;
+    Ok(response)
+}
+
+pub fn issue_invoice(handle: WalletHandle, amount: u64) -> Result<String> {
     if amount == 0 {
-        return Err(anyhow!("Betrag muss groesser als 0 sein"));
+        return Err(invalid_input(Msg::AmountZero));
     }
-    with_owner(|owner, mask| {
+    with_runtime_mut(handle, |runtime| {
+        let mask = runtime.keychain_mask.as_ref();
+        // Hoehe vor dem Erzeugen holen; scheitert die Abfrage, entsteht keine Rechnung ohne TTL.
+        let cutoff = match runtime.default_ttl_blocks {
+            Some(ttl_blocks) => Some(
+                runtime
+                    .owner
+                    .node_height(mask)?
+                    .height
+                    .saturating_add(ttl_blocks),
+            ),
+            None => None,
+        };
         let args = IssueInvoiceTxArgs {
             amount,
             ..IssueInvoiceTxArgs::default()
         };
-        let slate = owner.issue_invoice_tx(mask, args)?;
-        let message = owner.create_slatepack_message(mask, &slate, Some(0), vec![])?;
+        let mut slate = runtime.owner.issue_invoice_tx(mask, args)?;
+        if let Some(cutoff) = cutoff {
+            // Auch ins I1, damit der Zahler den Cutoff in seinen Tx-Log uebernimmt.
+            slate.ttl_cutoff_height = cutoff;
+            set_invoice_ttl(runtime, &slate, cutoff)?;
+        }
+        let message = runtime
+            .owner
+            .create_slatepack_message(mask, &slate, Some(0), vec![])?;
         Ok(message)
     })
 }
 
-pub fn process_invoice(message: &str) -> Result<String> {
-    let msg = message.to_string();
-    with_owner(|owner, mask| {
+/// `IssueInvoiceTxArgs` kennt keinen TTL; der Cutoff wird deshalb direkt am Tx-Log-Eintrag
+/// der Rechnung nachgetragen, damit der Sweep sie spaeter storniert.
+fn set_invoice_ttl(runtime: &WalletRuntime, slate: &Slate, cutoff: u64) -> Result<()> {
+    let mask = runtime.keychain_mask.as_ref();
+    let mut w_lock = runtime.owner.wallet_inst.lock();
+    let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+    let wallet_inst = lc.wallet_inst().with_context(|| tr(Msg::WalletInstance))?;
+    let mut entry = wallet_inst
+        .tx_log_iter()
+        .find(|entry| entry.tx_slate_id == Some(slate.id))
+        .ok_or_else(|| BridgeError::TransactionError {
+            detail: tr(Msg::InvoiceTxMissing {
+                slate_id: &slate.id.to_string(),
+            }),
+        })?;
+    let parent_key_id = entry.parent_key_id.clone();
+    entry.ttl_cutoff_height = Some(cutoff);
+    let mut batch = wallet_inst.batch(mask)?;
+    batch.save_tx_log_entry(entry, &parent_key_id)?;
+    batch.commit()?;
+    Ok(())
+}
+
+/// Bezahlt eine Rechnung. Betrag und Gebuehr gibt die Rechnung vor, `send_all` und
+/// `amount_includes_fee` werden deshalb ignoriert.
+pub fn process_invoice(
+    handle: WalletHandle,
+    message: &str,
+    options: SendOptions,
+) -> Result<String> {
+    with_runtime_mut(handle, |runtime| {
+        ensure_review_not_required(runtime)?;
+        pay_invoice(runtime, message, &options)
+    })
+}
+
+fn pay_invoice(runtime: &WalletRuntime, message: &str, options: &SendOptions) -> Result<String> {
+    ensure_source_account(runtime, options)?;
+    let owner = &runtime.owner;
+    let mask = runtime.keychain_mask.as_ref();
+    let slate = owner.slate_from_slatepack_message(mask, message.to_string(), vec![0])?;
+    let decoded = owner.decode_slatepack_message(mask, message.to_string(), vec![0])?;
+    let init_args = InitTxArgs {
+        amount_includes_fee: Some(false),
+        selection_strategy_is_use_all: options.selection_strategy_is_use_all.unwrap_or(false),
+        ttl_blocks: options.ttl_blocks(runtime.default_ttl_blocks),
+        ..options.init_tx_args(slate.amount)
+    };
+    let processed = owner.process_invoice_tx(mask, &slate, init_args)?;
+    let mut recipients = Vec::new();
+    if let Some(sender) = decoded.sender {
+        recipients.push(sender);
+    }
+    let response = owner.create_slatepack_message(mask, &processed, Some(0), recipients)?;
+    owner.tx_lock_outputs(mask, &processed)?;
+    Ok(response)
+}
+
+/// Gebuehr, die `pay_invoice` mit diesen Optionen tragen wuerde; Betrag und Gebuehr kommen dort
+/// nie aus `send_all` oder `amount_includes_fee`.
+fn invoice_fee(runtime: &WalletRuntime, amount: u64, options: &SendOptions) -> Result<u64> {
+    let options = SendOptions {
+        send_all: Some(false),
+        amount_includes_fee: Some(false),
+        ..options.clone()
+    };
+    Ok(estimate_send_with(runtime, amount, &options)?.fee)
+}
+
+/// Schaltet den Pruefschritt ein: `receive_slatepack`, `process_invoice` und
+/// `process_slatepack` signieren dann nichts mehr direkt.
+pub fn set_require_review(handle: WalletHandle, required: bool) -> Result<()> {
+    with_runtime_mut(handle, |runtime| {
+        runtime.require_review = required;
+        if !required {
+            runtime.pending_reviews.clear();
+        }
+        Ok(())
+    })
+}
+
+/// Laeuft unter demselben Runtime-Lock wie das Signieren, damit ein gleichzeitiges
+/// `set_require_review` nicht dazwischenkommt.
+fn ensure_review_not_required(runtime: &WalletRuntime) -> Result<()> {
+    if runtime.require_review {
+        Err(invalid_input(Msg::ReviewRequired))
+    } else {
+        Ok(())
+    }
+}
+
+/// Prueft ein eingehendes S1 oder I1 gegen die Erwartung und merkt es fuer
+/// `approve_slatepack` vor. Signiert wird hier noch nichts; `options` gelten fuer die
+/// Gebuehrenschaetzung bei Rechnungen.
+pub fn review_slatepack(
+    handle: WalletHandle,
+    message: &str,
+    expectation: SlateExpectation,
+    options: SendOptions,
+) -> Result<SlateReviewDto> {
+    let msg = message.trim().to_string();
+    with_runtime_mut(handle, |runtime| {
+        let owner = &runtime.owner;
+        let mask = runtime.keychain_mask.as_ref();
         let slate = owner.slate_from_slatepack_message(mask, msg.clone(), vec![0])?;
         let decoded = owner.decode_slatepack_message(mask, msg.clone(), vec![0])?;
-        let init_args = InitTxArgs {
+        let code = match SlateCode::from_state(&slate.state) {
+            code @ (SlateCode::S1 | SlateCode::I1) => code,
+            _ => {
+                return Err(BridgeError::InvalidSlatepack {
+                    detail: tr(Msg::ReviewNotSignable {
+                        state: &format!("{:?}", slate.state),
+                    }),
+                }
+                .into())
+            }
+        };
+        let sender_address = decoded
+            .sender
+            .as_ref()
+            .map(String::try_from)
+            .transpose()
+            .with_context(|| tr(Msg::SlatepackAddressSerialize))?;
+        let fee = match code {
+            SlateCode::I1 => invoice_fee(runtime, slate.amount, &options)?,
+            _ => slate.fee_fields.fee(),
+        };
+        check_expectation(&expectation, slate.amount, fee)?;
+        let review = SlateReviewDto {
+            slate_id: slate.id.to_string(),
+            state: code,
             amount: slate.amount,
-            selection_strategy_is_use_all: false,
-            ..Default::default()
+            fee,
+            sender_address,
+            // Modus 1 heisst verschluesselt; entschluesseln konnten wir es oben mit Index 0.
+            encrypted_to_us: decoded.mode == 1,
+            ttl_cutoff_height: Some(slate.ttl_cutoff_height).filter(|height| *height > 0),
+            payment_proof_requested: slate.payment_proof.is_some(),
         };
-        let processed = owner.process_invoice_tx(mask, &slate, init_args)?;
-        let mut recipients = Vec::new();
-        if let Some(sender) = decoded.sender {
-            recipients.push(sender);
-        }
-        let response = owner.create_slatepack_message(mask, &processed, Some(0), recipients)?;
-        owner.tx_lock_outputs(mask, &processed)?;
+        runtime.pending_reviews.insert(
+            review.slate_id.clone(),
+            PendingReview {
+                message: msg,
+                code,
+                amount: review.amount,
+                expectation,
+            },
+        );
+        Ok(review)
+    })
+}
+
+/// Signiert ein geprueftes Slate und liefert die Antwort fuer die Gegenseite. Bei
+/// Rechnungen wird die Gebuehr mit den endgueltigen Optionen noch einmal geprueft.
+pub fn approve_slatepack(
+    handle: WalletHandle,
+    slate_id: &str,
+    options: SendOptions,
+) -> Result<String> {
+    let slate_id = slate_id.trim();
+    with_runtime_mut(handle, |runtime| {
+        let pending = runtime
+            .pending_reviews
+            .get(slate_id)
+            .ok_or_else(|| invalid_input(Msg::NoPendingReview { slate_id }))?;
+        let response = match pending.code {
+            SlateCode::S1 => {
+                let slate = runtime.owner.slate_from_slatepack_message(
+                    runtime.keychain_mask.as_ref(),
+                    pending.message.clone(),
+                    vec![0],
+                )?;
+                check_expectation(&pending.expectation, slate.amount, slate.fee_fields.fee())?;
+                sign_received(runtime, &pending.message)?
+            }
+            _ => {
+                let fee = invoice_fee(runtime, pending.amount, &options)?;
+                check_expectation(&pending.expectation, pending.amount, fee)?;
+                pay_invoice(runtime, &pending.message, &options)?
+            }
+        };
+        // Erst nach Erfolg verwerfen; scheitert das Signieren, kann es erneut freigegeben werden.
+        runtime.pending_reviews.remove(slate_id);
         Ok(response)
     })
 }
 
-pub fn inspect_slatepack(message: &str) -> Result<String> {
+/// Verwirft ein vorgemerktes Slate; `false`, wenn es keines mit dieser ID gab.
+pub fn reject_slatepack(handle: WalletHandle, slate_id: &str) -> Result<bool> {
+    with_runtime_mut(handle, |runtime| {
+        Ok(runtime.pending_reviews.remove(slate_id.trim()).is_some())
+    })
+}
+
+fn check_expectation(expectation: &SlateExpectation, amount: u64, fee: u64) -> Result<()> {
+    if let Some(expected) = expectation.amount {
+        if expected != amount {
+            return Err(BridgeError::TransactionError {
+                detail: tr(Msg::ReviewAmountMismatch {
+                    expected,
+                    actual: amount,
+                }),
+            }
+            .into());
+        }
+    }
+    if let Some(max_fee) = expectation.max_fee {
+        if fee > max_fee {
+            return Err(BridgeError::TransactionError {
+                detail: tr(Msg::ReviewFeeTooHigh { max_fee, fee }),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+pub fn inspect_slatepack(handle: WalletHandle, message: &str) -> Result<SlateInspectionDto> {
     let msg = message.to_string();
     with_owner(
-        |owner, mask| -> Result<String, grin_wallet_libwallet::Error> {
+        handle,
+        |owner, mask| -> Result<SlateInspectionDto, grin_wallet_libwallet::Error> {
             let slate = owner.slate_from_slatepack_message(mask, msg.clone(), vec![0])?;
             let slate_id = slate.id.to_string();
-            let code = match slate.state {
-                SlateState::Standard1 => "S1",
-                SlateState::Standard2 => "S2",
-                SlateState::Standard3 => "S3",
-                SlateState::Invoice1 => "I1",
-                SlateState::Invoice2 => "I2",
-                SlateState::Invoice3 => "I3",
-                SlateState::Unknown => "UN",
-            }
-            .to_string();
+            let code = SlateCode::from_state(&slate.state);
             let fee = slate.fee_fields.fee();
             let info = SlateInspectionDto {
                 code,
@@ -543,31 +1570,41 @@ pub fn inspect_slatepack(message: &str) -> Result<String> {
                     .and_then(|tx| tx.kernels().first())
                     .map(|k| k.excess().to_hex()),
             };
-            serde_json::to_string(&info)
-                .map_err(|e| grin_wallet_libwallet::Error::Format(e.to_string()))
+            Ok(info)
         },
     )
 }
 
-pub fn transaction_slatepack(tx_id: u32) -> Result<String> {
-    with_owner(|owner, mask| {
+pub fn transaction_slatepack(handle: WalletHandle, tx_id: u32) -> Result<String> {
+    with_owner(handle, |owner, mask| {
         let slate = owner
             .get_stored_tx(mask, Some(tx_id), None)?
             .ok_or_else(|| {
-                grin_wallet_libwallet::Error::StoredTx(format!(
-                    "Keine Slatepack-Daten fuer Tx {tx_id} gefunden"
-                ))
+                grin_wallet_libwallet::Error::StoredTx(tr(Msg::NoStoredSlate { tx_id }))
             })?;
         let message = owner.create_slatepack_message(mask, &slate, Some(0), vec![])?;
         Ok(message)
     })
 }
 
-pub fn finalize_slatepack(message: &str, post: bool, fluff: bool) -> Result<String> {
+pub fn finalize_slatepack(
+    handle: WalletHandle,
+    message: &str,
+    post: bool,
+    fluff: bool,
+) -> Result<String> {
     let msg = message.to_string();
-    with_owner(|owner, mask| {
+    with_runtime_mut(handle, |runtime| {
+        let owner = &runtime.owner;
+        let mask = runtime.keychain_mask.as_ref();
         let slate = owner.slate_from_slatepack_message(mask, msg.clone(), vec![0])?;
-        let finalized = owner.finalize_tx(mask, &slate)?;
+        // I2 finalisiert der Rechnungssteller ueber die Foreign-Seite, wie `grin-wallet finalize`.
+        let finalized = if slate.state == SlateState::Invoice2 {
+            let foreign = Foreign::new(owner.wallet_inst.clone(), mask.cloned(), None, false);
+            foreign.finalize_tx(&slate, false)?
+        } else {
+            owner.finalize_tx(mask, &slate).map_err(late_lock_error)?
+        };
         if post {
             owner.post_tx(mask, &finalized, fluff)?;
         }
@@ -576,63 +1613,187 @@ pub fn finalize_slatepack(message: &str, post: bool, fluff: bool) -> Result<Stri
     })
 }
 
-pub fn wallet_info() -> Result<String> {
-    with_runtime_mut(|runtime| {
+/// Erkennt am Slate-Status, was mit einem eingefuegten Slatepack zu tun ist: S1 empfangen,
+/// I1 bezahlen, S2/I2 finalisieren. Alles andere ist bereits abgeschlossen.
+pub fn process_slatepack(
+    handle: WalletHandle,
+    message: &str,
+    options: ProcessSlatepackOptions,
+) -> Result<ProcessedSlatepackDto> {
+    let inspection = inspect_slatepack(handle, message)?;
+    let post = options.post_tx.unwrap_or(true);
+    let (action, response) = match inspection.code {
+        SlateCode::S1 => (
+            SlatepackAction::Received,
+            receive_slatepack(handle, message)?,
+        ),
+        SlateCode::I1 => (
+            SlatepackAction::PaidInvoice,
+            process_invoice(handle, message, options.send)?,
+        ),
+        SlateCode::S2 | SlateCode::I2 => (
+            SlatepackAction::Finalized,
+            finalize_slatepack(handle, message, post, options.fluff.unwrap_or(false))?,
+        ),
+        _ => {
+            return Err(BridgeError::InvalidSlatepack {
+                detail: tr(Msg::SlatepackNothingToDo {
+                    state: &inspection.state,
+                }),
+            }
+            .into())
+        }
+    };
+    Ok(ProcessedSlatepackDto {
+        action,
+        slate_id: inspection.slate_id,
+        state: inspection.code,
+        posted: action == SlatepackAction::Finalized && post,
+        response,
+    })
+}
+
+/// Beim Finalisieren koennen diese Fehler nur aus einem Late-Lock-Send kommen: die Inputs
+/// werden erst jetzt gewaehlt, und das Guthaben hat sich seit S1 geaendert.
+fn late_lock_error(err: grin_wallet_libwallet::Error) -> anyhow::Error {
+    match err {
+        grin_wallet_libwallet::Error::NotEnoughFunds { .. } => BridgeError::InsufficientFunds {
+            detail: format!("{}: {err}", tr(Msg::LateLockFunds)),
+        }
+        .into(),
+        grin_wallet_libwallet::Error::Fee(_) => BridgeError::TransactionError {
+            detail: format!("{}: {err}", tr(Msg::LateLockFee)),
+        }
+        .into(),
+        other => other.into(),
+    }
+}
+
+pub fn wallet_info(handle: WalletHandle) -> Result<WalletInfoDto> {
+    with_runtime_mut(handle, |runtime| {
         let mask_ref = runtime.keychain_mask.as_ref();
         let (refreshed, info) = runtime.owner.retrieve_summary_info(mask_ref, true, 10)?;
-        let dto = WalletInfoDto {
-            refreshed_from_node: refreshed,
+        Ok(WalletInfoDto::from_info(
+            refreshed,
             info,
-            active_account: runtime.active_account.clone(),
-        };
-        to_json(&dto)
+            runtime.active_account.clone(),
+        ))
     })
 }
 
-pub fn list_transactions(refresh_from_node: bool) -> Result<String> {
-    with_runtime_mut(|runtime| {
+pub fn list_transactions(
+    handle: WalletHandle,
+    refresh_from_node: bool,
+) -> Result<Vec<TransactionDto>> {
+    with_runtime_mut(handle, |runtime| {
         let mask_ref = runtime.keychain_mask.as_ref();
-        let node_height = runtime.owner.node_height(mask_ref)?.height;
         let (_, entries) =
             runtime
                 .owner
                 .retrieve_txs(mask_ref, refresh_from_node, None, None, None)?;
-        let (_, mappings) =
-            runtime
-                .owner
-                .retrieve_outputs(mask_ref, true, refresh_from_node, None)?;
-        let mut confirmations_map: HashMap<u32, u64> = HashMap::new();
-        for mapping in mappings {
-            if let Some(tx_id) = mapping.output.tx_log_entry {
-                let height = mapping.output.height;
-                let confirmations = if height == 0 || height > node_height {
-                    0
-                } else {
-                    1 + (node_height - height)
-                };
-                confirmations_map
-                    .entry(tx_id)
-                    .and_modify(|existing| {
-                        if confirmations < *existing {
-                            *existing = confirmations;
-                        }
-                    })
-                    .or_insert(confirmations);
-            }
-        }
+        let confirmations_map = tx_confirmations(&runtime.owner, mask_ref, refresh_from_node)?;
+        let meta = tx_meta::load(&runtime.data_dir)?;
         let txs: Vec<TransactionDto> = entries
             .into_iter()
             .map(|entry| {
                 let confirmations = confirmations_map.get(&entry.id).copied().unwrap_or(0);
-                TransactionDto::from_entry(entry, confirmations)
+                let slate_id = entry.tx_slate_id.map(|id| id.to_string());
+                let metadata =
+                    meta.get(Some((&entry.parent_key_id, entry.id)), slate_id.as_deref());
+                TransactionDto {
+                    metadata,
+                    ..TransactionDto::from_entry(entry, confirmations)
+                }
             })
             .collect();
-        to_json(&txs)
+        Ok(txs)
+    })
+}
+
+pub fn tx_metadata(
+    handle: WalletHandle,
+    parent_key_id: &str,
+    tx_id: u32,
+) -> Result<Option<TxMetadataDto>> {
+    with_runtime_mut(handle, |runtime| {
+        let entry = tx_log_entry(runtime, parent_key_id, tx_id)?;
+        let slate_id = entry.tx_slate_id.map(|id| id.to_string());
+        let tx = Some((&entry.parent_key_id, tx_id));
+        Ok(tx_meta::load(&runtime.data_dir)?.get(tx, slate_id.as_deref()))
+    })
+}
+
+/// Ersetzt Notiz, Kategorie und Gegenpartei einer Tx; alles leer loescht den Eintrag.
+pub fn set_tx_metadata(
+    handle: WalletHandle,
+    parent_key_id: &str,
+    tx_id: u32,
+    metadata: TxMetadataDto,
+) -> Result<Option<TxMetadataDto>> {
+    with_runtime_mut(handle, |runtime| {
+        let entry = tx_log_entry(runtime, parent_key_id, tx_id)?;
+        let slate_id = entry.tx_slate_id.map(|id| id.to_string());
+        let tx = Some((&entry.parent_key_id, tx_id));
+        let mut meta = tx_meta::load(&runtime.data_dir)?;
+        meta.set(tx, slate_id.as_deref(), metadata);
+        meta.save()?;
+        Ok(meta.get(tx, slate_id.as_deref()))
     })
 }
 
-pub fn list_outputs(include_spent: bool, refresh_from_node: bool) -> Result<String> {
-    with_runtime_mut(|runtime| {
+/// Tx-Log-IDs gelten nur innerhalb eines Accounts. `parent_key_id` kommt aus dem
+/// `TransactionDto` des Aufrufers, damit ein inzwischen gewechselter Account nicht zaehlt.
+fn tx_log_entry(runtime: &WalletRuntime, parent_key_id: &str, tx_id: u32) -> Result<TxLogEntry> {
+    let unknown_tx = || BridgeError::TransactionError {
+        detail: tr(Msg::UnknownTx { tx_id }),
+    };
+    let parent_key_id = Identifier::from_hex(parent_key_id.trim()).map_err(|_| unknown_tx())?;
+    let mut w_lock = runtime.owner.wallet_inst.lock();
+    let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+    let wallet_inst = lc.wallet_inst().with_context(|| tr(Msg::WalletInstance))?;
+    let entry = wallet_inst
+        .tx_log_iter()
+        .find(|entry| entry.parent_key_id == parent_key_id && entry.id == tx_id)
+        .ok_or_else(unknown_tx)?;
+    Ok(entry)
+}
+
+/// Bestaetigungen je Tx-Log-Eintrag; massgeblich ist der juengste Output der Tx.
+fn tx_confirmations(
+    owner: &OwnerApi,
+    mask: Option<&SecretKey>,
+    refresh_from_node: bool,
+) -> Result<HashMap<u32, u64>> {
+    let node_height = owner.node_height(mask)?.height;
+    let (_, mappings) = owner.retrieve_outputs(mask, true, refresh_from_node, None)?;
+    let mut confirmations_map: HashMap<u32, u64> = HashMap::new();
+    for mapping in mappings {
+        if let Some(tx_id) = mapping.output.tx_log_entry {
+            let height = mapping.output.height;
+            let confirmations = if height == 0 || height > node_height {
+                0
+            } else {
+                1 + (node_height - height)
+            };
+            confirmations_map
+                .entry(tx_id)
+                .and_modify(|existing| {
+                    if confirmations < *existing {
+                        *existing = confirmations;
+                    }
+                })
+                .or_insert(confirmations);
+        }
+    }
+    Ok(confirmations_map)
+}
+
+pub fn list_outputs(
+    handle: WalletHandle,
+    include_spent: bool,
+    refresh_from_node: bool,
+) -> Result<Vec<OutputDto>> {
+    with_runtime_mut(handle, |runtime| {
         let mask_ref = runtime.keychain_mask.as_ref();
         let node_height = runtime.owner.node_height(mask_ref)?.height;
         let (_, mappings) =
@@ -643,12 +1804,12 @@ pub fn list_outputs(include_spent: bool, refresh_from_node: bool) -> Result<Stri
             .into_iter()
             .map(|mapping| OutputDto::from_mapping(mapping, node_height))
             .collect();
-        to_json(&outputs)
+        Ok(outputs)
     })
 }
 
-pub fn cancel_tx(tx_id: u32) -> Result<()> {
-    with_runtime_mut(|runtime| {
+pub fn cancel_tx(handle: WalletHandle, tx_id: u32) -> Result<()> {
+    with_runtime_mut(handle, |runtime| {
         let args = CancelArgs {
             tx_id: Some(tx_id),
             tx_slate_id: None,
@@ -659,8 +1820,8 @@ pub fn cancel_tx(tx_id: u32) -> Result<()> {
     })
 }
 
-pub fn repost_tx(tx_id: u32, fluff: bool) -> Result<()> {
-    with_runtime_mut(|runtime| {
+pub fn repost_tx(handle: WalletHandle, tx_id: u32, fluff: bool) -> Result<()> {
+    with_runtime_mut(handle, |runtime| {
         let args = RepostArgs {
             id: tx_id,
             dump_file: None,
@@ -672,29 +1833,181 @@ pub fn repost_tx(tx_id: u32, fluff: bool) -> Result<()> {
 }
 
 pub fn scan(
+    handle: WalletHandle,
     delete_unconfirmed: bool,
     start_height: Option<u64>,
     backwards_from_tip: Option<u64>,
-) -> Result<String> {
-    with_runtime_mut(|runtime| {
-        let args = CheckArgs {
-            delete_unconfirmed,
-            start_height,
-            backwards_from_tip,
-        };
-        command::scan(&mut runtime.owner, runtime.keychain_mask.as_ref(), args)?;
-        let dto = ScanResultDto {
-            delete_unconfirmed,
-            start_height,
-            backwards_from_tip,
-            performed_at_epoch_secs: epoch_secs(),
-        };
-        to_json(&dto)
+    cancel_token: Option<CancelToken>,
+) -> Result<ScanResultDto> {
+    with_cancel_flag(cancel_token, |cancel| {
+        with_runtime_mut(handle, |runtime| {
+            let tip = scan_tip(runtime)?;
+            let from = match (start_height, backwards_from_tip) {
+                (Some(height), _) => height,
+                (None, Some(blocks)) => tip.saturating_sub(blocks),
+                (None, None) => 1,
+            };
+            scan_in_windows(
+                handle,
+                runtime,
+                from,
+                tip,
+                delete_unconfirmed,
+                cancel.as_ref(),
+            )?;
+            let dto = ScanResultDto {
+                delete_unconfirmed,
+                start_height,
+                backwards_from_tip,
+                performed_at_epoch_secs: epoch_secs(),
+            };
+            events::emit(&WalletEventDto::ScanCompleted {
+                handle,
+                result: dto.clone(),
+            });
+            Ok(dto)
+        })
     })
 }
 
-pub fn list_accounts() -> Result<String> {
-    with_runtime_mut(|runtime| {
+/// Setzt einen abgebrochenen Scan oder Restore an der gespeicherten Hoehe fort.
+pub fn resume_scan(
+    handle: WalletHandle,
+    cancel_token: Option<CancelToken>,
+) -> Result<ScanResultDto> {
+    let resume_height =
+        scan_resume_height(handle)?.ok_or_else(|| invalid_input(Msg::NoScanToResume))?;
+    scan(handle, false, Some(resume_height), None, cancel_token)
+}
+
+pub fn scan_resume_height(handle: WalletHandle) -> Result<Option<u64>> {
+    with_runtime(handle, |runtime| Ok(read_scan_resume(&runtime.data_dir)))
+}
+
+/// Scannt `[start_height, tip]` in Fenstern zu `SCAN_WINDOW_BLOCKS`. Nach jedem Fenster gehen
+/// ein Fortschritts-Event und die Fortsetzungshoehe raus; ein Abbruch greift im Node-Client
+/// beim naechsten Output-Stapel.
+fn scan_in_windows(
+    handle: WalletHandle,
+    runtime: &WalletRuntime,
+    start_height: u64,
+    tip: u64,
+    delete_unconfirmed: bool,
+    cancel: Option<&Arc<AtomicBool>>,
+) -> Result<()> {
+    let mask = runtime.keychain_mask.as_ref();
+    let start = start_height.clamp(1, tip.max(1));
+    let mut from = start;
+    loop {
+        if cancel
+            .map(|flag| flag.load(Ordering::SeqCst))
+            .unwrap_or(false)
+        {
+            write_scan_resume(&runtime.data_dir, from);
+            return Err(scan_cancelled(from));
+        }
+        let to = from.saturating_add(SCAN_WINDOW_BLOCKS - 1).min(tip);
+        let last = to >= tip;
+        // Das letzte Fenster laeuft offen bis zum dann aktuellen Tip.
+        runtime
+            .scan_control
+            .begin(cancel.cloned(), if last { None } else { Some(to) });
+        let result = runtime
+            .owner
+            .scan(mask, Some(from), delete_unconfirmed && last);
+        runtime.scan_control.finish();
+        if let Err(err) = result {
+            write_scan_resume(&runtime.data_dir, from);
+            if cancel
+                .map(|flag| flag.load(Ordering::SeqCst))
+                .unwrap_or(false)
+            {
+                return Err(scan_cancelled(from));
+            }
+            return Err(err.into());
+        }
+        let done = to.saturating_sub(start) + 1;
+        let total = tip.saturating_sub(start) + 1;
+        events::emit(&WalletEventDto::ScanProgress {
+            handle,
+            progress: ScanProgressDto {
+                start_height: start,
+                current_height: to,
+                tip_height: tip,
+                percent: (done.saturating_mul(100) / total).min(100) as u8,
+            },
+        });
+        if last {
+            break;
+        }
+        from = to + 1;
+        write_scan_resume(&runtime.data_dir, from);
+    }
+    clear_scan_resume(&runtime.data_dir);
+    Ok(())
+}
+
+fn scan_tip(runtime: &WalletRuntime) -> Result<u64> {
+    let tip = runtime.owner.node_height(runtime.keychain_mask.as_ref())?;
+    if !tip.updated_from_node {
+        return Err(BridgeError::NodeUnreachable {
+            detail: tr(Msg::NodeTip),
+        }
+        .into());
+    }
+    Ok(tip.height)
+}
+
+fn scan_cancelled(resume_height: u64) -> anyhow::Error {
+    BridgeError::Cancelled {
+        detail: tr(Msg::ScanCancelled { resume_height }),
+    }
+    .into()
+}
+
+fn is_cancelled(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<BridgeError>(),
+        Some(BridgeError::Cancelled { .. })
+    )
+}
+
+/// Loest das Token auf und gibt es nach der Operation wieder frei.
+fn with_cancel_flag<R, F>(token: Option<CancelToken>, op: F) -> Result<R>
+where
+    F: FnOnce(Option<Arc<AtomicBool>>) -> Result<R>,
+{
+    let flag = match token {
+        Some(token) => Some(
+            cancel::flag(token).ok_or_else(|| invalid_input(Msg::UnknownCancelToken { token }))?,
+        ),
+        None => None,
+    };
+    let result = op(flag);
+    if let Some(token) = token {
+        cancel::release(token);
+    }
+    result
+}
+
+fn read_scan_resume(data_dir: &Path) -> Option<u64> {
+    fs::read_to_string(data_dir.join(SCAN_RESUME_FILE_NAME))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+fn write_scan_resume(data_dir: &Path, height: u64) {
+    let _ = fs::write(data_dir.join(SCAN_RESUME_FILE_NAME), height.to_string());
+}
+
+fn clear_scan_resume(data_dir: &Path) {
+    let _ = fs::remove_file(data_dir.join(SCAN_RESUME_FILE_NAME));
+}
+
+pub fn list_accounts(handle: WalletHandle) -> Result<Vec<AccountDto>> {
+    with_runtime_mut(handle, |runtime| {
         let mask_ref = runtime.keychain_mask.as_ref();
         let accounts = runtime.owner.accounts(mask_ref)?;
         let dto: Vec<AccountDto> = accounts
@@ -705,16 +2018,16 @@ pub fn list_accounts() -> Result<String> {
                 is_active: acct.label == runtime.active_account,
             })
             .collect();
-        to_json(&dto)
+        Ok(dto)
     })
 }
 
-pub fn create_account(label: &str) -> Result<String> {
+pub fn create_account(handle: WalletHandle, label: &str) -> Result<AccountDto> {
     let cleaned = label.trim();
     if cleaned.is_empty() {
-        return Err(anyhow!("Account-Name darf nicht leer sein"));
+        return Err(invalid_input(Msg::AccountNameEmpty));
     }
-    with_runtime_mut(|runtime| {
+    with_runtime_mut(handle, |runtime| {
         let mask_ref = runtime.keychain_mask.as_ref();
         let identifier = runtime.owner.create_account_path(mask_ref, cleaned)?;
         let dto = AccountDto {
@@ -722,16 +2035,16 @@ pub fn create_account(label: &str) -> Result<String> {
             path: identifier.to_bip_32_string(),
             is_active: false,
         };
-        to_json(&dto)
+        Ok(dto)
     })
 }
 
-pub fn set_active_account(label: &str) -> Result<String> {
+pub fn set_active_account(handle: WalletHandle, label: &str) -> Result<AccountDto> {
     let cleaned = label.trim();
     if cleaned.is_empty() {
-        return Err(anyhow!("Account-Name darf nicht leer sein"));
+        return Err(invalid_input(Msg::AccountNameEmpty));
     }
-    with_runtime_mut(|runtime| {
+    with_runtime_mut(handle, |runtime| {
         let mask_ref = runtime.keychain_mask.as_ref();
         runtime.owner.set_active_account(mask_ref, cleaned)?;
         runtime.active_account = cleaned.to_string();
@@ -746,99 +2059,549 @@ pub fn set_active_account(label: &str) -> Result<String> {
             path,
             is_active: true,
         };
-        to_json(&dto)
+        Ok(dto)
     })
 }
 
-pub fn active_account() -> Result<String> {
-    with_runtime_mut(|runtime| Ok(runtime.active_account.clone()))
+pub fn active_account(handle: WalletHandle) -> Result<String> {
+    with_runtime_mut(handle, |runtime| Ok(runtime.active_account.clone()))
+}
+
+pub fn payment_proof(handle: WalletHandle, tx_id: u32) -> Result<PaymentProofDto> {
+    with_runtime_mut(handle, |runtime| {
+        let mask_ref = runtime.keychain_mask.as_ref();
+        let proof = runtime
+            .owner
+            .retrieve_payment_proof(mask_ref, true, Some(tx_id), None)?;
+        let dto = PaymentProofDto::from_proof(tx_id, proof).map_err(|e| {
+            BridgeError::PaymentProofError {
+                detail: format!("{}: {e}", tr(Msg::PaymentProofSerialize)),
+            }
+        })?;
+        Ok(dto)
+    })
+}
+
+pub fn verify_payment_proof(
+    handle: WalletHandle,
+    serialized: &str,
+) -> Result<PaymentProofVerificationDto> {
+    let proof: PaymentProof =
+        serde_json::from_str(serialized).map_err(|e| BridgeError::PaymentProofError {
+            detail: format!("{}: {e}", tr(Msg::PaymentProofParse)),
+        })?;
+    with_runtime_mut(handle, |runtime| {
+        let mask_ref = runtime.keychain_mask.as_ref();
+        let (is_sender, is_recipient) = runtime.owner.verify_payment_proof(mask_ref, &proof)?;
+        let dto = PaymentProofVerificationDto {
+            is_sender,
+            is_recipient,
+        };
+        Ok(dto)
+    })
+}
+
+fn invalid_input(msg: Msg) -> anyhow::Error {
+    BridgeError::InvalidInput { detail: tr(msg) }.into()
+}
+
+/// Der LC-Provider meldet einen nicht entschluesselbaren Seed nur als `Lifecycle`. Nur fuer
+/// `get_mnemonic` auf einem vorhandenen Seed, dort bleibt als Ursache nur das Passwort.
+fn seed_password_error(err: grin_wallet_libwallet::Error, msg: Msg) -> anyhow::Error {
+    match err {
+        grin_wallet_libwallet::Error::Lifecycle(_) => BridgeError::WrongPassword {
+            detail: format!("{}: {err}", tr(msg)),
+        }
+        .into(),
+        err => anyhow::Error::new(err).context(tr(Msg::SeedRead)),
+    }
+}
+
+fn with_owner<R, F>(handle: WalletHandle, op: F) -> Result<R>
+where
+    F: FnOnce(&mut OwnerApi, Option<&SecretKey>) -> Result<R, grin_wallet_libwallet::Error>,
+{
+    with_runtime_mut(handle, |runtime| {
+        let mask_ref = runtime.keychain_mask.as_ref();
+        op(&mut runtime.owner, mask_ref).map_err(|e| anyhow!(e))
+    })
+}
+
+fn with_runtime_mut<R, F>(handle: WalletHandle, op: F) -> Result<R>
+where
+    F: FnOnce(&mut WalletRuntime) -> Result<R>,
+{
+    let entry = runtime_entry(handle)?;
+    let mut runtime = lock_runtime(&entry, handle)?;
+    global::set_local_chain_type(runtime.chain_type);
+    runtime.last_activity = Instant::now();
+    op(&mut runtime)
+}
+
+fn with_runtime<R, F>(handle: WalletHandle, op: F) -> Result<R>
+where
+    F: FnOnce(&WalletRuntime) -> Result<R>,
+{
+    let entry = runtime_entry(handle)?;
+    let runtime = lock_runtime(&entry, handle)?;
+    global::set_local_chain_type(runtime.chain_type);
+    op(&runtime)
+}
+
+fn lock_runtime(
+    entry: &Mutex<WalletRuntime>,
+    handle: WalletHandle,
+) -> Result<MutexGuard<'_, WalletRuntime>> {
+    let runtime = entry.lock().map_err(|_| anyhow!(tr(Msg::WalletLock)))?;
+    if runtime.closed {
+        return Err(wallet_not_open(handle));
+    }
+    Ok(runtime)
+}
+
+fn wallet_not_open(handle: WalletHandle) -> anyhow::Error {
+    BridgeError::WalletNotOpen {
+        detail: tr(Msg::WalletNotOpen { handle }),
+    }
+    .into()
+}
+
+fn runtime_entry(handle: WalletHandle) -> Result<Arc<Mutex<WalletRuntime>>> {
+    let wallets = WALLETS.lock().map_err(|_| anyhow!(tr(Msg::RegistryRead)))?;
+    wallets
+        .get(&handle)
+        .map(|entry| entry.runtime.clone())
+        .ok_or_else(|| wallet_not_open(handle))
+}
+
+/// Prueft das Verzeichnis und traegt die Runtime unter demselben Registry-Lock ein, damit zwei
+/// gleichzeitige Oeffnen-Aufrufe nicht beide durchkommen. Der Verlierer wird wieder abgebaut.
+fn register_runtime(mut runtime: WalletRuntime) -> Result<WalletHandle> {
+    let handle = runtime.handle;
+    let chain_type = runtime.chain_type;
+    let last_message = runtime.updater_last_message.clone();
+    let status_rx = runtime.status_rx.take();
+    {
+        let mut wallets = WALLETS.lock().map_err(|_| anyhow!(tr(Msg::RegistryLock)))?;
+        if let Some(err) = already_open(&wallets, &runtime.data_dir) {
+            drop(wallets);
+            shutdown_runtime(handle, &mut runtime);
+            return Err(err);
+        }
+        wallets.insert(
+            handle,
+            WalletEntry {
+                data_dir: runtime.data_dir.clone(),
+                events: runtime.events.clone(),
+                runtime: Arc::new(Mutex::new(runtime)),
+            },
+        );
+    }
+    if let Some(status_rx) = status_rx {
+        spawn_status_pump(handle, chain_type, status_rx, last_message);
+    }
+    Ok(handle)
+}
+
+/// Dasselbe Wallet-Verzeichnis darf nur einmal geoeffnet sein, sonst teilen sich zwei
+/// Runtimes dieselbe LMDB-Datenbank. Frueher Abbruch vor dem teuren Aufbau der Runtime;
+/// verbindlich ist erst die Pruefung in `register_runtime`.
+fn ensure_not_open(data_dir: &Path) -> Result<()> {
+    let wallets = WALLETS.lock().map_err(|_| anyhow!(tr(Msg::RegistryRead)))?;
+    match already_open(&wallets, data_dir) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+fn already_open(
+    wallets: &HashMap<WalletHandle, WalletEntry>,
+    data_dir: &Path,
+) -> Option<anyhow::Error> {
+    wallets
+        .iter()
+        .find(|(_, entry)| entry.data_dir == data_dir)
+        .map(|(handle, _)| {
+            BridgeError::WalletAlreadyOpen {
+                detail: tr(Msg::WalletAlreadyOpen {
+                    dir: &data_dir.to_string_lossy(),
+                    handle,
+                }),
+            }
+            .into()
+        })
 }
 
-pub fn payment_proof(tx_id: u32) -> Result<String> {
-    with_runtime_mut(|runtime| {
-        let mask_ref = runtime.keychain_mask.as_ref();
-        let proof = runtime
-            .owner
-            .retrieve_payment_proof(mask_ref, true, Some(tx_id), None)?;
-        let dto = PaymentProofDto { tx_id, proof };
-        to_json(&dto)
-    })
+/// Sperrt die Wallet automatisch nach `timeout_secs` ohne Owner-Aufruf; `0` schaltet ab.
+pub fn set_auto_lock(handle: WalletHandle, timeout_secs: u64) -> Result<()> {
+    with_runtime_mut(handle, |runtime| {
+        runtime.auto_lock_after = if timeout_secs == 0 {
+            None
+        } else {
+            Some(Duration::from_secs(timeout_secs))
+        };
+        Ok(())
+    })?;
+    if timeout_secs > 0 {
+        start_auto_lock_watchdog();
+    }
+    Ok(())
 }
 
-pub fn verify_payment_proof(serialized: &str) -> Result<String> {
-    let proof: PaymentProof = serde_json::from_str(serialized)
-        .map_err(|e| anyhow!("Payment Proof konnte nicht gelesen werden: {e}"))?;
-    with_runtime_mut(|runtime| {
-        let mask_ref = runtime.keychain_mask.as_ref();
-        let (is_sender, is_recipient) = runtime.owner.verify_payment_proof(mask_ref, &proof)?;
-        let dto = PaymentProofVerificationDto {
-            is_sender,
-            is_recipient,
+/// Setzt den Standard-TTL fuer Sends, Rechnungszahlungen und Rechnungen; 0 schaltet ihn ab.
+/// Der Wert wird im Wallet-Verzeichnis gespeichert und gilt auch nach dem naechsten Oeffnen.
+pub fn set_default_ttl(handle: WalletHandle, ttl_blocks: u64) -> Result<()> {
+    with_runtime_mut(handle, |runtime| {
+        fs::write(
+            runtime.data_dir.join(DEFAULT_TTL_FILE_NAME),
+            ttl_blocks.to_string(),
+        )
+        .with_context(|| tr(Msg::DefaultTtlWrite))?;
+        runtime.default_ttl_blocks = if ttl_blocks == 0 {
+            None
+        } else {
+            Some(ttl_blocks)
         };
-        to_json(&dto)
+        Ok(())
     })
 }
 
-fn with_owner<R, F>(op: F) -> Result<R>
-where
-    F: FnOnce(&mut OwnerApi, Option<&SecretKey>) -> Result<R, grin_wallet_libwallet::Error>,
-{
-    with_runtime_mut(|runtime| {
-        let mask_ref = runtime.keychain_mask.as_ref();
-        op(&mut runtime.owner, mask_ref).map_err(|e| anyhow!(e))
-    })
+fn stored_default_ttl(data_dir: &Path) -> Option<u64> {
+    let stored = fs::read_to_string(data_dir.join(DEFAULT_TTL_FILE_NAME))
+        .ok()
+        .and_then(|raw| raw.trim().parse::<u64>().ok());
+    match stored {
+        Some(0) => None,
+        Some(blocks) => Some(blocks),
+        None => Some(DEFAULT_TTL_BLOCKS),
+    }
 }
 
-fn with_runtime_mut<R, F>(op: F) -> Result<R>
-where
-    F: FnOnce(&mut WalletRuntime) -> Result<R>,
-{
-    let mut guard = WALLET_RUNTIME
-        .lock()
-        .map_err(|_| anyhow!("Wallet-Lock konnte nicht bezogen werden"))?;
-    let runtime = guard.as_mut().ok_or_else(|| {
-        anyhow!("Wallet ist noch nicht initialisiert. Bitte wallet_init_or_open zuerst aufrufen.")
-    })?;
-    op(runtime)
+fn start_auto_lock_watchdog() {
+    AUTO_LOCK_WATCHDOG.call_once(|| {
+        let spawned = thread::Builder::new()
+            .name("wallet-auto-lock".to_string())
+            .spawn(|| loop {
+                thread::sleep(AUTO_LOCK_POLL_INTERVAL);
+                for handle in idle_wallets() {
+                    if let Ok(Some(idle)) = auto_lock_runtime(handle) {
+                        log_listener_event(&format!("Wallet {handle} auto-locked"));
+                        events::emit(&WalletEventDto::AutoLocked {
+                            handle,
+                            idle_secs: idle.as_secs(),
+                        });
+                    }
+                }
+            });
+        if let Err(err) = spawned {
+            log_listener_event(&format!("Auto-lock watchdog failed to start: {err}"));
+        }
+    });
 }
 
-fn with_runtime<R, F>(op: F) -> Result<R>
-where
-    F: FnOnce(&WalletRuntime) -> Result<R>,
-{
-    let guard = WALLET_RUNTIME
-        .lock()
-        .map_err(|_| anyhow!("Wallet-Lock konnte nicht bezogen werden"))?;
-    let runtime = guard.as_ref().ok_or_else(|| {
-        anyhow!("Wallet ist noch nicht initialisiert. Bitte wallet_init_or_open zuerst aufrufen.")
-    })?;
-    op(runtime)
+/// Kandidaten fuer Auto-Lock. Laufende Operationen halten den Runtime-Lock und gelten als
+/// Aktivitaet, deshalb nur `try_lock`; endgueltig entscheidet `auto_lock_runtime`.
+fn idle_wallets() -> Vec<WalletHandle> {
+    let wallets = match WALLETS.lock() {
+        Ok(wallets) => wallets,
+        Err(_) => return Vec::new(),
+    };
+    wallets
+        .iter()
+        .filter_map(|(handle, entry)| {
+            let runtime = entry.runtime.try_lock().ok()?;
+            let timeout = runtime.auto_lock_after?;
+            (runtime.last_activity.elapsed() >= timeout).then_some(*handle)
+        })
+        .collect()
+}
+
+/// Gleicht alle offenen Wallets einmal ab, sobald Dart `wallet_events` abonniert. Der erste
+/// Abgleich fuellt nur den Snapshot, danach melden Updater und Listener die Aenderungen.
+pub fn prime_wallet_events() {
+    let watches: Vec<Arc<EventWatch>> = match WALLETS.lock() {
+        Ok(wallets) => wallets.values().map(|entry| entry.events.clone()).collect(),
+        Err(_) => return,
+    };
+    let spawned = thread::Builder::new()
+        .name("wallet-events-prime".to_string())
+        .spawn(move || {
+            for watch in watches {
+                watch.check(false);
+            }
+        });
+    if let Err(err) = spawned {
+        log_listener_event(&format!("Event priming failed to start: {err}"));
+    }
 }
 
-fn tx_direction(tx_type: &TxLogEntryType) -> &'static str {
-    match tx_type {
-        TxLogEntryType::TxSent | TxLogEntryType::TxSentCancelled => "sent",
-        TxLogEntryType::TxReverted => "reverted",
-        _ => "received",
+/// Meldet `NodeConnectivityChanged` nur beim Wechsel und nur, solange jemand zuhoert.
+fn note_node_reachable(reachable: bool, tip_height: Option<u64>) {
+    let Ok(mut last) = NODE_REACHABLE.lock() else {
+        return;
+    };
+    if !events::has_subscribers() {
+        *last = None;
+        return;
+    }
+    if *last != Some(reachable) {
+        *last = Some(reachable);
+        events::emit(&WalletEventDto::NodeConnectivityChanged {
+            reachable,
+            tip_height,
+        });
     }
 }
 
-fn tx_status(entry: &TxLogEntry) -> &'static str {
-    match entry.tx_type {
-        TxLogEntryType::TxReceivedCancelled | TxLogEntryType::TxSentCancelled => "cancelled",
-        TxLogEntryType::TxReverted => "reverted",
-        _ => {
-            if entry.confirmed {
-                "confirmed"
-            } else {
-                "pending"
+/// Vergleicht Tx-Log und Saldo einer Wallet mit dem letzten Abgleich. Haengt an einem eigenen
+/// `Owner` und an der geteilten Maske, braucht also den Runtime-Lock nicht.
+struct EventWatch {
+    handle: WalletHandle,
+    owner: OwnerApi,
+    mask: SharedMask,
+    chain_type: ChainTypes,
+    snapshot: Mutex<EventSnapshot>,
+    /// Listener-Requests seit dem letzten Abgleich; ueber 0 laeuft ein Hook-Thread.
+    pending_requests: AtomicUsize,
+}
+
+/// Stand einer Wallet beim letzten Abgleich; gilt nur fuer den Account in `account`.
+#[derive(Default)]
+struct EventSnapshot {
+    account: Option<String>,
+    txs: HashMap<u32, (TxLogEntryType, u64)>,
+    balance: Option<WalletInfoDto>,
+}
+
+impl EventWatch {
+    fn new(
+        handle: WalletHandle,
+        wallet: WalletBackendInstance,
+        status_tx: Sender<StatusMessage>,
+        mask: SharedMask,
+        chain_type: ChainTypes,
+    ) -> Self {
+        EventWatch {
+            handle,
+            owner: Owner::new(wallet, Some(status_tx)),
+            mask,
+            chain_type,
+            snapshot: Mutex::new(EventSnapshot::default()),
+            pending_requests: AtomicUsize::new(0),
+        }
+    }
+
+    /// Als Listener-Hook: nach jedem Request an Foreign- oder Owner-API abgleichen. Der Hook
+    /// laeuft im Poll der Response, der Abgleich selbst deshalb auf einem eigenen Thread.
+    fn hook(self: &Arc<Self>) -> RequestHook {
+        let watch = self.clone();
+        Arc::new(move || watch.schedule_check())
+    }
+
+    /// Startet hoechstens einen Hook-Thread. Kommen waehrend des Abgleichs weitere Requests,
+    /// gleicht derselbe Thread danach noch einmal ab.
+    fn schedule_check(self: &Arc<Self>) {
+        if self.pending_requests.fetch_add(1, Ordering::AcqRel) > 0 {
+            return;
+        }
+        let watch = self.clone();
+        let spawned = thread::Builder::new()
+            .name(format!("wallet-events-{}", self.handle))
+            .spawn(move || loop {
+                let seen = watch.pending_requests.load(Ordering::Acquire);
+                watch.check(false);
+                if watch
+                    .pending_requests
+                    .compare_exchange(seen, 0, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+                {
+                    break;
+                }
+            });
+        if let Err(err) = spawned {
+            self.pending_requests.store(0, Ordering::Release);
+            log_listener_event(&format!("Event check failed to start: {err}"));
+        }
+    }
+
+    fn check(&self, refreshed: bool) {
+        if let Err(err) = self.try_check(refreshed) {
+            log_listener_event(&format!("Event check for wallet {}: {err}", self.handle));
+        }
+    }
+
+    fn try_check(&self, refreshed: bool) -> Result<()> {
+        let mut snapshot = self
+            .snapshot
+            .lock()
+            .map_err(|_| anyhow!(tr(Msg::WalletLock)))?;
+        // Ohne Listener nichts abgleichen; ein neuer Listener startet mit frischem Snapshot.
+        if !events::has_subscribers() {
+            *snapshot = EventSnapshot::default();
+            return Ok(());
+        }
+        global::set_local_chain_type(self.chain_type);
+        let mask = self.mask.lock().clone();
+        let mask = mask.as_ref();
+        let account = active_account_label(&self.owner, mask)?;
+        let (_, info) = self.owner.retrieve_summary_info(mask, false, 10)?;
+        let (_, entries) = self.owner.retrieve_txs(mask, false, None, None, None)?;
+        let confirmations = tx_confirmations(&self.owner, mask, false)?;
+        let tip = Some(info.last_confirmed_height);
+        let balance = WalletInfoDto::from_info(refreshed, info, account.clone());
+        // Nach einem Account-Wechsel gilt der alte Snapshot nicht mehr.
+        if snapshot.account.as_ref() != Some(&account) {
+            *snapshot = EventSnapshot {
+                account: Some(account),
+                ..EventSnapshot::default()
+            };
+        }
+        let handle = self.handle;
+        // Der erste Abgleich fuellt nur den Snapshot, sonst kaeme beim Abonnieren die Historie.
+        let primed = snapshot.balance.is_some();
+        let mut pending = Vec::new();
+        for entry in entries {
+            let confs = confirmations.get(&entry.id).copied().unwrap_or(0);
+            let previous = snapshot
+                .txs
+                .insert(entry.id, (entry.tx_type.clone(), confs));
+            if !primed {
+                continue;
+            }
+            match previous {
+                Some((prev_type, _)) if expired_now(&prev_type, &entry, tip) => {
+                    let sent = entry.tx_type == TxLogEntryType::TxSentCancelled;
+                    pending.push(WalletEventDto::TxExpired {
+                        handle,
+                        tx_id: entry.id,
+                        tx_slate_id: entry.tx_slate_id.map(|id| id.to_string()),
+                        unlocked_amount: if sent { entry.amount_debited } else { 0 },
+                        unlocked_inputs: if sent { entry.num_inputs } else { 0 },
+                    });
+                }
+                None if entry.tx_type == TxLogEntryType::TxReceived && !entry.confirmed => {
+                    pending.push(WalletEventDto::IncomingSlate {
+                        handle,
+                        tx: TransactionDto::from_entry(entry, confs),
+                    });
+                }
+                Some((prev_type, _))
+                    if entry.tx_type == TxLogEntryType::TxReverted
+                        && prev_type != TxLogEntryType::TxReverted =>
+                {
+                    pending.push(WalletEventDto::TxReverted {
+                        handle,
+                        tx_id: entry.id,
+                    });
+                }
+                Some((_, prev_confs))
+                    if prev_confs != confs && prev_confs < EVENT_CONFIRMATION_TARGET =>
+                {
+                    pending.push(WalletEventDto::ConfirmationsChanged {
+                        handle,
+                        tx_id: entry.id,
+                        confirmations: confs,
+                    });
+                }
+                _ => {}
             }
         }
+        let balance_changed = match snapshot.balance.as_ref() {
+            Some(previous) => !previous.same_balance(&balance),
+            None => false,
+        };
+        if balance_changed {
+            pending.push(WalletEventDto::BalanceChanged {
+                handle,
+                balance: balance.clone(),
+            });
+        }
+        snapshot.balance = Some(balance);
+        drop(snapshot);
+        for event in &pending {
+            events::emit(event);
+        }
+        Ok(())
+    }
+}
+
+/// Label des Accounts, auf den die Wallet-Instanz gerade zeigt.
+fn active_account_label(owner: &OwnerApi, mask: Option<&SecretKey>) -> Result<String> {
+    let parent_key_id = {
+        let mut w_lock = owner.wallet_inst.lock();
+        let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+        let wallet_inst = lc.wallet_inst().with_context(|| tr(Msg::WalletInstance))?;
+        wallet_inst.parent_key_id()
+    };
+    let accounts = owner.accounts(mask)?;
+    Ok(accounts
+        .into_iter()
+        .find(|acct| acct.path == parent_key_id)
+        .map(|acct| acct.label)
+        .unwrap_or_default())
+}
+
+/// Storniert unbestaetigte Txs des aktiven Accounts, deren `ttl_cutoff_height` erreicht ist.
+/// libwallet erledigt das teils schon beim Refresh; das Event entsteht deshalb aus dem
+/// Snapshot-Vergleich, egal wer storniert hat.
+fn cancel_expired_txs(owner: &OwnerApi, mask: Option<&SecretKey>, tip: u64) -> Result<()> {
+    let (_, entries) = owner.retrieve_txs(mask, false, None, None, None)?;
+    for entry in entries {
+        if !is_open_tx_type(&entry.tx_type) || entry.confirmed || !tx_expired(&entry, Some(tip)) {
+            continue;
+        }
+        if let Err(err) = owner.cancel_tx(mask, Some(entry.id), None) {
+            log_listener_event(&format!("Cancelling expired tx {}: {err}", entry.id));
+        }
     }
+    Ok(())
+}
+
+/// Sweep ausserhalb des Updaters, z.B. nach `sync`, gegen die aktuelle Node-Hoehe.
+fn sweep_expired_txs(runtime: &WalletRuntime) -> Result<()> {
+    let mask = runtime.keychain_mask.as_ref();
+    let tip = runtime.owner.node_height(mask)?.height;
+    cancel_expired_txs(&runtime.owner, mask, tip)
+}
+
+/// Sweep nach dem Oeffnen; laeuft im Hintergrund, damit eine langsame Node das Oeffnen nicht
+/// aufhaelt.
+fn spawn_expiry_sweep(handle: WalletHandle) {
+    let spawned = thread::Builder::new()
+        .name(format!("wallet-ttl-sweep-{handle}"))
+        .spawn(move || {
+            if let Err(err) = with_runtime(handle, sweep_expired_txs) {
+                log_listener_event(&format!("TTL sweep for wallet {handle}: {err}"));
+            }
+        });
+    if let Err(err) = spawned {
+        log_listener_event(&format!(
+            "TTL sweep for wallet {handle} failed to start: {err}"
+        ));
+    }
+}
+
+/// Nur der Wechsel von offen auf storniert nach Ablauf des TTL; bestaetigte Txs behalten
+/// ihren Typ und loesen nichts aus.
+fn expired_now(prev_type: &TxLogEntryType, entry: &TxLogEntry, tip: Option<u64>) -> bool {
+    let cancelled = match prev_type {
+        TxLogEntryType::TxSent => TxLogEntryType::TxSentCancelled,
+        TxLogEntryType::TxReceived => TxLogEntryType::TxReceivedCancelled,
+        _ => return false,
+    };
+    entry.tx_type == cancelled && tx_expired(entry, tip)
+}
+
+fn is_open_tx_type(tx_type: &TxLogEntryType) -> bool {
+    matches!(tx_type, TxLogEntryType::TxSent | TxLogEntryType::TxReceived)
 }
 
-fn to_json<T: Serialize>(value: &T) -> Result<String> {
-    serde_json::to_string(value).map_err(|e| anyhow!("JSON konnte nicht erzeugt werden: {e}"))
+/// Abgelaufen und bereits storniert bzw. jetzt stornierbar.
+fn tx_expired(entry: &TxLogEntry, tip: Option<u64>) -> bool {
+    match (entry.ttl_cutoff_height, tip) {
+        (Some(cutoff), Some(tip)) => cutoff <= tip,
+        _ => false,
+    }
 }
 
 fn epoch_secs() -> u64 {
@@ -854,17 +2617,20 @@ fn resolve_data_dir(data_dir: &str) -> Result<PathBuf> {
         Ok(path.to_path_buf())
     } else {
         Ok(std::env::current_dir()
-            .context("Arbeitsverzeichnis konnte nicht bestimmt werden")?
+            .with_context(|| tr(Msg::WorkingDir))?
             .join(path))
     }
 }
 
-fn build_runtime(data_dir: &Path, passphrase: &str, node_url: &str) -> Result<WalletRuntime> {
-    let mut wallet = build_wallet_backend(data_dir, node_url)?;
-    let mut wallet_config = WalletConfig::default();
-    wallet_config.chain_type = Some(ChainTypes::Mainnet);
-    wallet_config.check_node_api_http_addr = node_url.to_owned();
-    wallet_config.data_file_dir = data_dir.to_string_lossy().to_string();
+fn build_runtime(
+    data_dir: &Path,
+    passphrase: &str,
+    node_url: &str,
+    chain_type: ChainTypes,
+) -> Result<WalletRuntime> {
+    let scan_control = Arc::new(ScanControl::default());
+    let mut wallet = build_wallet_backend(data_dir, node_url, scan_control.clone())?;
+    let mut wallet_config = base_wallet_config(data_dir, node_url, chain_type);
     wallet_config.api_secret_path = Some(
         data_dir
             .join(OWNER_API_SECRET_FILE_NAME)
@@ -879,34 +2645,35 @@ fn build_runtime(data_dir: &Path, passphrase: &str, node_url: &str) -> Result<Wa
     );
     wallet_config.owner_api_include_foreign = Some(false);
     wallet_config.data_file_dir = data_dir.to_string_lossy().to_string();
+    if let Some(stored) = stored_wallet_config(data_dir) {
+        wallet_config.api_listen_port = stored.api_listen_port;
+        wallet_config.owner_api_listen_port = stored.owner_api_listen_port;
+    }
     let mut tor_config = TorConfig::default();
     tor_config.send_config_dir = data_dir.to_string_lossy().to_string();
 
     std::fs::create_dir_all(&wallet_config.data_file_dir).with_context(|| {
-        format!(
-            "Konnte Wallet-Verzeichnis nicht anlegen: {}",
-            wallet_config.data_file_dir
-        )
+        tr(Msg::DataDirCreate {
+            dir: &wallet_config.data_file_dir,
+        })
     })?;
 
     {
-        let lc = wallet
-            .lc_provider()
-            .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
+        let lc = wallet.lc_provider().with_context(|| tr(Msg::LcProvider))?;
         lc.set_top_level_directory(&wallet_config.data_file_dir)
-            .map_err(|e| anyhow!("Top-Level-Verzeichnis konnte nicht gesetzt werden: {e}"))?;
+            .with_context(|| tr(Msg::TopLevelDirSet))?;
     }
 
     let wallet_arc: WalletBackendInstance = Arc::new(GrinMutex::new(wallet));
-    let mut owner_api = Owner::new(wallet_arc.clone(), None);
+    // Eigener Statuskanal, damit Updater- und Scan-Meldungen bei Dart statt auf stdout landen.
+    let (status_tx, status_rx) = mpsc::channel();
+    let mut owner_api = Owner::new(wallet_arc.clone(), Some(status_tx.clone()));
 
     let wallet_exists = {
         let mut lock = wallet_arc.lock();
-        let lc = lock
-            .lc_provider()
-            .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
+        let lc = lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
         lc.wallet_exists(None)
-            .map_err(|e| anyhow!("Wallet-Existenz konnte nicht geprueft werden: {e}"))?
+            .with_context(|| tr(Msg::WalletExistsCheck))?
     };
 
     let password = ZeroingString::from(passphrase);
@@ -928,46 +2695,80 @@ fn build_runtime(data_dir: &Path, passphrase: &str, node_url: &str) -> Result<Wa
             restore: false,
         };
         command::init(&mut owner_api, &global_args, init_args, false)
-            .map_err(|e| anyhow!("Wallet-Initialisierung fehlgeschlagen: {e}"))?;
+            .with_context(|| tr(Msg::WalletInit))?;
     }
 
     let keychain_mask = {
         let mut lock = wallet_arc.lock();
-        let lc = lock
-            .lc_provider()
-            .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
+        let lc = lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+        // Erst nur den Seed entschluesseln; `open_wallet` meldet auch DB-Fehler als Lifecycle.
+        lc.get_mnemonic(None, password.clone())
+            .map_err(|e| seed_password_error(e, Msg::WrongPassword))?;
         let mask = lc
             .open_wallet(None, password, false, false)
-            .map_err(|e| anyhow!("Wallet konnte nicht geoefnet werden: {e}"))?;
-        let wallet_inst = lc
-            .wallet_inst()
-            .map_err(|e| anyhow!("Wallet-Instanz konnte nicht geladen werden: {e}"))?;
+            .with_context(|| tr(Msg::WalletOpen))?;
+        let wallet_inst = lc.wallet_inst().with_context(|| tr(Msg::WalletInstance))?;
         wallet_inst
             .set_parent_key_id_by_name(&global_args.account)
-            .map_err(|e| anyhow!("Account 'default' konnte nicht gesetzt werden: {e}"))?;
+            .with_context(|| {
+                tr(Msg::AccountSet {
+                    label: &global_args.account,
+                })
+            })?;
         mask
     };
 
-    let runtime = WalletRuntime {
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let listener_mask: SharedMask = Arc::new(GrinMutex::new(keychain_mask.clone()));
+    let events = Arc::new(EventWatch::new(
+        handle,
+        wallet_arc.clone(),
+        status_tx,
+        listener_mask.clone(),
+        chain_type,
+    ));
+    let mut runtime = WalletRuntime {
+        handle,
         owner: owner_api,
+        listener_mask,
         keychain_mask,
         config: wallet_config.clone(),
         tor_config,
-        _data_dir: data_dir.to_path_buf(),
+        data_dir: data_dir.to_path_buf(),
         _node_url: node_url.to_owned(),
+        chain_type,
         active_account: global_args.account,
+        foreign_listener: None,
+        tor: None,
+        owner_listener: None,
+        last_activity: Instant::now(),
+        auto_lock_after: None,
+        closed: false,
+        updater: None,
+        updater_last_message: Arc::new(Mutex::new(None)),
+        status_rx: Some(status_rx),
+        scan_control,
+        events,
+        default_ttl_blocks: stored_default_ttl(data_dir),
+        require_review: false,
+        pending_reviews: HashMap::new(),
     };
 
-    let listen_addr = runtime.config.api_listen_addr();
-    if let Err(err) = spawn_foreign_listener(
+    // Jede Wallet bekommt ihren eigenen Port, damit mehrere gleichzeitig empfangen koennen.
+    let port = claim_listen_port(
+        &runtime.config.api_listen_interface,
+        runtime.config.api_listen_port,
+    )?;
+    runtime.config.api_listen_port = port.0;
+    let server = start_foreign_listener(
         runtime.owner.wallet_inst.clone(),
-        runtime.keychain_mask.clone(),
-        listen_addr,
-    ) {
-        log_listener_event(&format!(
-            "Foreign listener konnte nicht gestartet werden: {err}"
-        ));
-    }
+        runtime.listener_mask.clone(),
+        &runtime.config.api_listen_addr(),
+        port,
+        chain_type,
+        runtime.events.hook(),
+    )?;
+    runtime.foreign_listener = Some(server);
 
     Ok(runtime)
 }
@@ -975,29 +2776,115 @@ fn build_runtime(data_dir: &Path, passphrase: &str, node_url: &str) -> Result<Wa
 fn build_wallet_backend(
     _data_dir: &Path,
     node_url: &str,
+    scan_control: Arc<ScanControl>,
 ) -> Result<
     Box<
         dyn WalletInst<
             'static,
-            DefaultLCProvider<'static, HTTPNodeClient, ExtKeychain>,
-            HTTPNodeClient,
+            DefaultLCProvider<'static, BridgeNodeClient, ExtKeychain>,
+            BridgeNodeClient,
             ExtKeychain,
         >,
     >,
 > {
-    let node_client = HTTPNodeClient::new(node_url, None)
-        .map_err(|e| anyhow!("HTTPNodeClient konnte nicht erstellt werden: {e}"))?;
-    let wallet_backend = DefaultWalletImpl::<'static, HTTPNodeClient>::new(node_client.clone())
-        .map_err(|e| anyhow!("Wallet-Backend konnte nicht erstellt werden: {e}"))?;
+    let http_client =
+        HTTPNodeClient::new(node_url, None).with_context(|| tr(Msg::NodeClientCreate))?;
+    let node_client = BridgeNodeClient::new(http_client, scan_control);
+    let wallet_backend = DefaultWalletImpl::<'static, BridgeNodeClient>::new(node_client)
+        .with_context(|| tr(Msg::WalletBackendCreate))?;
     Ok(Box::new(wallet_backend))
 }
 
-fn ensure_chain_type() {
-    CHAIN_INIT.call_once(|| {
-        global::init_global_chain_type(ChainTypes::Mainnet);
-        global::set_local_chain_type(ChainTypes::Mainnet);
+/// Basis-Konfiguration fuer eine Wallet; Testnet bekommt die Standard-Ports von grin-wallet,
+/// damit sich Mainnet- und Testnet-Listener nicht in die Quere kommen.
+fn base_wallet_config(data_dir: &Path, node_url: &str, chain_type: ChainTypes) -> WalletConfig {
+    let mut wallet_config = WalletConfig::default();
+    wallet_config.chain_type = Some(chain_type);
+    wallet_config.check_node_api_http_addr = node_url.to_owned();
+    wallet_config.data_file_dir = data_dir.to_string_lossy().to_string();
+    if chain_type == ChainTypes::Testnet {
+        wallet_config.api_listen_port = 13415;
+        wallet_config.owner_api_listen_port = Some(13420);
+    }
+    wallet_config
+}
+
+/// Liest den Chain-Typ aus einer bereits vorhandenen `grin-wallet.toml`.
+fn stored_chain_type(data_dir: &Path) -> Option<ChainTypes> {
+    stored_wallet_config(data_dir).and_then(|config| config.chain_type)
+}
+
+fn stored_wallet_config(data_dir: &Path) -> Option<WalletConfig> {
+    let config_path = data_dir.join(WALLET_CONFIG_FILE_NAME);
+    if !config_path.exists() {
+        return None;
+    }
+    GlobalWalletConfig::new(&config_path.to_string_lossy())
+        .ok()
+        .and_then(|cfg| cfg.members)
+        .map(|members| members.wallet)
+}
+
+/// Reserviert ab `preferred` den ersten Port, den weder eine andere offene Wallet noch ein
+/// fremder Prozess belegt. `ApiServer::start` meldet einen belegten Port nicht zurueck, sein
+/// Thread bricht nur ab; deshalb vorher ein Probe-Bind.
+fn claim_listen_port(interface: &str, preferred: u16) -> Result<PortClaim> {
+    let mut claimed = CLAIMED_PORTS
+        .lock()
+        .map_err(|_| anyhow!(tr(Msg::RegistryLock)))?;
+    let last = preferred.saturating_add(LISTEN_PORT_ATTEMPTS - 1);
+    for port in preferred..=last {
+        if claimed.contains(&port) || TcpListener::bind((interface, port)).is_err() {
+            continue;
+        }
+        claimed.insert(port);
+        return Ok(PortClaim(port));
+    }
+    Err(BridgeError::ListenerFailed {
+        detail: tr(Msg::NoFreeListenPort {
+            from: preferred,
+            to: last,
+        }),
+    }
+    .into())
+}
+
+/// Stellt den globalen Chain-Typ auf die zuletzt geoeffnete Wallet um. Er ist nur der Fallback
+/// fuer Threads ohne eigenen Chain-Typ; Wallet-Aufrufe, Listener und alle Threads aus
+/// `spawn_wallet_thread` setzen den der Wallet thread-lokal. Der Fee-Base-Wert laesst sich in
+/// grin_core nur einmal setzen und wird deshalb weiterhin per `Once` initialisiert.
+fn ensure_chain_type(chain_type: ChainTypes) {
+    FEE_BASE_INIT.call_once(|| {
         global::init_global_accept_fee_base(WalletConfig::default_accept_fee_base());
     });
+    global::set_global_chain_type(chain_type);
+    global::set_local_chain_type(chain_type);
+    if let Ok(mut active) = ACTIVE_CHAIN_TYPE.lock() {
+        *active = chain_type;
+    }
+}
+
+fn current_chain_type() -> ChainTypes {
+    ACTIVE_CHAIN_TYPE
+        .lock()
+        .map(|active| *active)
+        .unwrap_or(ChainTypes::Mainnet)
+}
+
+/// Startet einen Hintergrund-Thread einer Wallet. `get_chain_type` faellt in einem neuen
+/// Thread auf den globalen Wert zurueck, der einer anderen Wallet gehoeren kann.
+fn spawn_wallet_thread<F>(
+    name: String,
+    chain_type: ChainTypes,
+    f: F,
+) -> std::io::Result<thread::JoinHandle<()>>
+where
+    F: FnOnce() + Send + 'static,
+{
+    thread::Builder::new().name(name).spawn(move || {
+        global::set_local_chain_type(chain_type);
+        f()
+    })
 }
 
 fn log_listener_event(event: &str) {
@@ -1007,222 +2894,415 @@ fn log_listener_event(event: &str) {
     }
 }
 
-fn spawn_foreign_listener(
+fn start_foreign_listener(
     wallet: WalletBackendInstance,
-    mask: Option<SecretKey>,
-    listen_addr: String,
-) -> Result<()> {
+    mask: SharedMask,
+    listen_addr: &str,
+    port: PortClaim,
+    chain_type: ChainTypes,
+    hook: RequestHook,
+) -> Result<ListenerServer> {
     log_listener_event(&format!("Spawning listener for {}", listen_addr));
-    let wallet_clone = wallet.clone();
-    let mask_guard = Arc::new(GrinMutex::new(mask));
-    thread::Builder::new()
-        .name("foreign-listener".to_string())
-        .spawn(move || {
-            log_listener_event("Listener thread started");
-            if let Err(err) = controller::foreign_listener(
-                wallet_clone,
-                mask_guard,
-                listen_addr.as_str(),
-                None,
-                false,
-                false,
-                None,
-            ) {
-                log_listener_event(&format!("Foreign listener failed: {err}"));
-            } else {
-                log_listener_event("Foreign listener stopped");
-            }
-        })?;
-    Ok(())
+    let api_handler = ForeignAPIHandlerV2::new(wallet, mask, false, GrinMutex::new(None));
+    let mut router = Router::new();
+    router
+        .add_route(
+            "/v2/foreign",
+            ChainScopedHandler::wrap(Arc::new(api_handler), chain_type, Some(hook)),
+        )
+        .with_context(|| tr(Msg::ForeignRoute))?;
+    start_api_server(router, listen_addr, port)
 }
 
-fn derive_onion_and_slatepack() -> Result<(grin_util::secp::key::SecretKey, OnionV3Address, String)>
-{
-    // Access current wallet instance via Owner API
-    let runtime_guard = WALLET_RUNTIME
-        .lock()
-        .map_err(|_| anyhow!("Wallet ist nicht initialisiert"))?;
-    let runtime = runtime_guard
-        .as_ref()
-        .ok_or_else(|| anyhow!("Wallet ist nicht initialisiert"))?;
+fn start_owner_listener(
+    wallet: WalletBackendInstance,
+    mask: SharedMask,
+    listen_addr: &str,
+    port: PortClaim,
+    secret: String,
+    include_foreign: bool,
+    tor_cfg: TorConfig,
+    chain_type: ChainTypes,
+    hook: RequestHook,
+) -> Result<ListenerServer> {
+    log_listener_event(&format!("Starting owner listener on {}", listen_addr));
+    let api_handler = OwnerAPIHandlerV3::new(
+        wallet.clone(),
+        mask.clone(),
+        Some(tor_cfg.clone()),
+        include_foreign,
+    );
+    let mut router = Router::new();
+    let api_basic_auth = format!("Basic {}", to_base64(&format!("grin:{secret}")));
+    router.add_middleware(Arc::new(BasicAuthMiddleware::new(
+        api_basic_auth,
+        &GRIN_OWNER_BASIC_REALM,
+        Some("/v2/foreign".into()),
+    )));
+    router
+        .add_route(
+            "/v3/owner",
+            ChainScopedHandler::wrap(Arc::new(api_handler), chain_type, Some(hook.clone())),
+        )
+        .with_context(|| tr(Msg::OwnerRoute))?;
+    if include_foreign {
+        let foreign_handler =
+            ForeignAPIHandlerV2::new(wallet, mask, false, GrinMutex::new(Some(tor_cfg)));
+        router
+            .add_route(
+                "/v2/foreign",
+                ChainScopedHandler::wrap(Arc::new(foreign_handler), chain_type, Some(hook)),
+            )
+            .with_context(|| tr(Msg::ForeignRoute))?;
+    }
+    start_api_server(router, listen_addr, port)
+}
+
+fn start_api_server(router: Router, listen_addr: &str, port: PortClaim) -> Result<ListenerServer> {
+    let socket_addr: SocketAddr = listen_addr.parse().map_err(|e| BridgeError::InvalidInput {
+        detail: format!(
+            "{}: {e}",
+            tr(Msg::ListenerAddrInvalid { addr: listen_addr })
+        ),
+    })?;
+    // ApiServer erwartet einen 'static Kanal; pro Listener ein kleines Leak wie im Controller.
+    let api_chan: &'static mut (oneshot::Sender<()>, oneshot::Receiver<()>) =
+        Box::leak(Box::new(oneshot::channel::<()>()));
+    let mut server = ApiServer::new();
+    let thread = server
+        .start(socket_addr, router, None, api_chan)
+        .map_err(|e| BridgeError::ListenerFailed {
+            detail: format!("{}: {e}", tr(Msg::ListenerStart { addr: listen_addr })),
+        })?;
+    log_listener_event(&format!("Listener started on {}", listen_addr));
+    Ok(ListenerServer {
+        server,
+        thread: Some(thread),
+        listen_addr: listen_addr.to_string(),
+        _port: port,
+    })
+}
 
+fn derive_onion_and_slatepack(
+    runtime: &WalletRuntime,
+) -> Result<(grin_util::secp::key::SecretKey, OnionV3Address, String)> {
     let mut w_lock = runtime.owner.wallet_inst.lock();
-    let lc = w_lock
-        .lc_provider()
-        .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
-    let w_inst = lc
-        .wallet_inst()
-        .map_err(|e| anyhow!("Wallet-Instanz konnte nicht geladen werden: {e}"))?;
+    let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+    let w_inst = lc.wallet_inst().with_context(|| tr(Msg::WalletInstance))?;
     let k = w_inst
         .keychain(runtime.keychain_mask.as_ref())
-        .map_err(|e| anyhow!("Keychain konnte nicht geladen werden: {e}"))?;
+        .with_context(|| tr(Msg::Keychain))?;
     let parent_key_id = w_inst.parent_key_id();
     let sec_key = address::address_from_derivation_path(&k, &parent_key_id, 0)
-        .map_err(|e| anyhow!("Address key konnte nicht abgeleitet werden: {e}"))?;
-    let onion = OnionV3Address::from_private(&sec_key.0)
-        .map_err(|e| anyhow!("Onion v3 konnte nicht erzeugt werden: {e}"))?;
+        .with_context(|| tr(Msg::AddressKey))?;
+    let onion = OnionV3Address::from_private(&sec_key.0).with_context(|| tr(Msg::OnionDerive))?;
     let sp = grin_wallet_libwallet::SlatepackAddress::try_from(onion.clone())
-        .map_err(|e| anyhow!("Slatepack-Adresse konnte nicht erzeugt werden: {e}"))?;
-    let sp_str = String::try_from(&sp)
-        .map_err(|e| anyhow!("Slatepack-Adresse konnte nicht serialisiert werden: {e}"))?;
+        .with_context(|| tr(Msg::SlatepackAddressDerive))?;
+    let sp_str = String::try_from(&sp).with_context(|| tr(Msg::SlatepackAddressSerialize))?;
     Ok((sec_key, onion, sp_str))
 }
 
-pub fn tor_status() -> Result<String> {
-    // Running?
-    let (running, onion_str, slatepack_str) = {
-        let guard = TOR_RUNTIME
-            .lock()
-            .map_err(|_| anyhow!("Tor-Status konnte nicht gelesen werden"))?;
-        if let Some(rt) = guard.as_ref() {
-            (true, rt.onion.clone(), rt.slatepack.clone())
-        } else {
-            let (_sk, onion, sp) = derive_onion_and_slatepack()?;
-            (false, onion.to_http_str(), sp)
-        }
+pub fn tor_status(handle: WalletHandle) -> Result<TorStatusDto> {
+    with_runtime(handle, tor_status_dto)
+}
+
+fn tor_status_dto(runtime: &WalletRuntime) -> Result<TorStatusDto> {
+    let (running, onion_str, slatepack_str) = if let Some(rt) = runtime.tor.as_ref() {
+        (true, rt.onion.clone(), rt.slatepack.clone())
+    } else {
+        let (_sk, onion, sp) = derive_onion_and_slatepack(runtime)?;
+        (false, onion.to_http_str(), sp)
     };
-    let dto = TorStatusDto {
+    Ok(TorStatusDto {
         running,
         onion_address: Some(onion_str),
         slatepack_address: Some(slatepack_str),
-    };
-    to_json(&dto)
+    })
 }
 
 fn read_owner_secret(path: &Path) -> Result<String> {
     if !path.exists() {
-        init_api_secret(&path.to_path_buf())
-            .map_err(|e| anyhow!("Owner API Secret konnte nicht erstellt werden: {e}"))?;
+        init_api_secret(&path.to_path_buf()).with_context(|| tr(Msg::OwnerSecretCreate))?;
     }
-    let raw = fs::read_to_string(path)
-        .map_err(|e| anyhow!("Owner API Secret konnte nicht gelesen werden: {e}"))?;
+    let raw = fs::read_to_string(path).with_context(|| tr(Msg::OwnerSecretRead))?;
     let secret = raw.trim().to_string();
     if secret.is_empty() {
-        Err(anyhow!("Owner API Secret ist leer"))
+        Err(anyhow!(tr(Msg::OwnerSecretEmpty)))
     } else {
         Ok(secret)
     }
 }
 
-pub fn owner_listener_status() -> Result<String> {
-    let (running, listen_addr) = {
-        let guard = OWNER_LISTENER
-            .lock()
-            .map_err(|_| anyhow!("Owner listener Lock fehlgeschlagen"))?;
-        if let Some(rt) = guard.as_ref() {
-            (true, rt.listen_addr.clone())
-        } else {
-            let addr = with_runtime(|runtime| Ok(runtime.config.owner_api_listen_addr()))?;
-            (false, addr)
-        }
+/// Adresse des Foreign-Listeners; der Port kann vom konfigurierten abweichen, wenn eine
+/// andere offene Wallet ihn schon belegt.
+pub fn foreign_listener_addr(handle: WalletHandle) -> Result<String> {
+    with_runtime(handle, |runtime| Ok(runtime.config.api_listen_addr()))
+}
+
+pub fn owner_listener_status(handle: WalletHandle) -> Result<OwnerListenerStatusDto> {
+    with_runtime(handle, |runtime| Ok(owner_listener_status_dto(runtime)))
+}
+
+fn owner_listener_status_dto(runtime: &WalletRuntime) -> OwnerListenerStatusDto {
+    let (running, listen_addr) = match runtime.owner_listener.as_ref() {
+        Some(rt) => (true, rt.listen_addr.clone()),
+        None => (false, runtime.config.owner_api_listen_addr()),
+    };
+    let message = if running {
+        tr(Msg::OwnerListenerRunning { addr: &listen_addr })
+    } else {
+        tr(Msg::OwnerListenerStopped)
     };
-    let dto = OwnerListenerStatusDto {
+    OwnerListenerStatusDto {
         running,
         listen_addr,
-        message: None,
-    };
-    to_json(&dto)
+        message: Some(message),
+    }
 }
 
-pub fn owner_listener_start() -> Result<String> {
-    {
-        let guard = OWNER_LISTENER
-            .lock()
-            .map_err(|_| anyhow!("Owner listener Lock fehlgeschlagen"))?;
-        if guard.is_some() {
-            return owner_listener_status();
+pub fn owner_listener_start(handle: WalletHandle) -> Result<OwnerListenerStatusDto> {
+    with_runtime_mut(handle, |runtime| {
+        if runtime.owner_listener.is_some() {
+            return Ok(owner_listener_status_dto(runtime));
         }
+        let secret_path = runtime
+            .config
+            .api_secret_path
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| runtime.data_dir.join(OWNER_API_SECRET_FILE_NAME));
+        let secret = read_owner_secret(&secret_path)?;
+        // Die Owner API lauscht bei grin-wallet immer auf 127.0.0.1.
+        let port = claim_listen_port("127.0.0.1", runtime.config.owner_api_listen_port())?;
+        runtime.config.owner_api_listen_port = Some(port.0);
+        let server = start_owner_listener(
+            runtime.owner.wallet_inst.clone(),
+            runtime.listener_mask.clone(),
+            &runtime.config.owner_api_listen_addr(),
+            port,
+            secret,
+            runtime.config.owner_api_include_foreign.unwrap_or(false),
+            runtime.tor_config.clone(),
+            runtime.chain_type,
+            runtime.events.hook(),
+        )?;
+        runtime.owner_listener = Some(server);
+        Ok(owner_listener_status_dto(runtime))
+    })
+}
+
+/// Startet den Updater, der die Wallet alle `interval_secs` gegen die Node abgleicht.
+/// Laeuft er schon, wird er mit dem neuen Intervall neu gestartet.
+pub fn start_updater(handle: WalletHandle, interval_secs: u64) -> Result<UpdaterStatusDto> {
+    if interval_secs == 0 {
+        return Err(invalid_input(Msg::UpdaterIntervalZero));
     }
-    let (wallet_inst, mask, include_foreign, tor_cfg, listen_addr, secret_path) =
-        with_runtime(|runtime| {
-            let path = runtime
-                .config
-                .api_secret_path
-                .as_ref()
-                .map(PathBuf::from)
-                .unwrap_or_else(|| runtime._data_dir.join(OWNER_API_SECRET_FILE_NAME));
-            Ok((
-                runtime.owner.wallet_inst.clone(),
-                runtime.keychain_mask.clone(),
-                runtime.config.owner_api_include_foreign.unwrap_or(false),
-                runtime.tor_config.clone(),
-                runtime.config.owner_api_listen_addr(),
-                path,
-            ))
-        })?;
-    let secret = read_owner_secret(&secret_path)?;
-    log_listener_event(&format!("Starting owner listener on {}", listen_addr));
+    let entry = runtime_entry(handle)?;
+    with_runtime_mut(handle, |runtime| {
+        if let Some(updater) = runtime.updater.take() {
+            updater.stop();
+        }
+        let interval = Duration::from_secs(interval_secs);
+        let updater = spawn_updater(handle, runtime, entry, interval)?;
+        runtime.updater = Some(updater);
+        updater_status_dto(runtime)
+    })
+}
 
-    let listen_addr_thread = listen_addr.clone();
-    let handle = thread::Builder::new()
-        .name("owner-listener".to_string())
-        .spawn(move || {
-            log_listener_event("Owner listener thread started");
-            let mask_guard = Arc::new(GrinMutex::new(mask));
-            if let Err(err) = controller::owner_listener(
-                wallet_inst,
-                mask_guard,
-                listen_addr_thread.as_str(),
-                Some(secret),
-                None,
-                Some(include_foreign),
-                Some(tor_cfg),
-                false,
-            ) {
-                log_listener_event(&format!("Owner listener failed: {err}"));
-            } else {
-                log_listener_event("Owner listener stopped");
+pub fn stop_updater(handle: WalletHandle) -> Result<UpdaterStatusDto> {
+    with_runtime_mut(handle, |runtime| {
+        if let Some(updater) = runtime.updater.take() {
+            updater.stop();
+        }
+        updater_status_dto(runtime)
+    })
+}
+
+/// Gleicht wie der Updater von libwallet per `retrieve_summary_info` ab. Refresh und TTL-Sweep
+/// laufen unter dem Runtime-Lock, damit sie sich nicht mit Sends oder Scans aus Dart
+/// ueberschneiden; der Event-Abgleich danach braucht ihn nicht.
+fn spawn_updater(
+    handle: WalletHandle,
+    runtime: &WalletRuntime,
+    entry: Arc<Mutex<WalletRuntime>>,
+    interval: Duration,
+) -> Result<UpdaterThread> {
+    let stop = Arc::new(AtomicBool::new(false));
+    let stopped = stop.clone();
+    let events = runtime.events.clone();
+    let thread = spawn_wallet_thread(
+        format!("wallet-updater-{handle}"),
+        runtime.chain_type,
+        move || {
+            while !stopped.load(Ordering::SeqCst) {
+                let Some(runtime) = lock_for_updater(&entry, &stopped) else {
+                    break;
+                };
+                let owner = &runtime.owner;
+                let mask = runtime.keychain_mask.as_ref();
+                let tip = match owner.retrieve_summary_info(mask, true, 10) {
+                    Ok((true, info)) => {
+                        note_node_reachable(true, Some(info.last_confirmed_height));
+                        Some(info.last_confirmed_height)
+                    }
+                    Ok((false, _)) => {
+                        note_node_reachable(false, None);
+                        None
+                    }
+                    Err(err) => {
+                        log_listener_event(&format!("Updater for wallet {handle}: {err}"));
+                        None
+                    }
+                };
+                if let Some(tip) = tip {
+                    if let Err(err) = cancel_expired_txs(owner, mask, tip) {
+                        log_listener_event(&format!("TTL sweep for wallet {handle}: {err}"));
+                    }
+                }
+                drop(runtime);
+                if tip.is_some() {
+                    events.check(true);
+                }
+                sleep_unless_stopped(&stopped, interval);
             }
-        })?;
+        },
+    )?;
+    Ok(UpdaterThread {
+        interval,
+        stop,
+        thread: Some(thread),
+    })
+}
 
-    let mut guard = OWNER_LISTENER
-        .lock()
-        .map_err(|_| anyhow!("Owner listener Lock fehlgeschlagen"))?;
-    *guard = Some(OwnerListenerRuntime {
-        _handle: handle,
-        listen_addr: listen_addr.clone(),
-    });
-    owner_listener_status()
+/// Wartet auf den Runtime-Lock, gibt aber auf, sobald der Updater gestoppt wird: wer ihn
+/// stoppt, haelt den Lock und wartet auf den Thread. `last_activity` bleibt unberuehrt, der
+/// Updater soll die automatische Sperre nicht aufhalten.
+fn lock_for_updater<'a>(
+    entry: &'a Mutex<WalletRuntime>,
+    stop: &AtomicBool,
+) -> Option<MutexGuard<'a, WalletRuntime>> {
+    loop {
+        if stop.load(Ordering::SeqCst) {
+            return None;
+        }
+        match entry.try_lock() {
+            Ok(runtime) if runtime.closed => return None,
+            Ok(runtime) => return Some(runtime),
+            Err(TryLockError::WouldBlock) => thread::sleep(UPDATER_LOCK_RETRY),
+            Err(TryLockError::Poisoned(_)) => return None,
+        }
+    }
 }
 
-pub fn tor_start(listen_addr: &str) -> Result<String> {
-    // If already running, just report status
-    {
-        let guard = TOR_RUNTIME
-            .lock()
-            .map_err(|_| anyhow!("Tor-Status konnte nicht gelesen werden"))?;
-        if guard.is_some() {
-            return tor_status();
+/// Schlaeft `interval`, prueft aber jede Sekunde, ob der Thread beendet werden soll.
+fn sleep_unless_stopped(stop: &AtomicBool, interval: Duration) {
+    let until = Instant::now() + interval;
+    while !stop.load(Ordering::SeqCst) {
+        let left = until.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            break;
         }
+        thread::sleep(left.min(Duration::from_secs(1)));
     }
+}
 
-    let (sec_key, onion, slatepack) = derive_onion_and_slatepack()?;
+pub fn updater_status(handle: WalletHandle) -> Result<UpdaterStatusDto> {
+    with_runtime(handle, updater_status_dto)
+}
 
-    // Build torrc and start tor process
-    let runtime_guard = WALLET_RUNTIME
+fn updater_status_dto(runtime: &WalletRuntime) -> Result<UpdaterStatusDto> {
+    let (_, info) =
+        runtime
+            .owner
+            .retrieve_summary_info(runtime.keychain_mask.as_ref(), false, 10)?;
+    let last_message = runtime
+        .updater_last_message
         .lock()
-        .map_err(|_| anyhow!("Wallet ist nicht initialisiert"))?;
-    let runtime = runtime_guard
-        .as_ref()
-        .ok_or_else(|| anyhow!("Wallet ist nicht initialisiert"))?;
+        .ok()
+        .and_then(|last| last.clone());
+    Ok(UpdaterStatusDto {
+        running: runtime.updater.is_some(),
+        interval_secs: runtime.updater.as_ref().map(|u| u.interval.as_secs()),
+        last_confirmed_height: info.last_confirmed_height,
+        last_message,
+    })
+}
 
-    let mut w_lock = runtime.owner.wallet_inst.lock();
-    let lc = w_lock
-        .lc_provider()
-        .map_err(|e| anyhow!("LC-Provider fehlgeschlagen: {e}"))?;
-    let top = lc
-        .get_top_level_directory()
-        .map_err(|e| anyhow!("Top-Level-Verzeichnis konnte nicht gelesen werden: {e}"))?;
+/// Leitet Statusmeldungen des Owners (Updater und Scans) als Events an Dart weiter. Endet,
+/// sobald Runtime und Updater beim Schliessen verworfen werden und kein Sender mehr lebt.
+fn spawn_status_pump(
+    handle: WalletHandle,
+    chain_type: ChainTypes,
+    status_rx: Receiver<StatusMessage>,
+    last_message: Arc<Mutex<Option<UpdaterMessageDto>>>,
+) {
+    let spawned = spawn_wallet_thread(format!("wallet-status-{handle}"), chain_type, move || {
+        for status in status_rx {
+            let message = UpdaterMessageDto::from_status(status);
+            if let Ok(mut last) = last_message.lock() {
+                *last = Some(message.clone());
+            }
+            events::emit(&WalletEventDto::UpdaterProgress { handle, message });
+        }
+    });
+    if let Err(err) = spawned {
+        log_listener_event(&format!(
+            "Status pump for wallet {handle} failed to start: {err}"
+        ));
+    }
+}
+
+/// Leere `listen_addr` nimmt die Foreign-Listener-Adresse aus der Konfiguration der Wallet.
+pub fn tor_start(handle: WalletHandle, listen_addr: &str) -> Result<TorStatusDto> {
+    with_runtime_mut(handle, |runtime| {
+        // If already running, just report status
+        if runtime.tor.is_none() {
+            start_tor(runtime, listen_addr)?;
+        }
+        tor_status_dto(runtime)
+    })
+}
+
+/// Ein Tor-Prozess je Wallet fuer Onion-Service und ausgehende Sends. Der SOCKS-Port bekommt
+/// wie die Listener einen eigenen Port, damit mehrere Wallets gleichzeitig Tor nutzen koennen.
+fn start_tor(runtime: &mut WalletRuntime, listen_addr: &str) -> Result<()> {
+    let listen_addr = match listen_addr.trim() {
+        "" => runtime.config.api_listen_addr(),
+        addr => addr.to_string(),
+    };
+
+    let (sec_key, onion, slatepack) = derive_onion_and_slatepack(runtime)?;
+
+    // Build torrc and start tor process
+    let top = {
+        let mut w_lock = runtime.owner.wallet_inst.lock();
+        let lc = w_lock.lc_provider().with_context(|| tr(Msg::LcProvider))?;
+        lc.get_top_level_directory()
+            .with_context(|| tr(Msg::TopLevelDirRead))?
+    };
     let tor_dir = format!("{}/tor/listener", top);
 
     tor_config::output_tor_listener_config(
         &tor_dir,
-        listen_addr,
+        &listen_addr,
         &[sec_key],
         Default::default(),
         Default::default(),
     )
-    .map_err(|e| anyhow!("Tor-Konfiguration fehlgeschlagen: {:?}", e))?;
+    .map_err(|e| BridgeError::TorFailed {
+        detail: format!("{}: {:?}", tr(Msg::TorConfig), e),
+    })?;
+    let socks: SocketAddr =
+        runtime
+            .tor_config
+            .socks_proxy_addr
+            .parse()
+            .map_err(|_| BridgeError::TorFailed {
+                detail: tr(Msg::TorSocksConfig),
+            })?;
+    let socks_port = claim_listen_port(&socks.ip().to_string(), socks.port())?;
+    let socks_addr = format!("{}:{}", socks.ip(), socks_port.0);
+    enable_socks_port(&tor_dir, &socks_addr)?;
 
     let mut process = tor_process::TorProcess::new();
     process
@@ -1231,31 +3311,81 @@ pub fn tor_start(listen_addr: &str) -> Result<String> {
         .timeout(40)
         .completion_percent(100)
         .launch()
-        .map_err(|e| anyhow!("Tor-Prozessstart fehlgeschlagen: {:?}", e))?;
+        .map_err(|e| BridgeError::TorFailed {
+            detail: format!("{}: {:?}", tr(Msg::TorProcess), e),
+        })?;
 
-    // Save runtime
-    {
-        let mut guard = TOR_RUNTIME
-            .lock()
-            .map_err(|_| anyhow!("Tor-Status konnte nicht gesetzt werden"))?;
-        *guard = Some(TorRuntime {
-            process: Some(process),
-            onion: onion.to_http_str(),
-            slatepack: slatepack.clone(),
-        });
-    }
+    runtime.tor = Some(TorRuntime {
+        process: Some(process),
+        onion: onion.to_http_str(),
+        slatepack,
+        socks_addr,
+        _socks_port: socks_port,
+    });
+    Ok(())
+}
 
-    tor_status()
+/// Die Listener-Konfiguration von grin-wallet schaltet SOCKS ab; hier wird der Port statt
+/// `SocksPort 0` eingetragen.
+fn enable_socks_port(tor_dir: &str, socks_addr: &str) -> Result<()> {
+    let torrc = Path::new(tor_dir).join("torrc");
+    let existing = fs::read_to_string(&torrc).with_context(|| tr(Msg::TorSocksConfig))?;
+    let mut lines: Vec<&str> = existing
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("SocksPort"))
+        .collect();
+    let socks_line = format!("SocksPort {socks_addr}");
+    lines.push(&socks_line);
+    fs::write(&torrc, lines.join("\n") + "\n").with_context(|| tr(Msg::TorSocksConfig))?;
+    Ok(())
 }
 
-pub fn tor_stop() -> Result<()> {
-    let mut guard = TOR_RUNTIME
-        .lock()
-        .map_err(|_| anyhow!("Tor-Status konnte nicht gesetzt werden"))?;
-    if let Some(mut rt) = guard.take() {
-        if let Some(child) = rt.process.as_mut().and_then(|p| p.process.as_mut()) {
-            let _ = child.kill();
+pub fn tor_stop(handle: WalletHandle) -> Result<()> {
+    with_runtime_mut(handle, |runtime| {
+        if let Some(rt) = runtime.tor.take() {
+            rt.kill();
         }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selects_smallest_outputs_first() {
+        assert_eq!(selected_input_count(&[1, 2, 5, 10], 3, false), Some(2));
+        assert_eq!(selected_input_count(&[1, 2, 5, 10], 4, false), Some(3));
+        assert_eq!(selected_input_count(&[1, 2, 5, 10], 19, false), None);
+        assert_eq!(input_count(vec![10, 5, 1, 2], 500, false, 3), 2);
+    }
+
+    #[test]
+    fn use_all_takes_every_output() {
+        assert_eq!(selected_input_count(&[1, 2, 3], 1, true), Some(3));
+        assert_eq!(input_count(vec![3, 1, 2], 500, true, 1), 3);
+    }
+
+    #[test]
+    fn max_outputs_slides_a_window() {
+        // Die kleinsten zwei reichen nicht, das erste passende Fenster ist [1, 10].
+        assert_eq!(input_count(vec![1, 1, 1, 1, 10], 2, false, 10), 2);
+        // Mit `use_all` zaehlt das ganze Fenster, auch wenn weniger reichen wuerde.
+        assert_eq!(input_count(vec![1, 1, 5, 6], 3, true, 2), 3);
+        assert_eq!(input_count(vec![1, 1, 5, 6], 3, false, 2), 2);
+    }
+
+    #[test]
+    fn send_all_uses_largest_max_outputs() {
+        assert_eq!(send_all_amount(vec![1, 7, 3, 5], 500), 16);
+        assert_eq!(send_all_amount(vec![1, 7, 3, 5], 2), 12);
+        assert_eq!(send_all_amount(vec![], 2), 0);
+    }
+
+    #[test]
+    fn insufficient_outputs_cap_at_max_outputs() {
+        assert_eq!(input_count(vec![1, 1, 1], 2, false, 10), 2);
+        assert_eq!(input_count(vec![1, 1, 1], 500, false, 10), 3);
     }
-    Ok(())
 }